@@ -8,3 +8,17 @@ fn bench_parse(b: &mut Bencher) {
     let model = budoux::models::default_japanese_model();
     b.iter(|| budoux::parse(model, "日本語の文章をいい感じに分割します。"))
 }
+
+#[bench]
+fn bench_parse_compiled(b: &mut Bencher) {
+    let model = budoux::CompiledModel::from(budoux::models::default_japanese_model());
+    b.iter(|| budoux::parse(&model, "日本語の文章をいい感じに分割します。"))
+}
+
+#[bench]
+fn bench_parser_compiled(b: &mut Bencher) {
+    let model = budoux::CompiledModel::from(budoux::models::default_japanese_model());
+    let mut parser = budoux::Parser::new(&model);
+    let mut offsets = Vec::new();
+    b.iter(|| parser.boundaries_into("日本語の文章をいい感じに分割します。", &mut offsets))
+}
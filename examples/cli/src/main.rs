@@ -1,13 +1,81 @@
 use getopts::Options;
+use std::collections::HashMap;
 use std::env;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufRead, BufReader};
 
 fn print_usage(program: &str, opts: &Options) {
     let brief = format!("Usage: {} [options]", program);
     print!("{}", opts.usage(&brief));
 }
 
+/// train prints model trained from segmented sentences in json.
+fn train(path: &str, schema: budoux::Schema, iterations: usize) {
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+
+    let mut trainer = budoux::train::Trainer::new(schema);
+    for line in reader.lines() {
+        trainer.add_sentence(line.unwrap().trim_end());
+    }
+
+    // Flat model of BudouX v0.1 or nested model of newer BudouX, same as schema.
+    let model = trainer.train(iterations);
+    println!("{}", serde_json::to_string(&model).unwrap());
+}
+
+/// load_model returns model loaded from json file.
+fn load_model(path: &str) -> budoux::Model {
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+
+    budoux::Model::from_json_reader(reader).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+/// load_model_unchecked returns model loaded from json file, unknown feature keys are kept.
+fn load_model_unchecked(path: &str) -> budoux::Model {
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+    let value: serde_json::Value = serde_json::from_reader(reader).unwrap();
+
+    if value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .all(|(_, v)| v.is_object())
+    {
+        // Nested model of newer BudouX.
+        let groups: HashMap<String, HashMap<String, i32>> = serde_json::from_value(value).unwrap();
+        budoux::Model::from(groups)
+    } else {
+        // Flat model of BudouX v0.1.
+        let features: HashMap<String, i32> = serde_json::from_value(value).unwrap();
+        budoux::Model::from(features)
+    }
+}
+
+/// evaluate prints metrics of model for each threshold, with file of segmented sentences.
+fn evaluate<M: budoux::Scorer + ?Sized>(model: &M, path: &str, thresholds: &[i32]) {
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+    let sentences: Vec<String> = reader
+        .lines()
+        .map(|line| line.unwrap().trim_end().to_string())
+        .collect();
+
+    println!("threshold\tprecision\trecall\tf1\taccuracy");
+    for (threshold, metrics) in budoux::eval::sweep(model, &sentences, thresholds) {
+        println!(
+            "{}\t{:.4}\t{:.4}\t{:.4}\t{:.4}",
+            threshold,
+            metrics.precision(),
+            metrics.recall(),
+            metrics.f1(),
+            metrics.accuracy()
+        );
+    }
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
     let program = args[0].clone();
@@ -15,6 +83,37 @@ fn main() {
     let mut opts = Options::new();
     opts.optopt("", "in", "input string", "INPUT");
     opts.optopt("", "model", "path of model file", "MODEL");
+    opts.optopt(
+        "",
+        "train",
+        "print model trained from file of segmented sentences (phrases are separated by '▁')",
+        "CORPUS",
+    );
+    opts.optopt("", "schema", "schema of trained model (v1 or v2)", "SCHEMA");
+    opts.optopt("", "iterations", "number of training iterations", "N");
+    opts.optopt(
+        "",
+        "eval",
+        "print precision, recall, f1 and accuracy of model with file of segmented sentences",
+        "CORPUS",
+    );
+    opts.optopt(
+        "",
+        "threshold",
+        "threshold for splitting a sentences",
+        "THRESHOLD",
+    );
+    opts.optopt(
+        "",
+        "sweep",
+        "evaluate thresholds around the threshold with step",
+        "STEP",
+    );
+    opts.optflag(
+        "",
+        "validate",
+        "print feature keys of model which are never looked up",
+    );
     opts.optflag("h", "help", "print this help menu");
 
     let matches = opts.parse(&args[1..]).unwrap_or_else(|f| {
@@ -27,20 +126,78 @@ fn main() {
         return;
     }
 
+    if let Some(corpus) = matches.opt_str("train") {
+        let schema = match matches.opt_str("schema").as_deref() {
+            Some("v1") => budoux::Schema::V1,
+            Some("v2") | None => budoux::Schema::V2,
+            Some(x) => panic!("unknown schema: {}", x),
+        };
+        let iterations = matches
+            .opt_str("iterations")
+            .map_or(10000, |x| x.parse().unwrap());
+
+        train(&corpus, schema, iterations);
+        return;
+    }
+
+    if matches.opt_present("validate") {
+        let model = match matches.opt_str("model") {
+            Some(x) => load_model_unchecked(&x),
+            None => budoux::models::default_japanese_model().to_model(),
+        };
+
+        let report = budoux::validate(&model);
+        for issue in &report.issues {
+            println!("{}", issue);
+        }
+        println!(
+            "{} keys checked, {} issues found",
+            report.checked,
+            report.issues.len()
+        );
+
+        if !report.is_valid() {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let threshold = matches
+        .opt_str("threshold")
+        .map_or(budoux::DEFAULT_THRESHOLD, |x| x.parse().unwrap());
+
+    if let Some(corpus) = matches.opt_str("eval") {
+        let thresholds: Vec<i32> = match matches.opt_str("sweep") {
+            Some(x) => {
+                let step: i32 = x.parse().unwrap();
+                (-5..=5).map(|i| threshold + i * step).collect()
+            }
+            None => vec![threshold],
+        };
+
+        match matches.opt_str("model") {
+            Some(x) => evaluate(&load_model(&x), &corpus, &thresholds),
+            None => evaluate(
+                budoux::models::default_japanese_model(),
+                &corpus,
+                &thresholds,
+            ),
+        }
+        return;
+    }
+
     let input = matches.opt_str("in").unwrap_or_default();
 
     let words = match matches.opt_str("model") {
         Some(x) => {
             // Load model from json file and split sentences using the loaded model.
-            let file = File::open(x).unwrap();
-            let reader = BufReader::new(file);
-            let model: budoux::Model = serde_json::from_reader(reader).unwrap();
-            budoux::parse(&model, &input)
+            let model = load_model(&x);
+            budoux::parse_with_threshold(&model, &input, threshold)
         }
         None => {
             // Split sentences with internal model.
             let model = budoux::models::default_japanese_model();
-            budoux::parse(model, &input)
+            budoux::parse_with_threshold(model, &input, threshold)
         }
     };
 
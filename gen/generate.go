@@ -1,5 +1,7 @@
 //go:generate go run .
 //go:generate rustfmt ../src/unicode_blocks.rs
+//go:generate rustfmt ../src/east_asian_width.rs
+//go:generate rustfmt ../src/grapheme_break.rs
 //go:generate rustfmt ../src/ja_knbc.rs
 //go:generate rustfmt ../src/zh_hans.rs
 package main
@@ -13,6 +15,8 @@ import (
 	"net/url"
 	"os"
 	"path/filepath"
+	"sort"
+	"strconv"
 	"strings"
 	"text/template"
 )
@@ -20,6 +24,33 @@ import (
 // defaultSrc original BudouX repository.
 const defaultSrc = "https://raw.githubusercontent.com/google/budoux/v0.1.0"
 
+// defaultEastAsianWidthSrc east asian width property of unicode character database.
+const defaultEastAsianWidthSrc = "https://www.unicode.org/Public/14.0.0/ucd/EastAsianWidth.txt"
+
+// defaultGraphemeBreakSrc grapheme cluster break property of unicode character database.
+const defaultGraphemeBreakSrc = "https://www.unicode.org/Public/15.0.0/ucd/auxiliary/GraphemeBreakProperty.txt"
+
+// defaultEmojiDataSrc emoji properties of unicode character database, for Extended_Pictographic.
+const defaultEmojiDataSrc = "https://www.unicode.org/Public/15.0.0/ucd/emoji/emoji-data.txt"
+
+// graphemeBreakProperties variant names of budoux::grapheme::Property by property value.
+var graphemeBreakProperties = map[string]string{
+	"CR":                    "CR",
+	"LF":                    "LF",
+	"Control":               "Control",
+	"Extend":                "Extend",
+	"ZWJ":                   "ZWJ",
+	"Regional_Indicator":    "RegionalIndicator",
+	"Prepend":               "Prepend",
+	"SpacingMark":           "SpacingMark",
+	"L":                     "L",
+	"V":                     "V",
+	"T":                     "T",
+	"LV":                    "LV",
+	"LVT":                   "LVT",
+	"Extended_Pictographic": "ExtendedPictographic",
+}
+
 var unicodeBlocksTemplate = template.Must(template.New("unicode_blocks").Parse(`
 // Code generated by gen/generate.go. DO NOT EDIT.
 // Generate from {{.Base}}
@@ -35,6 +66,28 @@ pub const UNICODE_BLOCKS: [u32; {{len .Data}}] = [{{range .Data}} {{.}}, {{end}}
 pub const BLOCK_FEATURES: [&str; {{len .Data}}] = [{{range $i, $v := .Data}} {{$i | printf "\"%03d\""}}, {{end}}];
 `))
 
+var eastAsianWidthTemplate = template.Must(template.New("east_asian_width").Parse(`
+// Code generated by gen/generate.go. DO NOT EDIT.
+// Generate from {{.Base}}
+//
+
+/// WIDE_RANGES ranges of code points whose east asian width is wide (W) or fullwidth (F), pairs of first and last.
+pub const WIDE_RANGES: [(u32, u32); {{len .Data}}] = [{{range .Data}} ({{index . 0}}, {{index . 1}}), {{end}}];
+`))
+
+var graphemeBreakTemplate = template.Must(template.New("grapheme_break").Parse(`
+// Code generated by gen/generate.go. DO NOT EDIT.
+// Generate from {{.Base}}
+// and {{.Emoji}}
+//
+
+use crate::grapheme::Property;
+
+/// GRAPHEME_BREAKS ranges of code points and their grapheme cluster break property, first, last and property.
+/// Extended_Pictographic of emoji data is included as a property, code points not in ranges are Other.
+pub static GRAPHEME_BREAKS: [(u32, u32, Property); {{len .Data}}] = [{{range .Data}} ({{.First}}, {{.Last}}, Property::{{.Property}}), {{end}}];
+`))
+
 var modelsTemplate = template.Must(template.New("models").Parse(`
 // Code generated by gen/generate.go. DO NOT EDIT.
 // Generate from {{.Base}}
@@ -43,17 +96,12 @@ var modelsTemplate = template.Must(template.New("models").Parse(`
 // BudouX | Apache License 2.0 | https://github.com/google/budoux/blob/main/LICENSE
 //
 
-use once_cell::sync::Lazy;
-
 /// MODEL reference to trained machine learning model.
-pub static MODEL: Lazy<crate::Model> = Lazy::new(|| {
-    let mut m = crate::Model::new();
-    {{range $key, $value := .Data}}
-    m.insert(String::from("{{$key}}"), {{$value}});
+pub static MODEL: crate::models::StaticModel = crate::models::StaticModel::new(&[
+    {{- range $key, $value := .Data}}
+    ("{{$key}}", {{$value}}),
     {{- end}}
-
-    m
-});
+]).with_index(&[{{range .Seeds}} {{.}}, {{end}}], &[{{range .Slots}} {{.}}, {{end}}]);
 `))
 
 func main() {
@@ -74,6 +122,16 @@ func main() {
 		os.Exit(1)
 	}
 
+	if err := genEastAsianWidth(defaultEastAsianWidthSrc, out); err != nil {
+		fmt.Printf("generate east asian width: %v\n", err)
+		os.Exit(1)
+	}
+
+	if err := genGraphemeBreak(defaultGraphemeBreakSrc, defaultEmojiDataSrc, out); err != nil {
+		fmt.Printf("generate grapheme break: %v\n", err)
+		os.Exit(1)
+	}
+
 	if err := genModels(src, out); err != nil {
 		fmt.Printf("generate models: %v\n", err)
 		os.Exit(1)
@@ -113,6 +171,160 @@ func genUnicodeBlocks(src, out string) error {
 	return nil
 }
 
+func genEastAsianWidth(srcURL, out string) error {
+	body, dlErr := download(srcURL)
+	if dlErr != nil {
+		return fmt.Errorf("download: %w", dlErr)
+	}
+
+	inventory := struct {
+		Base string
+		Data [][2]uint64
+	}{
+		Base: srcURL,
+		Data: [][2]uint64{},
+	}
+
+	// Each line is "first..last;property # comment" or "code;property # comment".
+	for _, line := range strings.Split(string(body), "\n") {
+		line = strings.TrimSpace(strings.SplitN(line, "#", 2)[0])
+		fields := strings.SplitN(line, ";", 2)
+		if len(fields) != 2 {
+			continue
+		}
+
+		if prop := strings.TrimSpace(fields[1]); prop != "W" && prop != "F" {
+			continue
+		}
+
+		codes := strings.SplitN(strings.TrimSpace(fields[0]), "..", 2)
+		first, err := strconv.ParseUint(codes[0], 16, 32)
+		if err != nil {
+			return fmt.Errorf("parse code point: %w", err)
+		}
+		last := first
+		if len(codes) == 2 {
+			if last, err = strconv.ParseUint(codes[1], 16, 32); err != nil {
+				return fmt.Errorf("parse code point: %w", err)
+			}
+		}
+
+		// Merge adjacent ranges.
+		if n := len(inventory.Data); n > 0 && inventory.Data[n-1][1]+1 == first {
+			inventory.Data[n-1][1] = last
+			continue
+		}
+		inventory.Data = append(inventory.Data, [2]uint64{first, last})
+	}
+
+	file, fileErr := os.Create(filepath.Join(out, "east_asian_width.rs"))
+	if fileErr != nil {
+		return fmt.Errorf("open file: %w", fileErr)
+	}
+	defer file.Close()
+
+	if err := eastAsianWidthTemplate.Execute(file, inventory); err != nil {
+		return fmt.Errorf("execute: %w", err)
+	}
+
+	return nil
+}
+
+// propertyRange is range of code points with a property.
+type propertyRange struct {
+	First    uint64
+	Last     uint64
+	Property string
+}
+
+func genGraphemeBreak(srcURL, emojiURL, out string) error {
+	ranges := []propertyRange{}
+
+	for _, v := range []string{srcURL, emojiURL} {
+		body, dlErr := download(v)
+		if dlErr != nil {
+			return fmt.Errorf("download: %w", dlErr)
+		}
+
+		parsed, err := parsePropertyRanges(string(body))
+		if err != nil {
+			return err
+		}
+		ranges = append(ranges, parsed...)
+	}
+
+	sort.Slice(ranges, func(i, j int) bool { return ranges[i].First < ranges[j].First })
+
+	inventory := struct {
+		Base  string
+		Emoji string
+		Data  []propertyRange
+	}{
+		Base:  srcURL,
+		Emoji: emojiURL,
+		Data:  []propertyRange{},
+	}
+
+	// Merge adjacent ranges of the same property.
+	for _, r := range ranges {
+		if n := len(inventory.Data); n > 0 {
+			last := &inventory.Data[n-1]
+			if last.Property == r.Property && last.Last+1 == r.First {
+				last.Last = r.Last
+				continue
+			}
+		}
+		inventory.Data = append(inventory.Data, r)
+	}
+
+	file, fileErr := os.Create(filepath.Join(out, "grapheme_break.rs"))
+	if fileErr != nil {
+		return fmt.Errorf("open file: %w", fileErr)
+	}
+	defer file.Close()
+
+	if err := graphemeBreakTemplate.Execute(file, inventory); err != nil {
+		return fmt.Errorf("execute: %w", err)
+	}
+
+	return nil
+}
+
+// parsePropertyRanges parses properties in graphemeBreakProperties from a file of unicode character database.
+func parsePropertyRanges(body string) ([]propertyRange, error) {
+	ranges := []propertyRange{}
+
+	// Each line is "first..last ; property # comment" or "code ; property # comment".
+	for _, line := range strings.Split(body, "\n") {
+		line = strings.TrimSpace(strings.SplitN(line, "#", 2)[0])
+		fields := strings.SplitN(line, ";", 2)
+		if len(fields) != 2 {
+			continue
+		}
+
+		prop, ok := graphemeBreakProperties[strings.TrimSpace(fields[1])]
+		if !ok {
+			continue
+		}
+
+		codes := strings.SplitN(strings.TrimSpace(fields[0]), "..", 2)
+		first, err := strconv.ParseUint(codes[0], 16, 32)
+		if err != nil {
+			return nil, fmt.Errorf("parse code point: %w", err)
+		}
+		last := first
+		if len(codes) == 2 {
+			if last, err = strconv.ParseUint(codes[1], 16, 32); err != nil {
+				return nil, fmt.Errorf("parse code point: %w", err)
+			}
+		}
+
+		ranges = append(ranges, propertyRange{First: first, Last: last, Property: prop})
+	}
+
+	return ranges, nil
+}
+
 func genModels(src, out string) error {
 	tbl := []struct {
 		name string
@@ -147,8 +359,10 @@ func genLangModel(srcURL, outPath string) error {
 	}
 
 	inventory := struct {
-		Base string
-		Data map[string]int
+		Base  string
+		Data  map[string]int
+		Seeds []uint32
+		Slots []int
 	}{
 		Base: srcURL,
 		Data: map[string]int{},
@@ -158,6 +372,14 @@ func genLangModel(srcURL, outPath string) error {
 		return fmt.Errorf("unmarshal: %w", err)
 	}
 
+	keys := make([]string, 0, len(inventory.Data))
+	for key := range inventory.Data {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	inventory.Seeds, inventory.Slots = perfectHash(keys)
+
 	file, fileErr := os.Create(outPath)
 	if fileErr != nil {
 		return fmt.Errorf("open file: %w", fileErr)
@@ -171,6 +393,89 @@ func genLangModel(srcURL, outPath string) error {
 	return nil
 }
 
+// perfectHash returns seed of each bucket and index of keys in each slot (hash and displace).
+// keys must be sorted, same as features of generated models.
+//
+// Hash of a key chooses its bucket, and hash mixed with seed of the bucket chooses its slot.
+// Buckets are placed from the largest one, by searching seed which maps all keys of the bucket to free slots.
+func perfectHash(keys []string) ([]uint32, []int) {
+	n := len(keys)
+	buckets := make([][]int, (n+3)/4)
+	for i, key := range keys {
+		b := hash(key) % uint32(len(buckets))
+		buckets[b] = append(buckets[b], i)
+	}
+
+	order := make([]int, len(buckets))
+	for i := range order {
+		order[i] = i
+	}
+	sort.SliceStable(order, func(i, j int) bool { return len(buckets[order[i]]) > len(buckets[order[j]]) })
+
+	seeds := make([]uint32, len(buckets))
+	slots := make([]int, n)
+	used := make([]bool, n)
+	for _, b := range order {
+		if len(buckets[b]) == 0 {
+			continue
+		}
+
+		for seed := uint32(1); ; seed++ {
+			pos := make([]int, 0, len(buckets[b]))
+			for _, i := range buckets[b] {
+				p := int(mix(hash(keys[i])^seed) % uint32(n))
+				if used[p] || containsInt(pos, p) {
+					break
+				}
+				pos = append(pos, p)
+			}
+
+			if len(pos) == len(buckets[b]) {
+				seeds[b] = seed
+				for j, p := range pos {
+					used[p] = true
+					slots[p] = buckets[b][j]
+				}
+				break
+			}
+		}
+	}
+
+	return seeds, slots
+}
+
+// hash returns FNV-1a hash of key followed by mix, it must be same as hash of src/models.rs.
+func hash(key string) uint32 {
+	h := uint32(0x811c9dc5)
+	for i := 0; i < len(key); i++ {
+		h ^= uint32(key[i])
+		h *= 0x01000193
+	}
+
+	return mix(h)
+}
+
+// mix returns h mixed by finalizer of MurmurHash3, it must be same as mix of src/models.rs.
+func mix(h uint32) uint32 {
+	h ^= h >> 16
+	h *= 0x85ebca6b
+	h ^= h >> 13
+	h *= 0xc2b2ae35
+	h ^= h >> 16
+
+	return h
+}
+
+func containsInt(values []int, v int) bool {
+	for _, x := range values {
+		if x == v {
+			return true
+		}
+	}
+
+	return false
+}
+
 func download(src string) ([]byte, error) {
 	resp, err := http.Get(src)
 	if err != nil {
@@ -0,0 +1,338 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasherDefault, Hasher};
+
+use crate::features::{templates, Slot};
+use crate::models::StaticModel;
+use crate::{Model, Schema, Scorer, Window, INVALID_FEATURE};
+
+/// CHAR_BITS is bit width of encoded character.
+const CHAR_BITS: u32 = 21;
+
+/// BLOCK_BITS is bit width of encoded unicode block feature.
+const BLOCK_BITS: u32 = 10;
+
+/// RESULT_BITS is bit width of encoded previous result.
+const RESULT_BITS: u32 = 2;
+
+/// INVALID_BLOCK is encoded value of INVALID_FEATURE.
+const INVALID_BLOCK: u64 = (1 << BLOCK_BITS) - 1;
+
+/// Table is integer keyed table of a feature template.
+#[cfg(feature = "std")]
+type Table = std::collections::HashMap<u64, i32, BuildHasherDefault<IdHasher>>;
+
+/// Table is integer keyed table of a feature template.
+#[cfg(not(feature = "std"))]
+type Table = alloc::collections::BTreeMap<u64, i32>;
+
+/// CompiledModel is trained machine learning model compiled for fast lookup.
+///
+/// Feature keys are encoded into integers (characters by code point, unicode blocks by index),
+/// and looked up from integer keyed table of each feature template.
+/// So scoring does not build any string keys.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::CompiledModel::from(budoux::models::default_japanese_model());
+/// let words = budoux::parse(&model, "これはテストです。");
+///
+/// assert_eq!(words, vec!["これは", "テストです。"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompiledModel {
+    schema: Schema,
+    base_score: i32,
+    tables: Vec<Table>, // table of each template
+}
+
+impl From<&Model> for CompiledModel {
+    fn from(model: &Model) -> Self {
+        CompiledModel::compile(model, model.iter())
+    }
+}
+
+impl From<&StaticModel> for CompiledModel {
+    fn from(model: &StaticModel) -> Self {
+        CompiledModel::compile(model, model.iter())
+    }
+}
+
+impl CompiledModel {
+    /// compile returns model from pairs of feature key and score of the model.
+    fn compile<'k, M: Scorer + ?Sized>(
+        model: &M,
+        features: impl Iterator<Item = (&'k str, i32)>,
+    ) -> Self {
+        let schema = model.schema();
+        let templates = templates(schema);
+        let mut tables = vec![Table::default(); templates.len()];
+
+        for (key, score) in features {
+            let found = templates
+                .iter()
+                .position(|(prefix, _)| key.starts_with(prefix));
+
+            // Keys of unknown template are never looked up.
+            let index = match found {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let (prefix, slots) = templates[index];
+
+            // Keys which can not be decoded never match any window.
+            if let Some(code) = decode_key(&key[prefix.len()..], slots) {
+                tables[index].insert(code, score);
+            }
+        }
+
+        CompiledModel {
+            schema,
+            base_score: model.base_score(),
+            tables,
+        }
+    }
+}
+
+impl Scorer for CompiledModel {
+    fn lookup(&self, key: &str) -> Option<i32> {
+        let templates = templates(self.schema);
+        let index = templates
+            .iter()
+            .position(|(prefix, _)| key.starts_with(prefix))?;
+        let (prefix, slots) = templates[index];
+        let code = decode_key(&key[prefix.len()..], slots)?;
+
+        self.tables.get(index)?.get(&code).copied()
+    }
+
+    fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    fn base_score(&self) -> i32 {
+        self.base_score
+    }
+
+    fn score(&self, window: &Window, _buf: &mut String) -> i32 {
+        let mut score: i32 = self.base_score;
+
+        for ((_, slots), table) in templates(self.schema).iter().zip(&self.tables) {
+            if table.is_empty() {
+                continue;
+            }
+
+            score = score.saturating_add(*table.get(&encode_window(window, slots)).unwrap_or(&0));
+        }
+
+        score
+    }
+}
+
+/// encode_window returns integer key of window values referred by slots.
+fn encode_window(window: &Window, slots: &[Slot]) -> u64 {
+    let mut code: u64 = 0;
+
+    for slot in slots {
+        let value = window.slot(*slot);
+        match slot {
+            Slot::Char(_) => {
+                // Empty characters are skipped, as well as concatenated string keys.
+                if let Some(c) = value.chars().next() {
+                    code = code << CHAR_BITS | (c as u64 + 1);
+                }
+            }
+            Slot::Block(_) => {
+                code = code << BLOCK_BITS | encode_block(value).unwrap_or(INVALID_BLOCK);
+            }
+            Slot::Result(_) => {
+                code = code << RESULT_BITS | encode_result(value).unwrap_or(0);
+            }
+        }
+    }
+
+    code
+}
+
+/// decode_key returns integer key of string key without prefix.
+/// It returns None if the key can not be produced by any window.
+fn decode_key(mut key: &str, slots: &[Slot]) -> Option<u64> {
+    let mut code: u64 = 0;
+
+    for slot in slots {
+        match slot {
+            Slot::Char(_) => {
+                if let Some(c) = key.chars().next() {
+                    code = code << CHAR_BITS | (c as u64 + 1);
+                    key = &key[c.len_utf8()..];
+                }
+            }
+            Slot::Block(_) => {
+                let size = if key.starts_with(INVALID_FEATURE) {
+                    INVALID_FEATURE.len()
+                } else {
+                    3
+                };
+                code = code << BLOCK_BITS | encode_block(key.get(..size)?)?;
+                key = &key[size..];
+            }
+            Slot::Result(_) => {
+                code = code << RESULT_BITS | encode_result(key.get(..1)?)?;
+                key = &key[1..];
+            }
+        }
+    }
+
+    if !key.is_empty() {
+        return None;
+    }
+
+    Some(code)
+}
+
+/// encode_block returns integer of unicode block feature ("000" to "999" or INVALID_FEATURE).
+fn encode_block(value: &str) -> Option<u64> {
+    if value == INVALID_FEATURE {
+        return Some(INVALID_BLOCK);
+    }
+
+    if value.len() != 3 || !value.bytes().all(|v| v.is_ascii_digit()) {
+        return None;
+    }
+
+    value.parse::<u64>().ok()
+}
+
+/// encode_result returns integer of previous result ("U", "B" or "O").
+fn encode_result(value: &str) -> Option<u64> {
+    match value {
+        "U" => Some(1),
+        "B" => Some(2),
+        "O" => Some(3),
+        _ => None,
+    }
+}
+
+/// IdHasher is hasher for integer encoded feature keys.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+struct IdHasher(u64);
+
+#[cfg(feature = "std")]
+impl Hasher for IdHasher {
+    fn finish(&self) -> u64 {
+        self.0 ^ (self.0 >> 32)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for v in bytes {
+            self.write_u64(*v as u64);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x517c_c1b7_2722_0a95);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CompiledModel;
+
+    #[test]
+    #[cfg(all(feature = "model-ja", feature = "model-zh-hans"))]
+    fn test_score() {
+        let inputs = [
+            "",
+            "日本語",
+            "水と油",
+            "PythonとJavaScriptとGolang",
+            "日本語の文章において語の区切りに空白を挟んで記述すること",
+            "これはテストです。\n今日は晴天です。",
+            "今天是晴天。",
+        ];
+
+        let models = [
+            crate::models::default_japanese_model(),
+            crate::models::default_simplified_chinese_model(),
+        ];
+
+        for m in models {
+            let c = CompiledModel::from(m);
+            for input in inputs {
+                assert_eq!(crate::scores(&c, input), crate::scores(m, input));
+                assert_eq!(crate::parse(&c, input), crate::parse(m, input));
+            }
+        }
+    }
+
+    #[test]
+    fn test_score_edge_keys() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("BW1:あ"), 1); // matches with empty i - 2
+        m.insert(String::from("TW4:い"), 2); // matches with empty i + 1 and i + 2
+        m.insert(String::from("UB1:▔"), 4);
+        m.insert(String::from("BB1:▔108"), 8);
+        m.insert(String::from("UQ1:U▔"), 16);
+        m.insert(String::from("UB2:12"), 32); // never matches
+        m.insert(String::from("UP1:X"), 64); // never matches
+        m.insert(String::from("XX1:あ"), 128); // never matches
+
+        let c = CompiledModel::from(&m);
+        assert_eq!(crate::Scorer::lookup(&c, "BB1:▔108"), Some(8));
+        assert_eq!(crate::Scorer::lookup(&c, "UB2:12"), None);
+        assert_eq!(crate::Scorer::lookup(&c, "XX1:あ"), None);
+
+        for input in ["", "あ", "あい", "いあい", "あいうえお"] {
+            assert_eq!(crate::scores(&c, input), crate::scores(&m, input));
+        }
+
+        assert_eq!(crate::scores(&c, "あい"), vec![(3, 1 + 2 + 4 + 8 + 16)]);
+    }
+
+    #[test]
+    fn test_score_v2() {
+        let mut m = crate::Model::with_schema(crate::Schema::V2);
+        m.insert(String::from("UW3:と"), 400);
+        m.insert(String::from("BW2:水と"), -300);
+        m.insert(String::from("UW1:x"), 10000); // never matches
+
+        let c = CompiledModel::from(&m);
+        assert_eq!(crate::Scorer::schema(&c), crate::Schema::V2);
+        assert_eq!(crate::Scorer::base_score(&c), m.base_score());
+        assert_eq!(crate::Scorer::lookup(&c, "UW1:x"), Some(10000));
+
+        for input in ["", "水と油", "水と空", "今日は水と油です"] {
+            assert_eq!(crate::scores(&c, input), crate::scores(&m, input));
+        }
+    }
+
+    #[test]
+    fn test_decode_key() {
+        use crate::features::Slot::{Block, Char, Result};
+
+        assert_eq!(super::decode_key("", &[Char(0)]), Some(0));
+        assert_eq!(super::decode_key("a", &[Char(0)]), Some(98));
+        assert_eq!(super::decode_key("ab", &[Char(0)]), None);
+        assert_eq!(
+            super::decode_key("ab", &[Char(0), Char(1)]),
+            Some(98 << 21 | 99)
+        );
+        assert_eq!(super::decode_key("120", &[Block(0)]), Some(120));
+        assert_eq!(super::decode_key("▔", &[Block(0)]), Some(1023));
+        assert_eq!(super::decode_key("12", &[Block(0)]), None);
+        assert_eq!(super::decode_key("+12", &[Block(0)]), None);
+        assert_eq!(
+            super::decode_key("O108120", &[Result(0), Block(0), Block(1)]),
+            Some((3 << 10 | 108) << 10 | 120)
+        );
+        assert_eq!(super::decode_key("X", &[Result(0)]), None);
+    }
+}
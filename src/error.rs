@@ -0,0 +1,89 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Error is error of loading model.
+#[derive(Debug)]
+pub enum Error {
+    /// Io is error of reading model, it is available with `std` feature.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// Json is malformed json, with byte offset of the error.
+    Json {
+        /// byte offset of the error in json.
+        offset: usize,
+        /// description of the error.
+        reason: &'static str,
+    },
+    /// InvalidScore is score which is not integer in range of i32, with its feature key.
+    InvalidScore(String),
+    /// UnknownFeature is feature key never looked up by schema of model (e.g. "XX1:あ").
+    UnknownFeature(String),
+    /// EmptyModel is model which has no features.
+    EmptyModel,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Error::Io(e) => write!(f, "failed to read model: {}", e),
+            Error::Json { offset, reason } => {
+                write!(f, "malformed json at byte {}: {}", offset, reason)
+            }
+            Error::InvalidScore(key) => write!(f, "score of feature {:?} is not i32", key),
+            Error::UnknownFeature(key) => write!(f, "unknown feature {:?}", key),
+            Error::EmptyModel => write!(f, "model has no features"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_display() {
+        let e = Error::Json {
+            offset: 3,
+            reason: "unexpected character",
+        };
+        assert_eq!(
+            e.to_string(),
+            "malformed json at byte 3: unexpected character"
+        );
+        assert_eq!(
+            Error::InvalidScore(String::from("UW1:あ")).to_string(),
+            "score of feature \"UW1:あ\" is not i32"
+        );
+        assert_eq!(
+            Error::UnknownFeature(String::from("XX1:あ")).to_string(),
+            "unknown feature \"XX1:あ\""
+        );
+        assert_eq!(Error::EmptyModel.to_string(), "model has no features");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_io() {
+        let e = Error::from(std::io::Error::new(std::io::ErrorKind::Other, "oops"));
+        assert_eq!(e.to_string(), "failed to read model: oops");
+        assert!(std::error::Error::source(&e).is_some());
+    }
+}
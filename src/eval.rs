@@ -0,0 +1,255 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::train::SEPARATOR;
+use crate::Scorer;
+
+/// Metrics is counts of boundary decisions at character gaps, compared with gold segmentation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Metrics {
+    /// number of gaps split by both model and gold.
+    pub true_positive: usize,
+    /// number of gaps split by model, but not by gold.
+    pub false_positive: usize,
+    /// number of gaps split by gold, but not by model.
+    pub false_negative: usize,
+    /// number of gaps split by neither model nor gold.
+    pub true_negative: usize,
+}
+
+impl Metrics {
+    /// precision returns rate of gold boundaries in boundaries of model, zero if model has no boundaries.
+    pub fn precision(&self) -> f64 {
+        ratio(self.true_positive, self.true_positive + self.false_positive)
+    }
+
+    /// recall returns rate of boundaries of model in gold boundaries, zero if gold has no boundaries.
+    pub fn recall(&self) -> f64 {
+        ratio(self.true_positive, self.true_positive + self.false_negative)
+    }
+
+    /// f1 returns harmonic mean of precision and recall.
+    pub fn f1(&self) -> f64 {
+        ratio(
+            2 * self.true_positive,
+            2 * self.true_positive + self.false_positive + self.false_negative,
+        )
+    }
+
+    /// accuracy returns rate of gaps decided same as gold, zero if there are no gaps.
+    pub fn accuracy(&self) -> f64 {
+        ratio(
+            self.true_positive + self.true_negative,
+            self.true_positive + self.false_positive + self.false_negative + self.true_negative,
+        )
+    }
+
+    /// add counts decision at a gap.
+    fn add(&mut self, predicted: bool, gold: bool) {
+        match (predicted, gold) {
+            (true, true) => self.true_positive += 1,
+            (true, false) => self.false_positive += 1,
+            (false, true) => self.false_negative += 1,
+            (false, false) => self.true_negative += 1,
+        }
+    }
+}
+
+/// ratio returns n / d, zero if d is zero.
+fn ratio(n: usize, d: usize) -> f64 {
+    if d == 0 {
+        return 0.0;
+    }
+
+    n as f64 / d as f64
+}
+
+/// evaluate returns metrics of model splitting sentences at threshold.
+///
+/// Sentences are gold segmentation, phrases are separated by budoux::train::SEPARATOR (e.g. "これは▁テストです。").
+/// Decisions are same as budoux::parse_with_threshold.
+/// Gold boundaries where the parser never splits (inside a grapheme cluster or a protected span)
+/// are counted as false negatives.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `sentences` - segmented sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let metrics = budoux::eval::evaluate(model, &["これは▁テストです。"], budoux::DEFAULT_THRESHOLD);
+///
+/// assert_eq!(metrics.true_positive, 1);
+/// assert_eq!(metrics.f1(), 1.0);
+/// # }
+/// ```
+pub fn evaluate<M: Scorer + ?Sized, S: AsRef<str>>(
+    model: &M,
+    sentences: &[S],
+    threshold: i32,
+) -> Metrics {
+    sweep(model, sentences, &[threshold])[0].1
+}
+
+/// sweep returns metrics of model for each threshold, see budoux::eval::evaluate.
+///
+/// Scores of each sentence are computed only once, so it is faster than evaluate for each threshold.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `sentences` - segmented sentences.
+/// * `thresholds` - thresholds for splitting a sentences.
+///
+/// # Examples
+///
+/// Compare thresholds around budoux::DEFAULT_THRESHOLD.
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let sentences = ["これは▁テストです。", "今日は▁晴天です。"];
+/// let thresholds: Vec<i32> = (-5..=5).map(|i| budoux::DEFAULT_THRESHOLD + i * 200).collect();
+///
+/// for (threshold, metrics) in budoux::eval::sweep(model, &sentences, &thresholds) {
+///     println!("{}: {}", threshold, metrics.f1());
+/// }
+/// # }
+/// ```
+pub fn sweep<M: Scorer + ?Sized, S: AsRef<str>>(
+    model: &M,
+    sentences: &[S],
+    thresholds: &[i32],
+) -> Vec<(i32, Metrics)> {
+    let mut results: Vec<(i32, Metrics)> = thresholds
+        .iter()
+        .map(|threshold| (*threshold, Metrics::default()))
+        .collect();
+
+    let mut input = String::new();
+    let mut boundaries = Vec::new();
+
+    for sentence in sentences {
+        input.clear();
+        boundaries.clear();
+        for phrase in sentence.as_ref().split(SEPARATOR) {
+            input.push_str(phrase);
+            boundaries.push(input.len());
+        }
+
+        // Gold boundaries at the start or end of sentence are not gaps.
+        boundaries.dedup();
+        boundaries.retain(|&offset| 0 < offset && offset < input.len());
+
+        let mut found = 0;
+        for (offset, score) in crate::scores(model, &input) {
+            let gold = boundaries.binary_search(&offset).is_ok();
+            if gold {
+                found += 1;
+            }
+
+            for (threshold, metrics) in &mut results {
+                metrics.add(score > *threshold, gold);
+            }
+        }
+
+        // Gold boundaries which are not candidates can never be split by model.
+        for (_, metrics) in &mut results {
+            metrics.false_negative += boundaries.len() - found;
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metrics;
+
+    #[test]
+    fn test_metrics() {
+        let m = Metrics::default();
+        assert_eq!(m.precision(), 0.0);
+        assert_eq!(m.recall(), 0.0);
+        assert_eq!(m.f1(), 0.0);
+        assert_eq!(m.accuracy(), 0.0);
+
+        let m = Metrics {
+            true_positive: 3,
+            false_positive: 1,
+            false_negative: 2,
+            true_negative: 4,
+        };
+        assert_eq!(m.precision(), 0.75);
+        assert_eq!(m.recall(), 0.6);
+        assert_eq!(m.f1(), 6.0 / 9.0);
+        assert_eq!(m.accuracy(), 0.7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        m.insert(String::from("UW3:は"), 500);
+
+        let sentences = ["水と▁油", "空は▁青い", "水と油", "", "水▁▁と"];
+        assert_eq!(
+            super::evaluate(&m, &sentences, crate::DEFAULT_THRESHOLD),
+            Metrics {
+                true_positive: 1,
+                false_positive: 1,
+                false_negative: 2,
+                true_negative: 4,
+            }
+        );
+
+        let results = super::sweep(&m, &sentences, &[0, 1000, 3000]);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].0, 0);
+        assert_eq!(results[0].1.true_positive, 2);
+        assert_eq!(results[0].1.false_positive, 1);
+        assert_eq!(results[1].1, super::evaluate(&m, &sentences, 1000));
+        assert_eq!(results[2].1.true_positive, 0);
+        assert_eq!(results[2].1.false_negative, 3);
+
+        let strings: Vec<String> = sentences.iter().map(|v| v.to_string()).collect();
+        assert_eq!(super::sweep(&m, &strings, &[0, 1000, 3000]), results);
+    }
+
+    #[test]
+    fn test_evaluate_non_candidates() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+
+        // Gold boundaries inside a grapheme cluster or a protected span are missed.
+        let sentences = ["か▁\u{3099}と", "v1.▁2と▁水"];
+        assert_eq!(
+            super::evaluate(&m, &sentences, i32::MIN),
+            Metrics {
+                true_positive: 1,
+                false_positive: 2,
+                false_negative: 2,
+                true_negative: 0,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_evaluate_ja() {
+        let m = crate::models::default_japanese_model();
+        let sentences = [
+            "これは▁テストです。",
+            "今日は▁晴天です。",
+            "日本語の▁文章に▁おいて▁語の▁区切りに▁空白を▁挟んで▁記述する▁こと",
+        ];
+
+        let metrics = super::evaluate(m, &sentences, crate::DEFAULT_THRESHOLD);
+        assert_eq!(metrics.accuracy(), 1.0);
+        assert_eq!(metrics.f1(), 1.0);
+        assert_eq!(metrics.true_positive, 10);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::Schema;
+
+/// Slot is a value of window referred by feature template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// character, index 0 is i - 3.
+    Char(usize),
+    /// unicode block feature, index 0 is i - 3.
+    Block(usize),
+    /// previous result, index 0 is oldest.
+    Result(usize),
+}
+
+use Slot::{Block as B, Char as W, Result as P};
+
+/// templates returns feature templates used by schema.
+pub fn templates(schema: Schema) -> &'static [(&'static str, &'static [Slot])] {
+    match schema {
+        Schema::V1 => &TEMPLATES,
+        Schema::V2 => &TEMPLATES[5..18], // UW, BW and TW
+    }
+}
+
+/// TEMPLATES is feature templates, pair of key prefix and slots.
+/// Key of feature is concatenation of prefix and values of slots.
+pub const TEMPLATES: [(&str, &[Slot]); 42] = [
+    // UP is means unigram of previous results.
+    ("UP1:", &[P(0)]),
+    ("UP2:", &[P(1)]),
+    ("UP3:", &[P(2)]),
+    // BP is means bigram of previous results.
+    ("BP1:", &[P(0), P(1)]),
+    ("BP2:", &[P(1), P(2)]),
+    // UW is means unigram of words.
+    ("UW1:", &[W(0)]),
+    ("UW2:", &[W(1)]),
+    ("UW3:", &[W(2)]),
+    ("UW4:", &[W(3)]),
+    ("UW5:", &[W(4)]),
+    ("UW6:", &[W(5)]),
+    // BW is means bigram of words.
+    ("BW1:", &[W(1), W(2)]),
+    ("BW2:", &[W(2), W(3)]),
+    ("BW3:", &[W(3), W(4)]),
+    // TW is means trigram of words.
+    ("TW1:", &[W(0), W(1), W(2)]),
+    ("TW2:", &[W(1), W(2), W(3)]),
+    ("TW3:", &[W(2), W(3), W(4)]),
+    ("TW4:", &[W(3), W(4), W(5)]),
+    // UB is means unigram of unicode blocks.
+    ("UB1:", &[B(0)]),
+    ("UB2:", &[B(1)]),
+    ("UB3:", &[B(2)]),
+    ("UB4:", &[B(3)]),
+    ("UB5:", &[B(4)]),
+    ("UB6:", &[B(5)]),
+    // BB is means bigram of unicode blocks.
+    ("BB1:", &[B(1), B(2)]),
+    ("BB2:", &[B(2), B(3)]),
+    ("BB3:", &[B(3), B(4)]),
+    // TB is means trigram of unicode blocks.
+    ("TB1:", &[B(0), B(1), B(2)]),
+    ("TB2:", &[B(1), B(2), B(3)]),
+    ("TB3:", &[B(2), B(3), B(4)]),
+    ("TB4:", &[B(3), B(4), B(5)]),
+    // UQ is combination of UP and UB.
+    ("UQ1:", &[P(0), B(0)]),
+    ("UQ2:", &[P(1), B(1)]),
+    ("UQ3:", &[P(2), B(2)]),
+    // BQ is combination of UP and BB.
+    ("BQ1:", &[P(1), B(1), B(2)]),
+    ("BQ2:", &[P(1), B(2), B(3)]),
+    ("BQ3:", &[P(2), B(1), B(2)]),
+    ("BQ4:", &[P(2), B(2), B(3)]),
+    // TQ is combination of UP and TB.
+    ("TQ1:", &[P(1), B(0), B(1), B(2)]),
+    ("TQ2:", &[P(1), B(1), B(2), B(3)]),
+    ("TQ3:", &[P(2), B(0), B(1), B(2)]),
+    ("TQ4:", &[P(2), B(1), B(2), B(3)]),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::Schema;
+
+    #[test]
+    fn test_templates() {
+        assert_eq!(super::templates(Schema::V1).len(), 42);
+
+        let prefixes: Vec<&str> = super::templates(Schema::V2)
+            .iter()
+            .map(|(prefix, _)| *prefix)
+            .collect();
+        assert_eq!(
+            prefixes,
+            vec![
+                "UW1:", "UW2:", "UW3:", "UW4:", "UW5:", "UW6:", "BW1:", "BW2:", "BW3:", "TW1:",
+                "TW2:", "TW3:", "TW4:"
+            ]
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use alloc::string::String;
+
+use crate::{phrases_with_threshold, Scorer, DEFAULT_THRESHOLD};
+
+/// KEEP_ALL_START is start tag wrapping each phrase of budoux::Separator::KeepAll.
+const KEEP_ALL_START: &str = r#"<span style="word-break: keep-all">"#;
+
+/// KEEP_ALL_END is end tag of KEEP_ALL_START.
+const KEEP_ALL_END: &str = "</span>";
+
+/// Separator is how budoux::format marks phrase boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// Wbr inserts `<wbr>` between phrases, and escapes phrases as html.
+    Wbr,
+    /// ZeroWidthSpaceReference inserts `&#8203;` between phrases, and escapes phrases as html.
+    ZeroWidthSpaceReference,
+    /// ZeroWidthSpace inserts U+200B between phrases, phrases are not escaped.
+    /// It is for renderers other than html.
+    ZeroWidthSpace,
+    /// KeepAll wraps each phrase by `<span style="word-break: keep-all">`, and escapes phrases as html.
+    KeepAll,
+}
+
+impl Separator {
+    /// is_html returns true if output is html, so phrases are escaped.
+    pub fn is_html(&self) -> bool {
+        !matches!(self, Separator::ZeroWidthSpace)
+    }
+
+    /// separator returns string inserted between phrases.
+    fn separator(&self) -> &'static str {
+        match self {
+            Separator::Wbr => "<wbr>",
+            Separator::ZeroWidthSpaceReference => "&#8203;",
+            Separator::ZeroWidthSpace => "\u{200B}",
+            Separator::KeepAll => "",
+        }
+    }
+}
+
+/// format returns input with phrase boundaries marked by separator.
+/// It is shorthand for budoux::format_with_threshold(model, input, separator, budoux::DEFAULT_THRESHOLD).
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `separator` - how phrase boundaries are marked.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+///
+/// assert_eq!(
+///     budoux::format(model, "これはテストです。", budoux::Separator::Wbr),
+///     "これは<wbr>テストです。"
+/// );
+/// assert_eq!(
+///     budoux::format(model, "これはテストです。", budoux::Separator::ZeroWidthSpace),
+///     "これは\u{200B}テストです。"
+/// );
+/// # }
+/// ```
+pub fn format<M: Scorer + ?Sized>(model: &M, input: &str, separator: Separator) -> String {
+    format_with_threshold(model, input, separator, DEFAULT_THRESHOLD)
+}
+
+/// format_with_threshold returns input with phrase boundaries marked by separator.
+///
+/// Input is plain text, so it is escaped if separator makes html (see budoux::Separator::is_html).
+/// Use budoux::html::translate_html for html input.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `separator` - how phrase boundaries are marked.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let output = budoux::format_with_threshold(
+///     model,
+///     "これはテストです。",
+///     budoux::Separator::KeepAll,
+///     budoux::DEFAULT_THRESHOLD,
+/// );
+///
+/// assert_eq!(
+///     output,
+///     r#"<span style="word-break: keep-all">これは</span><span style="word-break: keep-all">テストです。</span>"#
+/// );
+/// # }
+/// ```
+pub fn format_with_threshold<M: Scorer + ?Sized>(
+    model: &M,
+    input: &str,
+    separator: Separator,
+    threshold: i32,
+) -> String {
+    let mut output = String::with_capacity(input.len() * 2);
+    if input.is_empty() {
+        return output;
+    }
+
+    for (i, phrase) in phrases_with_threshold(model, input, threshold).enumerate() {
+        if i > 0 {
+            output.push_str(separator.separator());
+        }
+
+        if separator == Separator::KeepAll {
+            output.push_str(KEEP_ALL_START);
+        }
+
+        if separator.is_html() {
+            escape(&mut output, phrase);
+        } else {
+            output.push_str(phrase);
+        }
+
+        if separator == Separator::KeepAll {
+            output.push_str(KEEP_ALL_END);
+        }
+    }
+
+    output
+}
+
+/// escape appends s to output with html special characters escaped.
+pub(crate) fn escape(output: &mut String, s: &str) {
+    for c in s.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Separator;
+
+    fn model() -> crate::Model {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+
+        m
+    }
+
+    #[test]
+    fn test_format() {
+        let m = model();
+
+        for separator in [
+            Separator::Wbr,
+            Separator::ZeroWidthSpaceReference,
+            Separator::ZeroWidthSpace,
+            Separator::KeepAll,
+        ] {
+            assert_eq!(super::format(&m, "", separator), "");
+        }
+
+        assert_eq!(super::format(&m, "水と油", Separator::Wbr), "水と<wbr>油");
+        assert_eq!(
+            super::format(&m, "水と油", Separator::ZeroWidthSpaceReference),
+            "水と&#8203;油"
+        );
+        assert_eq!(
+            super::format(&m, "水と油", Separator::ZeroWidthSpace),
+            "水と\u{200B}油"
+        );
+        assert_eq!(
+            super::format(&m, "水と油", Separator::KeepAll),
+            r#"<span style="word-break: keep-all">水と</span><span style="word-break: keep-all">油</span>"#
+        );
+        assert_eq!(
+            super::format(&m, "水", Separator::KeepAll),
+            r#"<span style="word-break: keep-all">水</span>"#
+        );
+        assert_eq!(
+            super::format_with_threshold(&m, "水と油", Separator::Wbr, 3000),
+            "水と油"
+        );
+    }
+
+    #[test]
+    fn test_format_escape() {
+        let m = model();
+        let input = r#"<b>と"A&B's"</b>"#;
+
+        assert_eq!(
+            super::format(&m, input, Separator::Wbr),
+            "&lt;b&gt;と<wbr>&quot;A&amp;B&#39;s&quot;&lt;/b&gt;"
+        );
+        assert_eq!(
+            super::format(&m, input, Separator::ZeroWidthSpaceReference),
+            "&lt;b&gt;と&#8203;&quot;A&amp;B&#39;s&quot;&lt;/b&gt;"
+        );
+        assert_eq!(
+            super::format(&m, input, Separator::ZeroWidthSpace),
+            "<b>と\u{200B}\"A&B's\"</b>"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_format_ja() {
+        let m = crate::models::default_japanese_model();
+        let input = "これはテストです。今日は晴天です。";
+
+        assert_eq!(
+            super::format(m, input, Separator::ZeroWidthSpace),
+            crate::parse(m, input).join("\u{200B}")
+        );
+        assert_eq!(
+            super::format(m, input, Separator::Wbr),
+            "これは<wbr>テストです。<wbr>今日は<wbr>晴天です。"
+        );
+    }
+}
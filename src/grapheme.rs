@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+
+use crate::grapheme_break::GRAPHEME_BREAKS;
+
+/// Property is grapheme cluster break property of a character (UAX #29).
+///
+/// Extended_Pictographic is treated as a property, because it is not overlapped with others.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+}
+
+impl Property {
+    /// of returns grapheme cluster break property of c.
+    pub fn of(c: char) -> Property {
+        let c = c as u32;
+
+        match GRAPHEME_BREAKS.binary_search_by(|&(first, last, _)| {
+            if last < c {
+                core::cmp::Ordering::Less
+            } else if first > c {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        }) {
+            Ok(i) => GRAPHEME_BREAKS[i].2,
+            Err(_) => Property::Other,
+        }
+    }
+}
+
+/// Clusters tracks extended grapheme clusters over consecutive character gaps.
+///
+/// is_boundary must be called for every gap of input in order,
+/// because emoji and regional indicator sequences depend on preceding characters.
+#[derive(Debug, Clone, Default)]
+pub struct Clusters {
+    emoji: bool,    // preceding characters end with Extended_Pictographic Extend*
+    regional: bool, // preceding characters end with odd number of Regional_Indicator
+}
+
+impl Clusters {
+    /// is_boundary returns true if the gap between before and after is a grapheme cluster boundary.
+    pub fn is_boundary(&mut self, before: char, after: char) -> bool {
+        use Property::*;
+
+        let (b, a) = (Property::of(before), Property::of(after));
+
+        let boundary = match (b, a) {
+            (CR, LF) => false,                                       // GB3
+            (CR | LF | Control, _) | (_, CR | LF | Control) => true, // GB4, GB5
+            (L, L | V | LV | LVT) => false,                          // GB6
+            (LV | V, V | T) => false,                                // GB7
+            (LVT | T, T) => false,                                   // GB8
+            (_, Extend | ZWJ | SpacingMark) => false,                // GB9, GB9a
+            (Prepend, _) => false,                                   // GB9b
+            (ZWJ, ExtendedPictographic) => !self.emoji,              // GB11
+            (RegionalIndicator, RegionalIndicator) => self.regional, // GB12, GB13
+            _ => true,                                               // GB999
+        };
+
+        self.emoji = match b {
+            ExtendedPictographic => true,
+            Extend => self.emoji,
+            _ => false,
+        };
+        self.regional = b == RegionalIndicator && !self.regional;
+
+        boundary
+    }
+}
+
+/// clusters splits input into extended grapheme clusters, empty input has no clusters.
+pub fn clusters(input: &str) -> Vec<&str> {
+    let mut state = Clusters::default();
+    let mut result = Vec::new();
+    let mut start = 0;
+
+    for ((offset, before), after) in input.char_indices().zip(input.chars().skip(1)) {
+        if state.is_boundary(before, after) {
+            let end = offset + before.len_utf8();
+            result.push(&input[start..end]);
+            start = end;
+        }
+    }
+    if start < input.len() {
+        result.push(&input[start..]);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clusters, Property};
+
+    #[test]
+    fn test_property() {
+        assert_eq!(Property::of('あ'), Property::Other);
+        assert_eq!(Property::of('\r'), Property::CR);
+        assert_eq!(Property::of('\u{3099}'), Property::Extend);
+        assert_eq!(Property::of('\u{E0100}'), Property::Extend);
+        assert_eq!(Property::of('\u{200D}'), Property::ZWJ);
+        assert_eq!(Property::of('🇯'), Property::RegionalIndicator);
+        assert_eq!(Property::of('👍'), Property::ExtendedPictographic);
+        assert_eq!(Property::of('각'), Property::LVT);
+        assert_eq!(Property::of('\u{0E31}'), Property::Extend);
+        assert_eq!(Property::of('\u{0E33}'), Property::SpacingMark);
+        assert_eq!(Property::of('\u{064B}'), Property::Extend);
+        assert_eq!(Property::of('\u{061C}'), Property::Control);
+    }
+
+    #[test]
+    fn test_clusters() {
+        assert_eq!(clusters(""), Vec::<&str>::new());
+        assert_eq!(clusters("あいう"), vec!["あ", "い", "う"]);
+        assert_eq!(clusters("a\r\nb"), vec!["a", "\r\n", "b"]);
+        assert_eq!(clusters("🇯🇵🇺🇸🇯"), vec!["🇯🇵", "🇺🇸", "🇯"]);
+        assert_eq!(clusters("👍🏽👍"), vec!["👍🏽", "👍"]);
+        assert_eq!(
+            clusters("👨\u{200D}👩\u{200D}👧です"),
+            vec!["👨\u{200D}👩\u{200D}👧", "で", "す"]
+        );
+        assert_eq!(clusters("a\u{200D}👧"), vec!["a\u{200D}", "👧"]);
+        assert_eq!(clusters("か\u{3099}き"), vec!["か\u{3099}", "き"]);
+        assert_eq!(clusters("葛\u{E0100}城"), vec!["葛\u{E0100}", "城"]);
+        assert_eq!(clusters("한국"), vec!["한", "국"]);
+        assert_eq!(clusters("วันนี้"), vec!["วั", "น", "นี้"]);
+        assert_eq!(
+            clusters("\u{1112}\u{1161}\u{11AB}"),
+            vec!["\u{1112}\u{1161}\u{11AB}"]
+        );
+    }
+}
@@ -0,0 +1,1460 @@
+// Code generated by gen/generate.go. DO NOT EDIT.
+// Generate from https://www.unicode.org/Public/15.0.0/ucd/auxiliary/GraphemeBreakProperty.txt
+// and https://www.unicode.org/Public/15.0.0/ucd/emoji/emoji-data.txt
+//
+
+use crate::grapheme::Property;
+
+/// GRAPHEME_BREAKS ranges of code points and their grapheme cluster break property, first, last and property.
+/// Extended_Pictographic of emoji data is included as a property, code points not in ranges are Other.
+pub static GRAPHEME_BREAKS: [(u32, u32, Property); 1449] = [
+    (0, 9, Property::Control),
+    (10, 10, Property::LF),
+    (11, 12, Property::Control),
+    (13, 13, Property::CR),
+    (14, 31, Property::Control),
+    (127, 159, Property::Control),
+    (169, 169, Property::ExtendedPictographic),
+    (173, 173, Property::Control),
+    (174, 174, Property::ExtendedPictographic),
+    (768, 879, Property::Extend),
+    (1155, 1161, Property::Extend),
+    (1425, 1469, Property::Extend),
+    (1471, 1471, Property::Extend),
+    (1473, 1474, Property::Extend),
+    (1476, 1477, Property::Extend),
+    (1479, 1479, Property::Extend),
+    (1536, 1541, Property::Prepend),
+    (1552, 1562, Property::Extend),
+    (1564, 1564, Property::Control),
+    (1611, 1631, Property::Extend),
+    (1648, 1648, Property::Extend),
+    (1750, 1756, Property::Extend),
+    (1757, 1757, Property::Prepend),
+    (1759, 1764, Property::Extend),
+    (1767, 1768, Property::Extend),
+    (1770, 1773, Property::Extend),
+    (1807, 1807, Property::Prepend),
+    (1809, 1809, Property::Extend),
+    (1840, 1866, Property::Extend),
+    (1958, 1968, Property::Extend),
+    (2027, 2035, Property::Extend),
+    (2045, 2045, Property::Extend),
+    (2070, 2073, Property::Extend),
+    (2075, 2083, Property::Extend),
+    (2085, 2087, Property::Extend),
+    (2089, 2093, Property::Extend),
+    (2137, 2139, Property::Extend),
+    (2192, 2193, Property::Prepend),
+    (2200, 2207, Property::Extend),
+    (2250, 2273, Property::Extend),
+    (2274, 2274, Property::Prepend),
+    (2275, 2306, Property::Extend),
+    (2307, 2307, Property::SpacingMark),
+    (2362, 2362, Property::Extend),
+    (2363, 2363, Property::SpacingMark),
+    (2364, 2364, Property::Extend),
+    (2366, 2368, Property::SpacingMark),
+    (2369, 2376, Property::Extend),
+    (2377, 2380, Property::SpacingMark),
+    (2381, 2381, Property::Extend),
+    (2382, 2383, Property::SpacingMark),
+    (2385, 2391, Property::Extend),
+    (2402, 2403, Property::Extend),
+    (2433, 2433, Property::Extend),
+    (2434, 2435, Property::SpacingMark),
+    (2492, 2492, Property::Extend),
+    (2494, 2494, Property::Extend),
+    (2495, 2496, Property::SpacingMark),
+    (2497, 2500, Property::Extend),
+    (2503, 2504, Property::SpacingMark),
+    (2507, 2508, Property::SpacingMark),
+    (2509, 2509, Property::Extend),
+    (2519, 2519, Property::Extend),
+    (2530, 2531, Property::Extend),
+    (2558, 2558, Property::Extend),
+    (2561, 2562, Property::Extend),
+    (2563, 2563, Property::SpacingMark),
+    (2620, 2620, Property::Extend),
+    (2622, 2624, Property::SpacingMark),
+    (2625, 2626, Property::Extend),
+    (2631, 2632, Property::Extend),
+    (2635, 2637, Property::Extend),
+    (2641, 2641, Property::Extend),
+    (2672, 2673, Property::Extend),
+    (2677, 2677, Property::Extend),
+    (2689, 2690, Property::Extend),
+    (2691, 2691, Property::SpacingMark),
+    (2748, 2748, Property::Extend),
+    (2750, 2752, Property::SpacingMark),
+    (2753, 2757, Property::Extend),
+    (2759, 2760, Property::Extend),
+    (2761, 2761, Property::SpacingMark),
+    (2763, 2764, Property::SpacingMark),
+    (2765, 2765, Property::Extend),
+    (2786, 2787, Property::Extend),
+    (2810, 2815, Property::Extend),
+    (2817, 2817, Property::Extend),
+    (2818, 2819, Property::SpacingMark),
+    (2876, 2876, Property::Extend),
+    (2878, 2879, Property::Extend),
+    (2880, 2880, Property::SpacingMark),
+    (2881, 2884, Property::Extend),
+    (2887, 2888, Property::SpacingMark),
+    (2891, 2892, Property::SpacingMark),
+    (2893, 2893, Property::Extend),
+    (2901, 2903, Property::Extend),
+    (2914, 2915, Property::Extend),
+    (2946, 2946, Property::Extend),
+    (3006, 3006, Property::Extend),
+    (3007, 3007, Property::SpacingMark),
+    (3008, 3008, Property::Extend),
+    (3009, 3010, Property::SpacingMark),
+    (3014, 3016, Property::SpacingMark),
+    (3018, 3020, Property::SpacingMark),
+    (3021, 3021, Property::Extend),
+    (3031, 3031, Property::Extend),
+    (3072, 3072, Property::Extend),
+    (3073, 3075, Property::SpacingMark),
+    (3076, 3076, Property::Extend),
+    (3132, 3132, Property::Extend),
+    (3134, 3136, Property::Extend),
+    (3137, 3140, Property::SpacingMark),
+    (3142, 3144, Property::Extend),
+    (3146, 3149, Property::Extend),
+    (3157, 3158, Property::Extend),
+    (3170, 3171, Property::Extend),
+    (3201, 3201, Property::Extend),
+    (3202, 3203, Property::SpacingMark),
+    (3260, 3260, Property::Extend),
+    (3262, 3262, Property::SpacingMark),
+    (3263, 3263, Property::Extend),
+    (3264, 3265, Property::SpacingMark),
+    (3266, 3266, Property::Extend),
+    (3267, 3268, Property::SpacingMark),
+    (3270, 3270, Property::Extend),
+    (3271, 3272, Property::SpacingMark),
+    (3274, 3275, Property::SpacingMark),
+    (3276, 3277, Property::Extend),
+    (3285, 3286, Property::Extend),
+    (3298, 3299, Property::Extend),
+    (3315, 3315, Property::SpacingMark),
+    (3328, 3329, Property::Extend),
+    (3330, 3331, Property::SpacingMark),
+    (3387, 3388, Property::Extend),
+    (3390, 3390, Property::Extend),
+    (3391, 3392, Property::SpacingMark),
+    (3393, 3396, Property::Extend),
+    (3398, 3400, Property::SpacingMark),
+    (3402, 3404, Property::SpacingMark),
+    (3405, 3405, Property::Extend),
+    (3406, 3406, Property::Prepend),
+    (3415, 3415, Property::Extend),
+    (3426, 3427, Property::Extend),
+    (3457, 3457, Property::Extend),
+    (3458, 3459, Property::SpacingMark),
+    (3530, 3530, Property::Extend),
+    (3535, 3535, Property::Extend),
+    (3536, 3537, Property::SpacingMark),
+    (3538, 3540, Property::Extend),
+    (3542, 3542, Property::Extend),
+    (3544, 3550, Property::SpacingMark),
+    (3551, 3551, Property::Extend),
+    (3570, 3571, Property::SpacingMark),
+    (3633, 3633, Property::Extend),
+    (3635, 3635, Property::SpacingMark),
+    (3636, 3642, Property::Extend),
+    (3655, 3662, Property::Extend),
+    (3761, 3761, Property::Extend),
+    (3763, 3763, Property::SpacingMark),
+    (3764, 3772, Property::Extend),
+    (3784, 3790, Property::Extend),
+    (3864, 3865, Property::Extend),
+    (3893, 3893, Property::Extend),
+    (3895, 3895, Property::Extend),
+    (3897, 3897, Property::Extend),
+    (3902, 3903, Property::SpacingMark),
+    (3953, 3966, Property::Extend),
+    (3967, 3967, Property::SpacingMark),
+    (3968, 3972, Property::Extend),
+    (3974, 3975, Property::Extend),
+    (3981, 3991, Property::Extend),
+    (3993, 4028, Property::Extend),
+    (4038, 4038, Property::Extend),
+    (4141, 4144, Property::Extend),
+    (4145, 4145, Property::SpacingMark),
+    (4146, 4151, Property::Extend),
+    (4153, 4154, Property::Extend),
+    (4155, 4156, Property::SpacingMark),
+    (4157, 4158, Property::Extend),
+    (4182, 4183, Property::SpacingMark),
+    (4184, 4185, Property::Extend),
+    (4190, 4192, Property::Extend),
+    (4209, 4212, Property::Extend),
+    (4226, 4226, Property::Extend),
+    (4228, 4228, Property::SpacingMark),
+    (4229, 4230, Property::Extend),
+    (4237, 4237, Property::Extend),
+    (4253, 4253, Property::Extend),
+    (4352, 4447, Property::L),
+    (4448, 4519, Property::V),
+    (4520, 4607, Property::T),
+    (4957, 4959, Property::Extend),
+    (5906, 5908, Property::Extend),
+    (5909, 5909, Property::SpacingMark),
+    (5938, 5939, Property::Extend),
+    (5940, 5940, Property::SpacingMark),
+    (5970, 5971, Property::Extend),
+    (6002, 6003, Property::Extend),
+    (6068, 6069, Property::Extend),
+    (6070, 6070, Property::SpacingMark),
+    (6071, 6077, Property::Extend),
+    (6078, 6085, Property::SpacingMark),
+    (6086, 6086, Property::Extend),
+    (6087, 6088, Property::SpacingMark),
+    (6089, 6099, Property::Extend),
+    (6109, 6109, Property::Extend),
+    (6155, 6157, Property::Extend),
+    (6158, 6158, Property::Control),
+    (6159, 6159, Property::Extend),
+    (6277, 6278, Property::Extend),
+    (6313, 6313, Property::Extend),
+    (6432, 6434, Property::Extend),
+    (6435, 6438, Property::SpacingMark),
+    (6439, 6440, Property::Extend),
+    (6441, 6443, Property::SpacingMark),
+    (6448, 6449, Property::SpacingMark),
+    (6450, 6450, Property::Extend),
+    (6451, 6456, Property::SpacingMark),
+    (6457, 6459, Property::Extend),
+    (6679, 6680, Property::Extend),
+    (6681, 6682, Property::SpacingMark),
+    (6683, 6683, Property::Extend),
+    (6741, 6741, Property::SpacingMark),
+    (6742, 6742, Property::Extend),
+    (6743, 6743, Property::SpacingMark),
+    (6744, 6750, Property::Extend),
+    (6752, 6752, Property::Extend),
+    (6754, 6754, Property::Extend),
+    (6757, 6764, Property::Extend),
+    (6765, 6770, Property::SpacingMark),
+    (6771, 6780, Property::Extend),
+    (6783, 6783, Property::Extend),
+    (6832, 6862, Property::Extend),
+    (6912, 6915, Property::Extend),
+    (6916, 6916, Property::SpacingMark),
+    (6964, 6970, Property::Extend),
+    (6971, 6971, Property::SpacingMark),
+    (6972, 6972, Property::Extend),
+    (6973, 6977, Property::SpacingMark),
+    (6978, 6978, Property::Extend),
+    (6979, 6980, Property::SpacingMark),
+    (7019, 7027, Property::Extend),
+    (7040, 7041, Property::Extend),
+    (7042, 7042, Property::SpacingMark),
+    (7073, 7073, Property::SpacingMark),
+    (7074, 7077, Property::Extend),
+    (7078, 7079, Property::SpacingMark),
+    (7080, 7081, Property::Extend),
+    (7082, 7082, Property::SpacingMark),
+    (7083, 7085, Property::Extend),
+    (7142, 7142, Property::Extend),
+    (7143, 7143, Property::SpacingMark),
+    (7144, 7145, Property::Extend),
+    (7146, 7148, Property::SpacingMark),
+    (7149, 7149, Property::Extend),
+    (7150, 7150, Property::SpacingMark),
+    (7151, 7153, Property::Extend),
+    (7154, 7155, Property::SpacingMark),
+    (7204, 7211, Property::SpacingMark),
+    (7212, 7219, Property::Extend),
+    (7220, 7221, Property::SpacingMark),
+    (7222, 7223, Property::Extend),
+    (7376, 7378, Property::Extend),
+    (7380, 7392, Property::Extend),
+    (7393, 7393, Property::SpacingMark),
+    (7394, 7400, Property::Extend),
+    (7405, 7405, Property::Extend),
+    (7412, 7412, Property::Extend),
+    (7415, 7415, Property::SpacingMark),
+    (7416, 7417, Property::Extend),
+    (7616, 7679, Property::Extend),
+    (8203, 8203, Property::Control),
+    (8204, 8204, Property::Extend),
+    (8205, 8205, Property::ZWJ),
+    (8206, 8207, Property::Control),
+    (8232, 8238, Property::Control),
+    (8252, 8252, Property::ExtendedPictographic),
+    (8265, 8265, Property::ExtendedPictographic),
+    (8288, 8303, Property::Control),
+    (8400, 8432, Property::Extend),
+    (8482, 8482, Property::ExtendedPictographic),
+    (8505, 8505, Property::ExtendedPictographic),
+    (8596, 8601, Property::ExtendedPictographic),
+    (8617, 8618, Property::ExtendedPictographic),
+    (8986, 8987, Property::ExtendedPictographic),
+    (9000, 9000, Property::ExtendedPictographic),
+    (9096, 9096, Property::ExtendedPictographic),
+    (9167, 9167, Property::ExtendedPictographic),
+    (9193, 9203, Property::ExtendedPictographic),
+    (9208, 9210, Property::ExtendedPictographic),
+    (9410, 9410, Property::ExtendedPictographic),
+    (9642, 9643, Property::ExtendedPictographic),
+    (9654, 9654, Property::ExtendedPictographic),
+    (9664, 9664, Property::ExtendedPictographic),
+    (9723, 9726, Property::ExtendedPictographic),
+    (9728, 9733, Property::ExtendedPictographic),
+    (9735, 9746, Property::ExtendedPictographic),
+    (9748, 9861, Property::ExtendedPictographic),
+    (9872, 9989, Property::ExtendedPictographic),
+    (9992, 10002, Property::ExtendedPictographic),
+    (10004, 10004, Property::ExtendedPictographic),
+    (10006, 10006, Property::ExtendedPictographic),
+    (10013, 10013, Property::ExtendedPictographic),
+    (10017, 10017, Property::ExtendedPictographic),
+    (10024, 10024, Property::ExtendedPictographic),
+    (10035, 10036, Property::ExtendedPictographic),
+    (10052, 10052, Property::ExtendedPictographic),
+    (10055, 10055, Property::ExtendedPictographic),
+    (10060, 10060, Property::ExtendedPictographic),
+    (10062, 10062, Property::ExtendedPictographic),
+    (10067, 10069, Property::ExtendedPictographic),
+    (10071, 10071, Property::ExtendedPictographic),
+    (10083, 10087, Property::ExtendedPictographic),
+    (10133, 10135, Property::ExtendedPictographic),
+    (10145, 10145, Property::ExtendedPictographic),
+    (10160, 10160, Property::ExtendedPictographic),
+    (10175, 10175, Property::ExtendedPictographic),
+    (10548, 10549, Property::ExtendedPictographic),
+    (11013, 11015, Property::ExtendedPictographic),
+    (11035, 11036, Property::ExtendedPictographic),
+    (11088, 11088, Property::ExtendedPictographic),
+    (11093, 11093, Property::ExtendedPictographic),
+    (11503, 11505, Property::Extend),
+    (11647, 11647, Property::Extend),
+    (11744, 11775, Property::Extend),
+    (12330, 12335, Property::Extend),
+    (12336, 12336, Property::ExtendedPictographic),
+    (12349, 12349, Property::ExtendedPictographic),
+    (12441, 12442, Property::Extend),
+    (12951, 12951, Property::ExtendedPictographic),
+    (12953, 12953, Property::ExtendedPictographic),
+    (42607, 42610, Property::Extend),
+    (42612, 42621, Property::Extend),
+    (42654, 42655, Property::Extend),
+    (42736, 42737, Property::Extend),
+    (43010, 43010, Property::Extend),
+    (43014, 43014, Property::Extend),
+    (43019, 43019, Property::Extend),
+    (43043, 43044, Property::SpacingMark),
+    (43045, 43046, Property::Extend),
+    (43047, 43047, Property::SpacingMark),
+    (43052, 43052, Property::Extend),
+    (43136, 43137, Property::SpacingMark),
+    (43188, 43203, Property::SpacingMark),
+    (43204, 43205, Property::Extend),
+    (43232, 43249, Property::Extend),
+    (43263, 43263, Property::Extend),
+    (43302, 43309, Property::Extend),
+    (43335, 43345, Property::Extend),
+    (43346, 43347, Property::SpacingMark),
+    (43360, 43388, Property::L),
+    (43392, 43394, Property::Extend),
+    (43395, 43395, Property::SpacingMark),
+    (43443, 43443, Property::Extend),
+    (43444, 43445, Property::SpacingMark),
+    (43446, 43449, Property::Extend),
+    (43450, 43451, Property::SpacingMark),
+    (43452, 43453, Property::Extend),
+    (43454, 43456, Property::SpacingMark),
+    (43493, 43493, Property::Extend),
+    (43561, 43566, Property::Extend),
+    (43567, 43568, Property::SpacingMark),
+    (43569, 43570, Property::Extend),
+    (43571, 43572, Property::SpacingMark),
+    (43573, 43574, Property::Extend),
+    (43587, 43587, Property::Extend),
+    (43596, 43596, Property::Extend),
+    (43597, 43597, Property::SpacingMark),
+    (43644, 43644, Property::Extend),
+    (43696, 43696, Property::Extend),
+    (43698, 43700, Property::Extend),
+    (43703, 43704, Property::Extend),
+    (43710, 43711, Property::Extend),
+    (43713, 43713, Property::Extend),
+    (43755, 43755, Property::SpacingMark),
+    (43756, 43757, Property::Extend),
+    (43758, 43759, Property::SpacingMark),
+    (43765, 43765, Property::SpacingMark),
+    (43766, 43766, Property::Extend),
+    (44003, 44004, Property::SpacingMark),
+    (44005, 44005, Property::Extend),
+    (44006, 44007, Property::SpacingMark),
+    (44008, 44008, Property::Extend),
+    (44009, 44010, Property::SpacingMark),
+    (44012, 44012, Property::SpacingMark),
+    (44013, 44013, Property::Extend),
+    (44032, 44032, Property::LV),
+    (44033, 44059, Property::LVT),
+    (44060, 44060, Property::LV),
+    (44061, 44087, Property::LVT),
+    (44088, 44088, Property::LV),
+    (44089, 44115, Property::LVT),
+    (44116, 44116, Property::LV),
+    (44117, 44143, Property::LVT),
+    (44144, 44144, Property::LV),
+    (44145, 44171, Property::LVT),
+    (44172, 44172, Property::LV),
+    (44173, 44199, Property::LVT),
+    (44200, 44200, Property::LV),
+    (44201, 44227, Property::LVT),
+    (44228, 44228, Property::LV),
+    (44229, 44255, Property::LVT),
+    (44256, 44256, Property::LV),
+    (44257, 44283, Property::LVT),
+    (44284, 44284, Property::LV),
+    (44285, 44311, Property::LVT),
+    (44312, 44312, Property::LV),
+    (44313, 44339, Property::LVT),
+    (44340, 44340, Property::LV),
+    (44341, 44367, Property::LVT),
+    (44368, 44368, Property::LV),
+    (44369, 44395, Property::LVT),
+    (44396, 44396, Property::LV),
+    (44397, 44423, Property::LVT),
+    (44424, 44424, Property::LV),
+    (44425, 44451, Property::LVT),
+    (44452, 44452, Property::LV),
+    (44453, 44479, Property::LVT),
+    (44480, 44480, Property::LV),
+    (44481, 44507, Property::LVT),
+    (44508, 44508, Property::LV),
+    (44509, 44535, Property::LVT),
+    (44536, 44536, Property::LV),
+    (44537, 44563, Property::LVT),
+    (44564, 44564, Property::LV),
+    (44565, 44591, Property::LVT),
+    (44592, 44592, Property::LV),
+    (44593, 44619, Property::LVT),
+    (44620, 44620, Property::LV),
+    (44621, 44647, Property::LVT),
+    (44648, 44648, Property::LV),
+    (44649, 44675, Property::LVT),
+    (44676, 44676, Property::LV),
+    (44677, 44703, Property::LVT),
+    (44704, 44704, Property::LV),
+    (44705, 44731, Property::LVT),
+    (44732, 44732, Property::LV),
+    (44733, 44759, Property::LVT),
+    (44760, 44760, Property::LV),
+    (44761, 44787, Property::LVT),
+    (44788, 44788, Property::LV),
+    (44789, 44815, Property::LVT),
+    (44816, 44816, Property::LV),
+    (44817, 44843, Property::LVT),
+    (44844, 44844, Property::LV),
+    (44845, 44871, Property::LVT),
+    (44872, 44872, Property::LV),
+    (44873, 44899, Property::LVT),
+    (44900, 44900, Property::LV),
+    (44901, 44927, Property::LVT),
+    (44928, 44928, Property::LV),
+    (44929, 44955, Property::LVT),
+    (44956, 44956, Property::LV),
+    (44957, 44983, Property::LVT),
+    (44984, 44984, Property::LV),
+    (44985, 45011, Property::LVT),
+    (45012, 45012, Property::LV),
+    (45013, 45039, Property::LVT),
+    (45040, 45040, Property::LV),
+    (45041, 45067, Property::LVT),
+    (45068, 45068, Property::LV),
+    (45069, 45095, Property::LVT),
+    (45096, 45096, Property::LV),
+    (45097, 45123, Property::LVT),
+    (45124, 45124, Property::LV),
+    (45125, 45151, Property::LVT),
+    (45152, 45152, Property::LV),
+    (45153, 45179, Property::LVT),
+    (45180, 45180, Property::LV),
+    (45181, 45207, Property::LVT),
+    (45208, 45208, Property::LV),
+    (45209, 45235, Property::LVT),
+    (45236, 45236, Property::LV),
+    (45237, 45263, Property::LVT),
+    (45264, 45264, Property::LV),
+    (45265, 45291, Property::LVT),
+    (45292, 45292, Property::LV),
+    (45293, 45319, Property::LVT),
+    (45320, 45320, Property::LV),
+    (45321, 45347, Property::LVT),
+    (45348, 45348, Property::LV),
+    (45349, 45375, Property::LVT),
+    (45376, 45376, Property::LV),
+    (45377, 45403, Property::LVT),
+    (45404, 45404, Property::LV),
+    (45405, 45431, Property::LVT),
+    (45432, 45432, Property::LV),
+    (45433, 45459, Property::LVT),
+    (45460, 45460, Property::LV),
+    (45461, 45487, Property::LVT),
+    (45488, 45488, Property::LV),
+    (45489, 45515, Property::LVT),
+    (45516, 45516, Property::LV),
+    (45517, 45543, Property::LVT),
+    (45544, 45544, Property::LV),
+    (45545, 45571, Property::LVT),
+    (45572, 45572, Property::LV),
+    (45573, 45599, Property::LVT),
+    (45600, 45600, Property::LV),
+    (45601, 45627, Property::LVT),
+    (45628, 45628, Property::LV),
+    (45629, 45655, Property::LVT),
+    (45656, 45656, Property::LV),
+    (45657, 45683, Property::LVT),
+    (45684, 45684, Property::LV),
+    (45685, 45711, Property::LVT),
+    (45712, 45712, Property::LV),
+    (45713, 45739, Property::LVT),
+    (45740, 45740, Property::LV),
+    (45741, 45767, Property::LVT),
+    (45768, 45768, Property::LV),
+    (45769, 45795, Property::LVT),
+    (45796, 45796, Property::LV),
+    (45797, 45823, Property::LVT),
+    (45824, 45824, Property::LV),
+    (45825, 45851, Property::LVT),
+    (45852, 45852, Property::LV),
+    (45853, 45879, Property::LVT),
+    (45880, 45880, Property::LV),
+    (45881, 45907, Property::LVT),
+    (45908, 45908, Property::LV),
+    (45909, 45935, Property::LVT),
+    (45936, 45936, Property::LV),
+    (45937, 45963, Property::LVT),
+    (45964, 45964, Property::LV),
+    (45965, 45991, Property::LVT),
+    (45992, 45992, Property::LV),
+    (45993, 46019, Property::LVT),
+    (46020, 46020, Property::LV),
+    (46021, 46047, Property::LVT),
+    (46048, 46048, Property::LV),
+    (46049, 46075, Property::LVT),
+    (46076, 46076, Property::LV),
+    (46077, 46103, Property::LVT),
+    (46104, 46104, Property::LV),
+    (46105, 46131, Property::LVT),
+    (46132, 46132, Property::LV),
+    (46133, 46159, Property::LVT),
+    (46160, 46160, Property::LV),
+    (46161, 46187, Property::LVT),
+    (46188, 46188, Property::LV),
+    (46189, 46215, Property::LVT),
+    (46216, 46216, Property::LV),
+    (46217, 46243, Property::LVT),
+    (46244, 46244, Property::LV),
+    (46245, 46271, Property::LVT),
+    (46272, 46272, Property::LV),
+    (46273, 46299, Property::LVT),
+    (46300, 46300, Property::LV),
+    (46301, 46327, Property::LVT),
+    (46328, 46328, Property::LV),
+    (46329, 46355, Property::LVT),
+    (46356, 46356, Property::LV),
+    (46357, 46383, Property::LVT),
+    (46384, 46384, Property::LV),
+    (46385, 46411, Property::LVT),
+    (46412, 46412, Property::LV),
+    (46413, 46439, Property::LVT),
+    (46440, 46440, Property::LV),
+    (46441, 46467, Property::LVT),
+    (46468, 46468, Property::LV),
+    (46469, 46495, Property::LVT),
+    (46496, 46496, Property::LV),
+    (46497, 46523, Property::LVT),
+    (46524, 46524, Property::LV),
+    (46525, 46551, Property::LVT),
+    (46552, 46552, Property::LV),
+    (46553, 46579, Property::LVT),
+    (46580, 46580, Property::LV),
+    (46581, 46607, Property::LVT),
+    (46608, 46608, Property::LV),
+    (46609, 46635, Property::LVT),
+    (46636, 46636, Property::LV),
+    (46637, 46663, Property::LVT),
+    (46664, 46664, Property::LV),
+    (46665, 46691, Property::LVT),
+    (46692, 46692, Property::LV),
+    (46693, 46719, Property::LVT),
+    (46720, 46720, Property::LV),
+    (46721, 46747, Property::LVT),
+    (46748, 46748, Property::LV),
+    (46749, 46775, Property::LVT),
+    (46776, 46776, Property::LV),
+    (46777, 46803, Property::LVT),
+    (46804, 46804, Property::LV),
+    (46805, 46831, Property::LVT),
+    (46832, 46832, Property::LV),
+    (46833, 46859, Property::LVT),
+    (46860, 46860, Property::LV),
+    (46861, 46887, Property::LVT),
+    (46888, 46888, Property::LV),
+    (46889, 46915, Property::LVT),
+    (46916, 46916, Property::LV),
+    (46917, 46943, Property::LVT),
+    (46944, 46944, Property::LV),
+    (46945, 46971, Property::LVT),
+    (46972, 46972, Property::LV),
+    (46973, 46999, Property::LVT),
+    (47000, 47000, Property::LV),
+    (47001, 47027, Property::LVT),
+    (47028, 47028, Property::LV),
+    (47029, 47055, Property::LVT),
+    (47056, 47056, Property::LV),
+    (47057, 47083, Property::LVT),
+    (47084, 47084, Property::LV),
+    (47085, 47111, Property::LVT),
+    (47112, 47112, Property::LV),
+    (47113, 47139, Property::LVT),
+    (47140, 47140, Property::LV),
+    (47141, 47167, Property::LVT),
+    (47168, 47168, Property::LV),
+    (47169, 47195, Property::LVT),
+    (47196, 47196, Property::LV),
+    (47197, 47223, Property::LVT),
+    (47224, 47224, Property::LV),
+    (47225, 47251, Property::LVT),
+    (47252, 47252, Property::LV),
+    (47253, 47279, Property::LVT),
+    (47280, 47280, Property::LV),
+    (47281, 47307, Property::LVT),
+    (47308, 47308, Property::LV),
+    (47309, 47335, Property::LVT),
+    (47336, 47336, Property::LV),
+    (47337, 47363, Property::LVT),
+    (47364, 47364, Property::LV),
+    (47365, 47391, Property::LVT),
+    (47392, 47392, Property::LV),
+    (47393, 47419, Property::LVT),
+    (47420, 47420, Property::LV),
+    (47421, 47447, Property::LVT),
+    (47448, 47448, Property::LV),
+    (47449, 47475, Property::LVT),
+    (47476, 47476, Property::LV),
+    (47477, 47503, Property::LVT),
+    (47504, 47504, Property::LV),
+    (47505, 47531, Property::LVT),
+    (47532, 47532, Property::LV),
+    (47533, 47559, Property::LVT),
+    (47560, 47560, Property::LV),
+    (47561, 47587, Property::LVT),
+    (47588, 47588, Property::LV),
+    (47589, 47615, Property::LVT),
+    (47616, 47616, Property::LV),
+    (47617, 47643, Property::LVT),
+    (47644, 47644, Property::LV),
+    (47645, 47671, Property::LVT),
+    (47672, 47672, Property::LV),
+    (47673, 47699, Property::LVT),
+    (47700, 47700, Property::LV),
+    (47701, 47727, Property::LVT),
+    (47728, 47728, Property::LV),
+    (47729, 47755, Property::LVT),
+    (47756, 47756, Property::LV),
+    (47757, 47783, Property::LVT),
+    (47784, 47784, Property::LV),
+    (47785, 47811, Property::LVT),
+    (47812, 47812, Property::LV),
+    (47813, 47839, Property::LVT),
+    (47840, 47840, Property::LV),
+    (47841, 47867, Property::LVT),
+    (47868, 47868, Property::LV),
+    (47869, 47895, Property::LVT),
+    (47896, 47896, Property::LV),
+    (47897, 47923, Property::LVT),
+    (47924, 47924, Property::LV),
+    (47925, 47951, Property::LVT),
+    (47952, 47952, Property::LV),
+    (47953, 47979, Property::LVT),
+    (47980, 47980, Property::LV),
+    (47981, 48007, Property::LVT),
+    (48008, 48008, Property::LV),
+    (48009, 48035, Property::LVT),
+    (48036, 48036, Property::LV),
+    (48037, 48063, Property::LVT),
+    (48064, 48064, Property::LV),
+    (48065, 48091, Property::LVT),
+    (48092, 48092, Property::LV),
+    (48093, 48119, Property::LVT),
+    (48120, 48120, Property::LV),
+    (48121, 48147, Property::LVT),
+    (48148, 48148, Property::LV),
+    (48149, 48175, Property::LVT),
+    (48176, 48176, Property::LV),
+    (48177, 48203, Property::LVT),
+    (48204, 48204, Property::LV),
+    (48205, 48231, Property::LVT),
+    (48232, 48232, Property::LV),
+    (48233, 48259, Property::LVT),
+    (48260, 48260, Property::LV),
+    (48261, 48287, Property::LVT),
+    (48288, 48288, Property::LV),
+    (48289, 48315, Property::LVT),
+    (48316, 48316, Property::LV),
+    (48317, 48343, Property::LVT),
+    (48344, 48344, Property::LV),
+    (48345, 48371, Property::LVT),
+    (48372, 48372, Property::LV),
+    (48373, 48399, Property::LVT),
+    (48400, 48400, Property::LV),
+    (48401, 48427, Property::LVT),
+    (48428, 48428, Property::LV),
+    (48429, 48455, Property::LVT),
+    (48456, 48456, Property::LV),
+    (48457, 48483, Property::LVT),
+    (48484, 48484, Property::LV),
+    (48485, 48511, Property::LVT),
+    (48512, 48512, Property::LV),
+    (48513, 48539, Property::LVT),
+    (48540, 48540, Property::LV),
+    (48541, 48567, Property::LVT),
+    (48568, 48568, Property::LV),
+    (48569, 48595, Property::LVT),
+    (48596, 48596, Property::LV),
+    (48597, 48623, Property::LVT),
+    (48624, 48624, Property::LV),
+    (48625, 48651, Property::LVT),
+    (48652, 48652, Property::LV),
+    (48653, 48679, Property::LVT),
+    (48680, 48680, Property::LV),
+    (48681, 48707, Property::LVT),
+    (48708, 48708, Property::LV),
+    (48709, 48735, Property::LVT),
+    (48736, 48736, Property::LV),
+    (48737, 48763, Property::LVT),
+    (48764, 48764, Property::LV),
+    (48765, 48791, Property::LVT),
+    (48792, 48792, Property::LV),
+    (48793, 48819, Property::LVT),
+    (48820, 48820, Property::LV),
+    (48821, 48847, Property::LVT),
+    (48848, 48848, Property::LV),
+    (48849, 48875, Property::LVT),
+    (48876, 48876, Property::LV),
+    (48877, 48903, Property::LVT),
+    (48904, 48904, Property::LV),
+    (48905, 48931, Property::LVT),
+    (48932, 48932, Property::LV),
+    (48933, 48959, Property::LVT),
+    (48960, 48960, Property::LV),
+    (48961, 48987, Property::LVT),
+    (48988, 48988, Property::LV),
+    (48989, 49015, Property::LVT),
+    (49016, 49016, Property::LV),
+    (49017, 49043, Property::LVT),
+    (49044, 49044, Property::LV),
+    (49045, 49071, Property::LVT),
+    (49072, 49072, Property::LV),
+    (49073, 49099, Property::LVT),
+    (49100, 49100, Property::LV),
+    (49101, 49127, Property::LVT),
+    (49128, 49128, Property::LV),
+    (49129, 49155, Property::LVT),
+    (49156, 49156, Property::LV),
+    (49157, 49183, Property::LVT),
+    (49184, 49184, Property::LV),
+    (49185, 49211, Property::LVT),
+    (49212, 49212, Property::LV),
+    (49213, 49239, Property::LVT),
+    (49240, 49240, Property::LV),
+    (49241, 49267, Property::LVT),
+    (49268, 49268, Property::LV),
+    (49269, 49295, Property::LVT),
+    (49296, 49296, Property::LV),
+    (49297, 49323, Property::LVT),
+    (49324, 49324, Property::LV),
+    (49325, 49351, Property::LVT),
+    (49352, 49352, Property::LV),
+    (49353, 49379, Property::LVT),
+    (49380, 49380, Property::LV),
+    (49381, 49407, Property::LVT),
+    (49408, 49408, Property::LV),
+    (49409, 49435, Property::LVT),
+    (49436, 49436, Property::LV),
+    (49437, 49463, Property::LVT),
+    (49464, 49464, Property::LV),
+    (49465, 49491, Property::LVT),
+    (49492, 49492, Property::LV),
+    (49493, 49519, Property::LVT),
+    (49520, 49520, Property::LV),
+    (49521, 49547, Property::LVT),
+    (49548, 49548, Property::LV),
+    (49549, 49575, Property::LVT),
+    (49576, 49576, Property::LV),
+    (49577, 49603, Property::LVT),
+    (49604, 49604, Property::LV),
+    (49605, 49631, Property::LVT),
+    (49632, 49632, Property::LV),
+    (49633, 49659, Property::LVT),
+    (49660, 49660, Property::LV),
+    (49661, 49687, Property::LVT),
+    (49688, 49688, Property::LV),
+    (49689, 49715, Property::LVT),
+    (49716, 49716, Property::LV),
+    (49717, 49743, Property::LVT),
+    (49744, 49744, Property::LV),
+    (49745, 49771, Property::LVT),
+    (49772, 49772, Property::LV),
+    (49773, 49799, Property::LVT),
+    (49800, 49800, Property::LV),
+    (49801, 49827, Property::LVT),
+    (49828, 49828, Property::LV),
+    (49829, 49855, Property::LVT),
+    (49856, 49856, Property::LV),
+    (49857, 49883, Property::LVT),
+    (49884, 49884, Property::LV),
+    (49885, 49911, Property::LVT),
+    (49912, 49912, Property::LV),
+    (49913, 49939, Property::LVT),
+    (49940, 49940, Property::LV),
+    (49941, 49967, Property::LVT),
+    (49968, 49968, Property::LV),
+    (49969, 49995, Property::LVT),
+    (49996, 49996, Property::LV),
+    (49997, 50023, Property::LVT),
+    (50024, 50024, Property::LV),
+    (50025, 50051, Property::LVT),
+    (50052, 50052, Property::LV),
+    (50053, 50079, Property::LVT),
+    (50080, 50080, Property::LV),
+    (50081, 50107, Property::LVT),
+    (50108, 50108, Property::LV),
+    (50109, 50135, Property::LVT),
+    (50136, 50136, Property::LV),
+    (50137, 50163, Property::LVT),
+    (50164, 50164, Property::LV),
+    (50165, 50191, Property::LVT),
+    (50192, 50192, Property::LV),
+    (50193, 50219, Property::LVT),
+    (50220, 50220, Property::LV),
+    (50221, 50247, Property::LVT),
+    (50248, 50248, Property::LV),
+    (50249, 50275, Property::LVT),
+    (50276, 50276, Property::LV),
+    (50277, 50303, Property::LVT),
+    (50304, 50304, Property::LV),
+    (50305, 50331, Property::LVT),
+    (50332, 50332, Property::LV),
+    (50333, 50359, Property::LVT),
+    (50360, 50360, Property::LV),
+    (50361, 50387, Property::LVT),
+    (50388, 50388, Property::LV),
+    (50389, 50415, Property::LVT),
+    (50416, 50416, Property::LV),
+    (50417, 50443, Property::LVT),
+    (50444, 50444, Property::LV),
+    (50445, 50471, Property::LVT),
+    (50472, 50472, Property::LV),
+    (50473, 50499, Property::LVT),
+    (50500, 50500, Property::LV),
+    (50501, 50527, Property::LVT),
+    (50528, 50528, Property::LV),
+    (50529, 50555, Property::LVT),
+    (50556, 50556, Property::LV),
+    (50557, 50583, Property::LVT),
+    (50584, 50584, Property::LV),
+    (50585, 50611, Property::LVT),
+    (50612, 50612, Property::LV),
+    (50613, 50639, Property::LVT),
+    (50640, 50640, Property::LV),
+    (50641, 50667, Property::LVT),
+    (50668, 50668, Property::LV),
+    (50669, 50695, Property::LVT),
+    (50696, 50696, Property::LV),
+    (50697, 50723, Property::LVT),
+    (50724, 50724, Property::LV),
+    (50725, 50751, Property::LVT),
+    (50752, 50752, Property::LV),
+    (50753, 50779, Property::LVT),
+    (50780, 50780, Property::LV),
+    (50781, 50807, Property::LVT),
+    (50808, 50808, Property::LV),
+    (50809, 50835, Property::LVT),
+    (50836, 50836, Property::LV),
+    (50837, 50863, Property::LVT),
+    (50864, 50864, Property::LV),
+    (50865, 50891, Property::LVT),
+    (50892, 50892, Property::LV),
+    (50893, 50919, Property::LVT),
+    (50920, 50920, Property::LV),
+    (50921, 50947, Property::LVT),
+    (50948, 50948, Property::LV),
+    (50949, 50975, Property::LVT),
+    (50976, 50976, Property::LV),
+    (50977, 51003, Property::LVT),
+    (51004, 51004, Property::LV),
+    (51005, 51031, Property::LVT),
+    (51032, 51032, Property::LV),
+    (51033, 51059, Property::LVT),
+    (51060, 51060, Property::LV),
+    (51061, 51087, Property::LVT),
+    (51088, 51088, Property::LV),
+    (51089, 51115, Property::LVT),
+    (51116, 51116, Property::LV),
+    (51117, 51143, Property::LVT),
+    (51144, 51144, Property::LV),
+    (51145, 51171, Property::LVT),
+    (51172, 51172, Property::LV),
+    (51173, 51199, Property::LVT),
+    (51200, 51200, Property::LV),
+    (51201, 51227, Property::LVT),
+    (51228, 51228, Property::LV),
+    (51229, 51255, Property::LVT),
+    (51256, 51256, Property::LV),
+    (51257, 51283, Property::LVT),
+    (51284, 51284, Property::LV),
+    (51285, 51311, Property::LVT),
+    (51312, 51312, Property::LV),
+    (51313, 51339, Property::LVT),
+    (51340, 51340, Property::LV),
+    (51341, 51367, Property::LVT),
+    (51368, 51368, Property::LV),
+    (51369, 51395, Property::LVT),
+    (51396, 51396, Property::LV),
+    (51397, 51423, Property::LVT),
+    (51424, 51424, Property::LV),
+    (51425, 51451, Property::LVT),
+    (51452, 51452, Property::LV),
+    (51453, 51479, Property::LVT),
+    (51480, 51480, Property::LV),
+    (51481, 51507, Property::LVT),
+    (51508, 51508, Property::LV),
+    (51509, 51535, Property::LVT),
+    (51536, 51536, Property::LV),
+    (51537, 51563, Property::LVT),
+    (51564, 51564, Property::LV),
+    (51565, 51591, Property::LVT),
+    (51592, 51592, Property::LV),
+    (51593, 51619, Property::LVT),
+    (51620, 51620, Property::LV),
+    (51621, 51647, Property::LVT),
+    (51648, 51648, Property::LV),
+    (51649, 51675, Property::LVT),
+    (51676, 51676, Property::LV),
+    (51677, 51703, Property::LVT),
+    (51704, 51704, Property::LV),
+    (51705, 51731, Property::LVT),
+    (51732, 51732, Property::LV),
+    (51733, 51759, Property::LVT),
+    (51760, 51760, Property::LV),
+    (51761, 51787, Property::LVT),
+    (51788, 51788, Property::LV),
+    (51789, 51815, Property::LVT),
+    (51816, 51816, Property::LV),
+    (51817, 51843, Property::LVT),
+    (51844, 51844, Property::LV),
+    (51845, 51871, Property::LVT),
+    (51872, 51872, Property::LV),
+    (51873, 51899, Property::LVT),
+    (51900, 51900, Property::LV),
+    (51901, 51927, Property::LVT),
+    (51928, 51928, Property::LV),
+    (51929, 51955, Property::LVT),
+    (51956, 51956, Property::LV),
+    (51957, 51983, Property::LVT),
+    (51984, 51984, Property::LV),
+    (51985, 52011, Property::LVT),
+    (52012, 52012, Property::LV),
+    (52013, 52039, Property::LVT),
+    (52040, 52040, Property::LV),
+    (52041, 52067, Property::LVT),
+    (52068, 52068, Property::LV),
+    (52069, 52095, Property::LVT),
+    (52096, 52096, Property::LV),
+    (52097, 52123, Property::LVT),
+    (52124, 52124, Property::LV),
+    (52125, 52151, Property::LVT),
+    (52152, 52152, Property::LV),
+    (52153, 52179, Property::LVT),
+    (52180, 52180, Property::LV),
+    (52181, 52207, Property::LVT),
+    (52208, 52208, Property::LV),
+    (52209, 52235, Property::LVT),
+    (52236, 52236, Property::LV),
+    (52237, 52263, Property::LVT),
+    (52264, 52264, Property::LV),
+    (52265, 52291, Property::LVT),
+    (52292, 52292, Property::LV),
+    (52293, 52319, Property::LVT),
+    (52320, 52320, Property::LV),
+    (52321, 52347, Property::LVT),
+    (52348, 52348, Property::LV),
+    (52349, 52375, Property::LVT),
+    (52376, 52376, Property::LV),
+    (52377, 52403, Property::LVT),
+    (52404, 52404, Property::LV),
+    (52405, 52431, Property::LVT),
+    (52432, 52432, Property::LV),
+    (52433, 52459, Property::LVT),
+    (52460, 52460, Property::LV),
+    (52461, 52487, Property::LVT),
+    (52488, 52488, Property::LV),
+    (52489, 52515, Property::LVT),
+    (52516, 52516, Property::LV),
+    (52517, 52543, Property::LVT),
+    (52544, 52544, Property::LV),
+    (52545, 52571, Property::LVT),
+    (52572, 52572, Property::LV),
+    (52573, 52599, Property::LVT),
+    (52600, 52600, Property::LV),
+    (52601, 52627, Property::LVT),
+    (52628, 52628, Property::LV),
+    (52629, 52655, Property::LVT),
+    (52656, 52656, Property::LV),
+    (52657, 52683, Property::LVT),
+    (52684, 52684, Property::LV),
+    (52685, 52711, Property::LVT),
+    (52712, 52712, Property::LV),
+    (52713, 52739, Property::LVT),
+    (52740, 52740, Property::LV),
+    (52741, 52767, Property::LVT),
+    (52768, 52768, Property::LV),
+    (52769, 52795, Property::LVT),
+    (52796, 52796, Property::LV),
+    (52797, 52823, Property::LVT),
+    (52824, 52824, Property::LV),
+    (52825, 52851, Property::LVT),
+    (52852, 52852, Property::LV),
+    (52853, 52879, Property::LVT),
+    (52880, 52880, Property::LV),
+    (52881, 52907, Property::LVT),
+    (52908, 52908, Property::LV),
+    (52909, 52935, Property::LVT),
+    (52936, 52936, Property::LV),
+    (52937, 52963, Property::LVT),
+    (52964, 52964, Property::LV),
+    (52965, 52991, Property::LVT),
+    (52992, 52992, Property::LV),
+    (52993, 53019, Property::LVT),
+    (53020, 53020, Property::LV),
+    (53021, 53047, Property::LVT),
+    (53048, 53048, Property::LV),
+    (53049, 53075, Property::LVT),
+    (53076, 53076, Property::LV),
+    (53077, 53103, Property::LVT),
+    (53104, 53104, Property::LV),
+    (53105, 53131, Property::LVT),
+    (53132, 53132, Property::LV),
+    (53133, 53159, Property::LVT),
+    (53160, 53160, Property::LV),
+    (53161, 53187, Property::LVT),
+    (53188, 53188, Property::LV),
+    (53189, 53215, Property::LVT),
+    (53216, 53216, Property::LV),
+    (53217, 53243, Property::LVT),
+    (53244, 53244, Property::LV),
+    (53245, 53271, Property::LVT),
+    (53272, 53272, Property::LV),
+    (53273, 53299, Property::LVT),
+    (53300, 53300, Property::LV),
+    (53301, 53327, Property::LVT),
+    (53328, 53328, Property::LV),
+    (53329, 53355, Property::LVT),
+    (53356, 53356, Property::LV),
+    (53357, 53383, Property::LVT),
+    (53384, 53384, Property::LV),
+    (53385, 53411, Property::LVT),
+    (53412, 53412, Property::LV),
+    (53413, 53439, Property::LVT),
+    (53440, 53440, Property::LV),
+    (53441, 53467, Property::LVT),
+    (53468, 53468, Property::LV),
+    (53469, 53495, Property::LVT),
+    (53496, 53496, Property::LV),
+    (53497, 53523, Property::LVT),
+    (53524, 53524, Property::LV),
+    (53525, 53551, Property::LVT),
+    (53552, 53552, Property::LV),
+    (53553, 53579, Property::LVT),
+    (53580, 53580, Property::LV),
+    (53581, 53607, Property::LVT),
+    (53608, 53608, Property::LV),
+    (53609, 53635, Property::LVT),
+    (53636, 53636, Property::LV),
+    (53637, 53663, Property::LVT),
+    (53664, 53664, Property::LV),
+    (53665, 53691, Property::LVT),
+    (53692, 53692, Property::LV),
+    (53693, 53719, Property::LVT),
+    (53720, 53720, Property::LV),
+    (53721, 53747, Property::LVT),
+    (53748, 53748, Property::LV),
+    (53749, 53775, Property::LVT),
+    (53776, 53776, Property::LV),
+    (53777, 53803, Property::LVT),
+    (53804, 53804, Property::LV),
+    (53805, 53831, Property::LVT),
+    (53832, 53832, Property::LV),
+    (53833, 53859, Property::LVT),
+    (53860, 53860, Property::LV),
+    (53861, 53887, Property::LVT),
+    (53888, 53888, Property::LV),
+    (53889, 53915, Property::LVT),
+    (53916, 53916, Property::LV),
+    (53917, 53943, Property::LVT),
+    (53944, 53944, Property::LV),
+    (53945, 53971, Property::LVT),
+    (53972, 53972, Property::LV),
+    (53973, 53999, Property::LVT),
+    (54000, 54000, Property::LV),
+    (54001, 54027, Property::LVT),
+    (54028, 54028, Property::LV),
+    (54029, 54055, Property::LVT),
+    (54056, 54056, Property::LV),
+    (54057, 54083, Property::LVT),
+    (54084, 54084, Property::LV),
+    (54085, 54111, Property::LVT),
+    (54112, 54112, Property::LV),
+    (54113, 54139, Property::LVT),
+    (54140, 54140, Property::LV),
+    (54141, 54167, Property::LVT),
+    (54168, 54168, Property::LV),
+    (54169, 54195, Property::LVT),
+    (54196, 54196, Property::LV),
+    (54197, 54223, Property::LVT),
+    (54224, 54224, Property::LV),
+    (54225, 54251, Property::LVT),
+    (54252, 54252, Property::LV),
+    (54253, 54279, Property::LVT),
+    (54280, 54280, Property::LV),
+    (54281, 54307, Property::LVT),
+    (54308, 54308, Property::LV),
+    (54309, 54335, Property::LVT),
+    (54336, 54336, Property::LV),
+    (54337, 54363, Property::LVT),
+    (54364, 54364, Property::LV),
+    (54365, 54391, Property::LVT),
+    (54392, 54392, Property::LV),
+    (54393, 54419, Property::LVT),
+    (54420, 54420, Property::LV),
+    (54421, 54447, Property::LVT),
+    (54448, 54448, Property::LV),
+    (54449, 54475, Property::LVT),
+    (54476, 54476, Property::LV),
+    (54477, 54503, Property::LVT),
+    (54504, 54504, Property::LV),
+    (54505, 54531, Property::LVT),
+    (54532, 54532, Property::LV),
+    (54533, 54559, Property::LVT),
+    (54560, 54560, Property::LV),
+    (54561, 54587, Property::LVT),
+    (54588, 54588, Property::LV),
+    (54589, 54615, Property::LVT),
+    (54616, 54616, Property::LV),
+    (54617, 54643, Property::LVT),
+    (54644, 54644, Property::LV),
+    (54645, 54671, Property::LVT),
+    (54672, 54672, Property::LV),
+    (54673, 54699, Property::LVT),
+    (54700, 54700, Property::LV),
+    (54701, 54727, Property::LVT),
+    (54728, 54728, Property::LV),
+    (54729, 54755, Property::LVT),
+    (54756, 54756, Property::LV),
+    (54757, 54783, Property::LVT),
+    (54784, 54784, Property::LV),
+    (54785, 54811, Property::LVT),
+    (54812, 54812, Property::LV),
+    (54813, 54839, Property::LVT),
+    (54840, 54840, Property::LV),
+    (54841, 54867, Property::LVT),
+    (54868, 54868, Property::LV),
+    (54869, 54895, Property::LVT),
+    (54896, 54896, Property::LV),
+    (54897, 54923, Property::LVT),
+    (54924, 54924, Property::LV),
+    (54925, 54951, Property::LVT),
+    (54952, 54952, Property::LV),
+    (54953, 54979, Property::LVT),
+    (54980, 54980, Property::LV),
+    (54981, 55007, Property::LVT),
+    (55008, 55008, Property::LV),
+    (55009, 55035, Property::LVT),
+    (55036, 55036, Property::LV),
+    (55037, 55063, Property::LVT),
+    (55064, 55064, Property::LV),
+    (55065, 55091, Property::LVT),
+    (55092, 55092, Property::LV),
+    (55093, 55119, Property::LVT),
+    (55120, 55120, Property::LV),
+    (55121, 55147, Property::LVT),
+    (55148, 55148, Property::LV),
+    (55149, 55175, Property::LVT),
+    (55176, 55176, Property::LV),
+    (55177, 55203, Property::LVT),
+    (55216, 55238, Property::V),
+    (55243, 55291, Property::T),
+    (64286, 64286, Property::Extend),
+    (65024, 65039, Property::Extend),
+    (65056, 65071, Property::Extend),
+    (65279, 65279, Property::Control),
+    (65438, 65439, Property::Extend),
+    (65520, 65531, Property::Control),
+    (66045, 66045, Property::Extend),
+    (66272, 66272, Property::Extend),
+    (66422, 66426, Property::Extend),
+    (68097, 68099, Property::Extend),
+    (68101, 68102, Property::Extend),
+    (68108, 68111, Property::Extend),
+    (68152, 68154, Property::Extend),
+    (68159, 68159, Property::Extend),
+    (68325, 68326, Property::Extend),
+    (68900, 68903, Property::Extend),
+    (69291, 69292, Property::Extend),
+    (69373, 69375, Property::Extend),
+    (69446, 69456, Property::Extend),
+    (69506, 69509, Property::Extend),
+    (69632, 69632, Property::SpacingMark),
+    (69633, 69633, Property::Extend),
+    (69634, 69634, Property::SpacingMark),
+    (69688, 69702, Property::Extend),
+    (69744, 69744, Property::Extend),
+    (69747, 69748, Property::Extend),
+    (69759, 69761, Property::Extend),
+    (69762, 69762, Property::SpacingMark),
+    (69808, 69810, Property::SpacingMark),
+    (69811, 69814, Property::Extend),
+    (69815, 69816, Property::SpacingMark),
+    (69817, 69818, Property::Extend),
+    (69821, 69821, Property::Prepend),
+    (69826, 69826, Property::Extend),
+    (69837, 69837, Property::Prepend),
+    (69888, 69890, Property::Extend),
+    (69927, 69931, Property::Extend),
+    (69932, 69932, Property::SpacingMark),
+    (69933, 69940, Property::Extend),
+    (69957, 69958, Property::SpacingMark),
+    (70003, 70003, Property::Extend),
+    (70016, 70017, Property::Extend),
+    (70018, 70018, Property::SpacingMark),
+    (70067, 70069, Property::SpacingMark),
+    (70070, 70078, Property::Extend),
+    (70079, 70080, Property::SpacingMark),
+    (70082, 70083, Property::Prepend),
+    (70089, 70092, Property::Extend),
+    (70094, 70094, Property::SpacingMark),
+    (70095, 70095, Property::Extend),
+    (70188, 70190, Property::SpacingMark),
+    (70191, 70193, Property::Extend),
+    (70194, 70195, Property::SpacingMark),
+    (70196, 70196, Property::Extend),
+    (70197, 70197, Property::SpacingMark),
+    (70198, 70199, Property::Extend),
+    (70206, 70206, Property::Extend),
+    (70209, 70209, Property::Extend),
+    (70367, 70367, Property::Extend),
+    (70368, 70370, Property::SpacingMark),
+    (70371, 70378, Property::Extend),
+    (70400, 70401, Property::Extend),
+    (70402, 70403, Property::SpacingMark),
+    (70459, 70460, Property::Extend),
+    (70462, 70462, Property::Extend),
+    (70463, 70463, Property::SpacingMark),
+    (70464, 70464, Property::Extend),
+    (70465, 70468, Property::SpacingMark),
+    (70471, 70472, Property::SpacingMark),
+    (70475, 70477, Property::SpacingMark),
+    (70487, 70487, Property::Extend),
+    (70498, 70499, Property::SpacingMark),
+    (70502, 70508, Property::Extend),
+    (70512, 70516, Property::Extend),
+    (70709, 70711, Property::SpacingMark),
+    (70712, 70719, Property::Extend),
+    (70720, 70721, Property::SpacingMark),
+    (70722, 70724, Property::Extend),
+    (70725, 70725, Property::SpacingMark),
+    (70726, 70726, Property::Extend),
+    (70750, 70750, Property::Extend),
+    (70832, 70832, Property::Extend),
+    (70833, 70834, Property::SpacingMark),
+    (70835, 70840, Property::Extend),
+    (70841, 70841, Property::SpacingMark),
+    (70842, 70842, Property::Extend),
+    (70843, 70844, Property::SpacingMark),
+    (70845, 70845, Property::Extend),
+    (70846, 70846, Property::SpacingMark),
+    (70847, 70848, Property::Extend),
+    (70849, 70849, Property::SpacingMark),
+    (70850, 70851, Property::Extend),
+    (71087, 71087, Property::Extend),
+    (71088, 71089, Property::SpacingMark),
+    (71090, 71093, Property::Extend),
+    (71096, 71099, Property::SpacingMark),
+    (71100, 71101, Property::Extend),
+    (71102, 71102, Property::SpacingMark),
+    (71103, 71104, Property::Extend),
+    (71132, 71133, Property::Extend),
+    (71216, 71218, Property::SpacingMark),
+    (71219, 71226, Property::Extend),
+    (71227, 71228, Property::SpacingMark),
+    (71229, 71229, Property::Extend),
+    (71230, 71230, Property::SpacingMark),
+    (71231, 71232, Property::Extend),
+    (71339, 71339, Property::Extend),
+    (71340, 71340, Property::SpacingMark),
+    (71341, 71341, Property::Extend),
+    (71342, 71343, Property::SpacingMark),
+    (71344, 71349, Property::Extend),
+    (71350, 71350, Property::SpacingMark),
+    (71351, 71351, Property::Extend),
+    (71453, 71455, Property::Extend),
+    (71458, 71461, Property::Extend),
+    (71462, 71462, Property::SpacingMark),
+    (71463, 71467, Property::Extend),
+    (71724, 71726, Property::SpacingMark),
+    (71727, 71735, Property::Extend),
+    (71736, 71736, Property::SpacingMark),
+    (71737, 71738, Property::Extend),
+    (71984, 71984, Property::Extend),
+    (71985, 71989, Property::SpacingMark),
+    (71991, 71992, Property::SpacingMark),
+    (71995, 71996, Property::Extend),
+    (71997, 71997, Property::SpacingMark),
+    (71998, 71998, Property::Extend),
+    (71999, 71999, Property::Prepend),
+    (72000, 72000, Property::SpacingMark),
+    (72001, 72001, Property::Prepend),
+    (72002, 72002, Property::SpacingMark),
+    (72003, 72003, Property::Extend),
+    (72145, 72147, Property::SpacingMark),
+    (72148, 72151, Property::Extend),
+    (72154, 72155, Property::Extend),
+    (72156, 72159, Property::SpacingMark),
+    (72160, 72160, Property::Extend),
+    (72164, 72164, Property::SpacingMark),
+    (72193, 72202, Property::Extend),
+    (72243, 72248, Property::Extend),
+    (72249, 72249, Property::SpacingMark),
+    (72250, 72250, Property::Prepend),
+    (72251, 72254, Property::Extend),
+    (72263, 72263, Property::Extend),
+    (72273, 72278, Property::Extend),
+    (72279, 72280, Property::SpacingMark),
+    (72281, 72283, Property::Extend),
+    (72324, 72329, Property::Prepend),
+    (72330, 72342, Property::Extend),
+    (72343, 72343, Property::SpacingMark),
+    (72344, 72345, Property::Extend),
+    (72751, 72751, Property::SpacingMark),
+    (72752, 72758, Property::Extend),
+    (72760, 72765, Property::Extend),
+    (72766, 72766, Property::SpacingMark),
+    (72767, 72767, Property::Extend),
+    (72850, 72871, Property::Extend),
+    (72873, 72873, Property::SpacingMark),
+    (72874, 72880, Property::Extend),
+    (72881, 72881, Property::SpacingMark),
+    (72882, 72883, Property::Extend),
+    (72884, 72884, Property::SpacingMark),
+    (72885, 72886, Property::Extend),
+    (73009, 73014, Property::Extend),
+    (73018, 73018, Property::Extend),
+    (73020, 73021, Property::Extend),
+    (73023, 73029, Property::Extend),
+    (73030, 73030, Property::Prepend),
+    (73031, 73031, Property::Extend),
+    (73098, 73102, Property::SpacingMark),
+    (73104, 73105, Property::Extend),
+    (73107, 73108, Property::SpacingMark),
+    (73109, 73109, Property::Extend),
+    (73110, 73110, Property::SpacingMark),
+    (73111, 73111, Property::Extend),
+    (73459, 73460, Property::Extend),
+    (73461, 73462, Property::SpacingMark),
+    (73472, 73473, Property::Extend),
+    (73474, 73474, Property::Prepend),
+    (73475, 73475, Property::SpacingMark),
+    (73524, 73525, Property::SpacingMark),
+    (73526, 73530, Property::Extend),
+    (73534, 73535, Property::SpacingMark),
+    (73536, 73536, Property::Extend),
+    (73537, 73537, Property::SpacingMark),
+    (73538, 73538, Property::Extend),
+    (78896, 78911, Property::Control),
+    (78912, 78912, Property::Extend),
+    (78919, 78933, Property::Extend),
+    (92912, 92916, Property::Extend),
+    (92976, 92982, Property::Extend),
+    (94031, 94031, Property::Extend),
+    (94033, 94087, Property::SpacingMark),
+    (94095, 94098, Property::Extend),
+    (94180, 94180, Property::Extend),
+    (94192, 94193, Property::SpacingMark),
+    (113821, 113822, Property::Extend),
+    (113824, 113827, Property::Control),
+    (118528, 118573, Property::Extend),
+    (118576, 118598, Property::Extend),
+    (119141, 119141, Property::Extend),
+    (119142, 119142, Property::SpacingMark),
+    (119143, 119145, Property::Extend),
+    (119149, 119149, Property::SpacingMark),
+    (119150, 119154, Property::Extend),
+    (119155, 119162, Property::Control),
+    (119163, 119170, Property::Extend),
+    (119173, 119179, Property::Extend),
+    (119210, 119213, Property::Extend),
+    (119362, 119364, Property::Extend),
+    (121344, 121398, Property::Extend),
+    (121403, 121452, Property::Extend),
+    (121461, 121461, Property::Extend),
+    (121476, 121476, Property::Extend),
+    (121499, 121503, Property::Extend),
+    (121505, 121519, Property::Extend),
+    (122880, 122886, Property::Extend),
+    (122888, 122904, Property::Extend),
+    (122907, 122913, Property::Extend),
+    (122915, 122916, Property::Extend),
+    (122918, 122922, Property::Extend),
+    (123023, 123023, Property::Extend),
+    (123184, 123190, Property::Extend),
+    (123566, 123566, Property::Extend),
+    (123628, 123631, Property::Extend),
+    (124140, 124143, Property::Extend),
+    (125136, 125142, Property::Extend),
+    (125252, 125258, Property::Extend),
+    (126976, 127231, Property::ExtendedPictographic),
+    (127245, 127247, Property::ExtendedPictographic),
+    (127279, 127279, Property::ExtendedPictographic),
+    (127340, 127345, Property::ExtendedPictographic),
+    (127358, 127359, Property::ExtendedPictographic),
+    (127374, 127374, Property::ExtendedPictographic),
+    (127377, 127386, Property::ExtendedPictographic),
+    (127405, 127461, Property::ExtendedPictographic),
+    (127462, 127487, Property::RegionalIndicator),
+    (127489, 127503, Property::ExtendedPictographic),
+    (127514, 127514, Property::ExtendedPictographic),
+    (127535, 127535, Property::ExtendedPictographic),
+    (127538, 127546, Property::ExtendedPictographic),
+    (127548, 127551, Property::ExtendedPictographic),
+    (127561, 127994, Property::ExtendedPictographic),
+    (127995, 127999, Property::Extend),
+    (128000, 128317, Property::ExtendedPictographic),
+    (128326, 128591, Property::ExtendedPictographic),
+    (128640, 128767, Property::ExtendedPictographic),
+    (128884, 128895, Property::ExtendedPictographic),
+    (128981, 129023, Property::ExtendedPictographic),
+    (129036, 129039, Property::ExtendedPictographic),
+    (129096, 129103, Property::ExtendedPictographic),
+    (129114, 129119, Property::ExtendedPictographic),
+    (129160, 129167, Property::ExtendedPictographic),
+    (129198, 129279, Property::ExtendedPictographic),
+    (129292, 129338, Property::ExtendedPictographic),
+    (129340, 129349, Property::ExtendedPictographic),
+    (129351, 129791, Property::ExtendedPictographic),
+    (130048, 131069, Property::ExtendedPictographic),
+    (917504, 917535, Property::Control),
+    (917536, 917631, Property::Extend),
+    (917632, 917759, Property::Control),
+    (917760, 917999, Property::Extend),
+    (918000, 921599, Property::Control),
+];
@@ -0,0 +1,533 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Scorer, DEFAULT_THRESHOLD};
+
+/// WRAPPER_START is start tag wrapping output, so browsers break lines only at inserted boundaries.
+const WRAPPER_START: &str = r#"<span style="word-break: keep-all; overflow-wrap: anywhere;">"#;
+
+/// WRAPPER_END is end tag of WRAPPER_START.
+const WRAPPER_END: &str = "</span>";
+
+/// SEPARATOR is inserted at phrase boundaries.
+const SEPARATOR: &str = "<wbr>";
+
+/// SKIP_ELEMENTS is elements which contents are not split, same as BudouX.
+/// Text in them is used for splitting around them.
+const SKIP_ELEMENTS: [&str; 36] = [
+    "abbr", "address", "audio", "br", "button", "canvas", "code", "data", "datalist", "dialog",
+    "embed", "form", "iframe", "img", "input", "kbd", "map", "math", "meter", "object", "output",
+    "picture", "progress", "rp", "rt", "ruby", "select", "slot", "svg", "textarea", "time", "var",
+    "video", "noscript", "template", "xmp",
+];
+
+/// HIDDEN_ELEMENTS is elements which contents are not rendered as text.
+/// Text in them is not split, and not used for splitting.
+const HIDDEN_ELEMENTS: [&str; 4] = ["head", "script", "style", "title"];
+
+/// RAW_TEXT_ELEMENTS is elements which contents are not markup, such as scripts.
+const RAW_TEXT_ELEMENTS: [&str; 5] = ["script", "style", "textarea", "title", "xmp"];
+
+/// VOID_ELEMENTS is elements which have no end tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// translate_html returns html with phrase boundaries inserted into text.
+/// It is shorthand for budoux::html::translate_html_with_threshold(model, html, budoux::DEFAULT_THRESHOLD).
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `html` - html fragment.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let html = budoux::html::translate_html(model, "これは<b>テスト</b>です。");
+///
+/// assert_eq!(
+///     html,
+///     r#"<span style="word-break: keep-all; overflow-wrap: anywhere;">これは<b><wbr>テスト</b>です。</span>"#
+/// );
+/// # }
+/// ```
+pub fn translate_html<M: Scorer + ?Sized>(model: &M, html: &str) -> String {
+    translate_html_with_threshold(model, html, DEFAULT_THRESHOLD)
+}
+
+/// translate_html_with_threshold returns html with phrase boundaries inserted into text.
+///
+/// Markup is kept as it is, and `<wbr>` is inserted at phrase boundaries of text.
+/// Text is concatenated across tags (e.g. `<b>`) to find boundaries, and character references (e.g. `&amp;`) are never split.
+/// Contents of elements such as `<code>` and `<ruby>` are not split, and contents of `<script>` and `<style>` are not used for splitting.
+/// Output is wrapped by `<span>` with `word-break: keep-all` style, so browsers break lines only at phrase boundaries.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `html` - html fragment.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let html = budoux::html::translate_html_with_threshold(model, "これは<b>テスト</b>です。", 100000000);
+///
+/// assert_eq!(
+///     html,
+///     r#"<span style="word-break: keep-all; overflow-wrap: anywhere;">これは<b>テスト</b>です。</span>"#
+/// );
+/// # }
+/// ```
+pub fn translate_html_with_threshold<M: Scorer + ?Sized>(
+    model: &M,
+    html: &str,
+    threshold: i32,
+) -> String {
+    let text = Text::new(html);
+    let offsets = crate::boundaries(model, &text.content, threshold);
+
+    let mut output = String::with_capacity(
+        WRAPPER_START.len() + html.len() + offsets.len() * SEPARATOR.len() + WRAPPER_END.len(),
+    );
+    output.push_str(WRAPPER_START);
+
+    let mut start = 0;
+    for end in text.html_offsets(&offsets) {
+        output.push_str(&html[start..end]);
+        output.push_str(SEPARATOR);
+        start = end;
+    }
+    output.push_str(&html[start..]);
+
+    output.push_str(WRAPPER_END);
+    output
+}
+
+/// Text is text content of html, used for splitting.
+#[derive(Debug, Default)]
+struct Text {
+    /// content is concatenated text, character references are decoded.
+    content: String,
+    /// units is characters (or character references) of content.
+    units: Vec<Unit>,
+}
+
+/// Unit is a character of text content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Unit {
+    /// offset is byte offset in text content.
+    offset: usize,
+    /// start is byte offset in html.
+    start: usize,
+    /// end is byte offset of the end in html.
+    end: usize,
+    /// skip is true if the character is in SKIP_ELEMENTS.
+    skip: bool,
+}
+
+impl Text {
+    /// new returns text content of html.
+    fn new(html: &str) -> Self {
+        let mut text = Text::default();
+        let mut skip: Vec<String> = Vec::new(); // open elements of SKIP_ELEMENTS
+        let mut hidden: Vec<String> = Vec::new(); // open elements of HIDDEN_ELEMENTS
+
+        for token in Tokenizer::new(html) {
+            match token {
+                Token::Text(start, end) => {
+                    if hidden.is_empty() {
+                        text.push(html, start, end, !skip.is_empty());
+                    }
+                }
+                Token::StartTag(name, self_closing) => {
+                    if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                        continue;
+                    }
+                    if HIDDEN_ELEMENTS.contains(&name.as_str()) {
+                        hidden.push(name);
+                    } else if SKIP_ELEMENTS.contains(&name.as_str()) {
+                        skip.push(name);
+                    }
+                }
+                Token::EndTag(name) => {
+                    for open in [&mut hidden, &mut skip] {
+                        if let Some(i) = open.iter().rposition(|v| *v == name) {
+                            open.truncate(i);
+                        }
+                    }
+                }
+                Token::Other => {}
+            }
+        }
+
+        text
+    }
+
+    /// push appends characters of html[start..end] to content.
+    fn push(&mut self, html: &str, start: usize, end: usize, skip: bool) {
+        let mut i = start;
+        while i < end {
+            let (c, size) = match decode_reference(&html[i..end]) {
+                Some(v) => v,
+                None => {
+                    let c = html[i..].chars().next().unwrap();
+                    (c, c.len_utf8())
+                }
+            };
+
+            self.units.push(Unit {
+                offset: self.content.len(),
+                start: i,
+                end: i + size,
+                skip,
+            });
+            self.content.push(c);
+            i += size;
+        }
+    }
+
+    /// html_offsets returns byte offsets in html of sorted byte offsets in content.
+    ///
+    /// A boundary is placed before the character after it.
+    /// If the character is in SKIP_ELEMENTS, it is placed after the character before it instead,
+    /// and dropped if both characters are in SKIP_ELEMENTS.
+    fn html_offsets<'a>(&'a self, offsets: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+        let mut units = self.units.windows(2);
+
+        offsets.iter().filter_map(move |offset| {
+            let (prev, next) = units
+                .find(|v| v[1].offset == *offset)
+                .map(|v| (v[0], v[1]))?;
+
+            match (prev.skip, next.skip) {
+                (_, false) => Some(next.start),
+                (false, true) => Some(prev.end),
+                (true, true) => None,
+            }
+        })
+    }
+}
+
+/// decode_reference returns character and byte length of character reference at the start of s.
+/// None is returned if s does not start with character reference.
+///
+/// Unknown named references are decoded as U+FFFD, so they are kept as a single character.
+fn decode_reference(s: &str) -> Option<(char, usize)> {
+    let body = s.strip_prefix('&')?;
+    let end = body.find(';')?;
+    let name = &body[..end];
+
+    let c = if let Some(num) = name.strip_prefix('#') {
+        let code = match num.strip_prefix(|c| c == 'x' || c == 'X') {
+            Some(hex) if !hex.is_empty() && hex.bytes().all(|v| v.is_ascii_hexdigit()) => {
+                u32::from_str_radix(hex, 16).ok()
+            }
+            None if !num.is_empty() && num.bytes().all(|v| v.is_ascii_digit()) => num.parse().ok(),
+            _ => return None,
+        };
+        code.and_then(char::from_u32).unwrap_or('\u{FFFD}')
+    } else {
+        if name.is_empty() || !name.bytes().all(|v| v.is_ascii_alphanumeric()) {
+            return None;
+        }
+        match name {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            "nbsp" => '\u{A0}',
+            _ => '\u{FFFD}',
+        }
+    };
+
+    Some((c, end + 2))
+}
+
+/// Token is token of html.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    /// Text is byte range of text in html.
+    Text(usize, usize),
+    /// StartTag is lowercase tag name, and whether the tag is self closing.
+    StartTag(String, bool),
+    /// EndTag is lowercase tag name.
+    EndTag(String),
+    /// Other is comment, doctype or processing instruction.
+    Other,
+}
+
+/// Tokenizer is iterator over tokens of html.
+///
+/// It only recognizes tags and comments, which are enough to find text.
+/// Malformed markup is treated as text, as well as browsers.
+#[derive(Debug)]
+struct Tokenizer<'a> {
+    html: &'a str,
+    pos: usize,
+    raw_text: Option<String>, // element of RAW_TEXT_ELEMENTS being read
+}
+
+impl<'a> Tokenizer<'a> {
+    /// new returns tokenizer of html.
+    fn new(html: &'a str) -> Self {
+        Tokenizer {
+            html,
+            pos: 0,
+            raw_text: None,
+        }
+    }
+
+    /// markup returns token and its byte length, if s starts with markup.
+    fn markup(s: &str) -> Option<(Token, usize)> {
+        let bytes = s.as_bytes();
+        if bytes.first() != Some(&b'<') {
+            return None;
+        }
+
+        if let Some(comment) = s.strip_prefix("<!--") {
+            let len = comment.find("-->").map_or(s.len(), |v| v + 7);
+            return Some((Token::Other, len));
+        }
+
+        match bytes.get(1) {
+            Some(b'!') | Some(b'?') => {
+                let len = s.find('>').map_or(s.len(), |v| v + 1);
+                Some((Token::Other, len))
+            }
+            Some(b'/') if bytes.get(2).is_some_and(|v| v.is_ascii_alphabetic()) => {
+                let len = s.find('>').map_or(s.len(), |v| v + 1);
+                Some((Token::EndTag(tag_name(&s[2..])), len))
+            }
+            Some(v) if v.is_ascii_alphabetic() => {
+                let len = tag_len(s);
+                let self_closing = s[..len].ends_with("/>");
+                Some((Token::StartTag(tag_name(&s[1..]), self_closing), len))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.html[self.pos..];
+        if rest.is_empty() {
+            return None;
+        }
+        let start = self.pos;
+
+        // Contents of raw text elements are text until its end tag.
+        if let Some(name) = self.raw_text.take() {
+            let len = find_end_tag(rest, &name).unwrap_or(rest.len());
+            if len > 0 {
+                self.pos += len;
+                return Some(Token::Text(start, self.pos));
+            }
+        }
+
+        if let Some((token, len)) = Tokenizer::markup(rest) {
+            if let Token::StartTag(name, false) = &token {
+                if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                    self.raw_text = Some(name.clone());
+                }
+            }
+
+            self.pos += len;
+            return Some(token);
+        }
+
+        // Text continues until the next markup.
+        let mut len = rest.len();
+        for (i, _) in rest.match_indices('<').skip_while(|(i, _)| *i == 0) {
+            if Tokenizer::markup(&rest[i..]).is_some() {
+                len = i;
+                break;
+            }
+        }
+
+        self.pos += len;
+        Some(Token::Text(start, self.pos))
+    }
+}
+
+/// tag_name returns lowercase tag name at the start of s.
+fn tag_name(s: &str) -> String {
+    let end = s
+        .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+        .unwrap_or(s.len());
+
+    s[..end].to_ascii_lowercase()
+}
+
+/// tag_len returns byte length of tag at the start of s, quoted attribute values may contain '>'.
+fn tag_len(s: &str) -> usize {
+    let mut quote = None;
+
+    for (i, c) in s.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '>' => return i + 1,
+            None => {}
+        }
+    }
+
+    s.len()
+}
+
+/// find_end_tag returns byte offset of end tag of element name in s.
+fn find_end_tag(s: &str, name: &str) -> Option<usize> {
+    s.match_indices("</").map(|(i, _)| i).find(|&i| {
+        let tag = &s[i + 2..];
+        tag.len() >= name.len()
+            && tag.as_bytes()[..name.len()].eq_ignore_ascii_case(name.as_bytes())
+            && tag[name.len()..]
+                .chars()
+                .next()
+                .map_or(true, |c| c.is_ascii_whitespace() || c == '/' || c == '>')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Token;
+
+    fn wrap(html: &str) -> String {
+        format!("{}{}{}", super::WRAPPER_START, html, super::WRAPPER_END)
+    }
+
+    fn model() -> crate::Model {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        m.insert(String::from("UW3:&"), 2000);
+
+        m
+    }
+
+    #[test]
+    fn test_translate_html() {
+        let m = model();
+
+        assert_eq!(super::translate_html(&m, ""), wrap(""));
+        assert_eq!(super::translate_html(&m, "水と油"), wrap("水と<wbr>油"));
+        assert_eq!(
+            super::translate_html(&m, "<p>水<b>と</b>油</p>"),
+            wrap("<p>水<b>と</b><wbr>油</p>")
+        );
+        assert_eq!(
+            super::translate_html(&m, r#"<a href="/" title="水と油>">水と油</a>"#),
+            wrap(r#"<a href="/" title="水と油>">水と<wbr>油</a>"#)
+        );
+        assert_eq!(
+            super::translate_html(&m, "<!-- 水と油 -->水と油<br/>水と油"),
+            wrap("<!-- 水と油 -->水と<wbr>油<br/>水と<wbr>油")
+        );
+        assert_eq!(super::translate_html(&m, "水<と油"), wrap("水<と<wbr>油"));
+        assert_eq!(
+            super::translate_html_with_threshold(&m, "<p>水と油</p>", 3000),
+            wrap("<p>水と油</p>")
+        );
+    }
+
+    #[test]
+    fn test_translate_html_skip() {
+        let m = model();
+
+        assert_eq!(
+            super::translate_html(&m, "<SCRIPT>if (a</b) { '水と油' }</SCRIPT>水と油"),
+            wrap("<SCRIPT>if (a</b) { '水と油' }</SCRIPT>水と<wbr>油")
+        );
+        assert_eq!(
+            super::translate_html(&m, "<style>p::after { content: '水と油' }</style>"),
+            wrap("<style>p::after { content: '水と油' }</style>")
+        );
+        assert_eq!(
+            super::translate_html(&m, "<code>水と<i>油</i></code>水と油"),
+            wrap("<code>水と<i>油</i></code>水と<wbr>油")
+        );
+        assert_eq!(
+            super::translate_html(&m, "<code>水と</code>油"),
+            wrap("<code>水と</code><wbr>油")
+        );
+        assert_eq!(
+            super::translate_html(&m, "水と<ruby>油<rt>あぶら</rt></ruby>"),
+            wrap("水と<wbr><ruby>油<rt>あぶら</rt></ruby>")
+        );
+    }
+
+    #[test]
+    fn test_translate_html_reference() {
+        let m = model();
+
+        assert_eq!(
+            super::translate_html(&m, "水&amp;油と&#x6C34;"),
+            wrap("水&amp;<wbr>油と<wbr>&#x6C34;")
+        );
+        assert_eq!(
+            super::translate_html(&m, "水&#38;油&copy;と&unknown;"),
+            wrap("水&#38;<wbr>油&copy;と<wbr>&unknown;")
+        );
+        assert_eq!(
+            super::translate_html(&m, "水&油 & と&;"),
+            wrap("水&<wbr>油 &<wbr> と<wbr>&<wbr>;")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_translate_html_ja() {
+        let m = crate::models::default_japanese_model();
+
+        assert_eq!(
+            super::translate_html(m, "<p>今日は<b>とても</b>天気です。</p>"),
+            wrap("<p>今日は<b><wbr>とても</b><wbr>天気です。</p>")
+        );
+    }
+
+    #[test]
+    fn test_tokenizer() {
+        let html = r#"<!DOCTYPE html><P class='a>b'>水<br/>と</p>< 油<script>a<b</script>"#;
+        let tokens: Vec<Token> = super::Tokenizer::new(html).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Other,
+                Token::StartTag(String::from("p"), false),
+                Token::Text(30, 33),
+                Token::StartTag(String::from("br"), true),
+                Token::Text(38, 41),
+                Token::EndTag(String::from("p")),
+                Token::Text(45, 50),
+                Token::StartTag(String::from("script"), false),
+                Token::Text(58, 61),
+                Token::EndTag(String::from("script")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_reference() {
+        assert_eq!(super::decode_reference("&amp;"), Some(('&', 5)));
+        assert_eq!(super::decode_reference("&lt;b"), Some(('<', 4)));
+        assert_eq!(super::decode_reference("&#12354;"), Some(('あ', 8)));
+        assert_eq!(super::decode_reference("&#x3042;"), Some(('あ', 8)));
+        assert_eq!(super::decode_reference("&#xD800;"), Some(('\u{FFFD}', 8)));
+        assert_eq!(super::decode_reference("&hellip;"), Some(('\u{FFFD}', 8)));
+        assert_eq!(super::decode_reference("&;"), None);
+        assert_eq!(super::decode_reference("&#;"), None);
+        assert_eq!(super::decode_reference("&#x;"), None);
+        assert_eq!(super::decode_reference("& b;"), None);
+        assert_eq!(super::decode_reference("&amp"), None);
+        assert_eq!(super::decode_reference("amp;"), None);
+    }
+}
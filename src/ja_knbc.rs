@@ -5,496 +5,528 @@
 // BudouX | Apache License 2.0 | https://github.com/google/budoux/blob/main/LICENSE
 //
 
-use once_cell::sync::Lazy;
-
 /// MODEL reference to trained machine learning model.
-pub static MODEL: Lazy<crate::Model> = Lazy::new(|| {
-    let mut m = crate::Model::new();
-
-    m.insert(String::from("BB1:108107"), 307);
-    m.insert(String::from("BB1:108108"), -77);
-    m.insert(String::from("BB1:109120"), -359);
-    m.insert(String::from("BB1:162120"), 397);
-    m.insert(String::from("BB1:162162"), -251);
-    m.insert(String::from("BB2:072999"), 78);
-    m.insert(String::from("BB2:087999"), 836);
-    m.insert(String::from("BB2:107162"), -766);
-    m.insert(String::from("BB2:107999"), 3411);
-    m.insert(String::from("BB2:108072"), -977);
-    m.insert(String::from("BB2:108108"), -92);
-    m.insert(String::from("BB2:108120"), 1817);
-    m.insert(String::from("BB2:108162"), 338);
-    m.insert(String::from("BB2:108999"), 1504);
-    m.insert(String::from("BB2:109109"), -2151);
-    m.insert(String::from("BB2:109999"), 1320);
-    m.insert(String::from("BB2:120120"), -420);
-    m.insert(String::from("BB2:120162"), 170);
-    m.insert(String::from("BB2:120999"), 2291);
-    m.insert(String::from("BB2:162162"), -1613);
-    m.insert(String::from("BB2:162999"), 2668);
-    m.insert(String::from("BB3:107999"), -1305);
-    m.insert(String::from("BB3:108108"), 849);
-    m.insert(String::from("BB3:108120"), -298);
-    m.insert(String::from("BB3:120108"), 255);
-    m.insert(String::from("BB3:120162"), -27);
-    m.insert(String::from("BB3:120999"), -299);
-    m.insert(String::from("BB3:162999"), -1821);
-    m.insert(String::from("BP2:OO"), 790);
-    m.insert(String::from("BQ1:B120120"), 365);
-    m.insert(String::from("BQ1:O107120"), 180);
-    m.insert(String::from("BQ1:O108108"), -263);
-    m.insert(String::from("BQ1:O120120"), -120);
-    m.insert(String::from("BQ2:B108108"), -30);
-    m.insert(String::from("BQ2:B108120"), -221);
-    m.insert(String::from("BQ2:O107108"), -896);
-    m.insert(String::from("BQ2:O108108"), -40);
-    m.insert(String::from("BQ2:O108120"), 129);
-    m.insert(String::from("BQ2:O120120"), -379);
-    m.insert(String::from("BQ3:O108107"), 187);
-    m.insert(String::from("BQ3:O162162"), -248);
-    m.insert(String::from("BQ4:B120108"), -105);
-    m.insert(String::from("BQ4:O108108"), -136);
-    m.insert(String::from("BQ4:O120109"), -112);
-    m.insert(String::from("BQ4:U120120"), -303);
-    m.insert(String::from("BW1:い、"), -518);
-    m.insert(String::from("BW1:いう"), 298);
-    m.insert(String::from("BW1:うに"), -90);
-    m.insert(String::from("BW1:かし"), 627);
-    m.insert(String::from("BW1:かも"), -928);
-    m.insert(String::from("BW1:から"), 816);
-    m.insert(String::from("BW1:こと"), -434);
-    m.insert(String::from("BW1:この"), -91);
-    m.insert(String::from("BW1:しい"), 135);
-    m.insert(String::from("BW1:しか"), 228);
-    m.insert(String::from("BW1:その"), -159);
-    m.insert(String::from("BW1:たら"), 328);
-    m.insert(String::from("BW1:った"), 105);
-    m.insert(String::from("BW1:てい"), -357);
-    m.insert(String::from("BW1:では"), -648);
-    m.insert(String::from("BW1:とか"), 206);
-    m.insert(String::from("BW1:とが"), -1211);
-    m.insert(String::from("BW1:とは"), -13);
-    m.insert(String::from("BW1:とも"), -672);
-    m.insert(String::from("BW1:ない"), 238);
-    m.insert(String::from("BW1:の間"), 103);
-    m.insert(String::from("BW1:より"), 526);
-    m.insert(String::from("BW1:るの"), -148);
-    m.insert(String::from("BW1:れて"), -342);
-    m.insert(String::from("BW1:んで"), -191);
-    m.insert(String::from("BW1:んな"), 174);
-    m.insert(String::from("BW1:少し"), 106);
-    m.insert(String::from("BW1:思い"), -244);
-    m.insert(String::from("BW2:、と"), -13);
-    m.insert(String::from("BW2:いも"), 373);
-    m.insert(String::from("BW2:くな"), -404);
-    m.insert(String::from("BW2:たい"), -155);
-    m.insert(String::from("BW2:てい"), -522);
-    m.insert(String::from("BW2:てお"), -1727);
-    m.insert(String::from("BW2:であ"), -1719);
-    m.insert(String::from("BW2:でき"), -373);
-    m.insert(String::from("BW2:でし"), -337);
-    m.insert(String::from("BW2:です"), -1109);
-    m.insert(String::from("BW2:とい"), 691);
-    m.insert(String::from("BW2:とし"), 540);
-    m.insert(String::from("BW2:と同"), -602);
-    m.insert(String::from("BW2:ない"), -1335);
-    m.insert(String::from("BW2:には"), -422);
-    m.insert(String::from("BW2:にも"), -25);
-    m.insert(String::from("BW2:のよ"), -162);
-    m.insert(String::from("BW2:はな"), -154);
-    m.insert(String::from("BW2:りし"), 52);
-    m.insert(String::from("BW2:帯電"), -52);
-    m.insert(String::from("BW3:いい"), 767);
-    m.insert(String::from("BW3:いう"), 861);
-    m.insert(String::from("BW3:かか"), 52);
-    m.insert(String::from("BW3:かけ"), 1145);
-    m.insert(String::from("BW3:かっ"), -386);
-    m.insert(String::from("BW3:から"), -702);
-    m.insert(String::from("BW3:こと"), 522);
-    m.insert(String::from("BW3:この"), 830);
-    m.insert(String::from("BW3:さん"), -50);
-    m.insert(String::from("BW3:した"), 228);
-    m.insert(String::from("BW3:して"), 220);
-    m.insert(String::from("BW3:すぐ"), 660);
-    m.insert(String::from("BW3:すご"), 173);
-    m.insert(String::from("BW3:そう"), -112);
-    m.insert(String::from("BW3:そし"), -568);
-    m.insert(String::from("BW3:その"), 1208);
-    m.insert(String::from("BW3:ため"), 1073);
-    m.insert(String::from("BW3:ちょ"), 1343);
-    m.insert(String::from("BW3:でき"), 393);
-    m.insert(String::from("BW3:とい"), -1444);
-    m.insert(String::from("BW3:とき"), 1220);
-    m.insert(String::from("BW3:とこ"), 1286);
-    m.insert(String::from("BW3:とて"), 173);
-    m.insert(String::from("BW3:どう"), 258);
-    m.insert(String::from("BW3:ない"), 95);
-    m.insert(String::from("BW3:なっ"), -546);
-    m.insert(String::from("BW3:もの"), 2676);
-    m.insert(String::from("BW3:よう"), -892);
-    m.insert(String::from("BW3:よく"), 134);
-    m.insert(String::from("BW3:わか"), 536);
-    m.insert(String::from("BW3:出来"), -1035);
-    m.insert(String::from("TB1:108107108"), -673);
-    m.insert(String::from("TB1:108108108"), -91);
-    m.insert(String::from("TB1:108120108"), -243);
-    m.insert(String::from("TB1:120108108"), -188);
-    m.insert(String::from("TB1:120108120"), -97);
-    m.insert(String::from("TB1:120120108"), 125);
-    m.insert(String::from("TB1:120120120"), -242);
-    m.insert(String::from("TB2:108108107"), -145);
-    m.insert(String::from("TB2:108108108"), -114);
-    m.insert(String::from("TB2:108108162"), 26);
-    m.insert(String::from("TB2:108120108"), -413);
-    m.insert(String::from("TB2:109109109"), -543);
-    m.insert(String::from("TB3:108108108"), 562);
-    m.insert(String::from("TB3:108108120"), 26);
-    m.insert(String::from("TB3:108109109"), 1577);
-    m.insert(String::from("TB3:108120108"), -250);
-    m.insert(String::from("TB3:108120120"), 322);
-    m.insert(String::from("TB3:108162162"), 317);
-    m.insert(String::from("TB3:109108108"), 130);
-    m.insert(String::from("TB3:109120108"), 169);
-    m.insert(String::from("TB3:109120120"), -148);
-    m.insert(String::from("TB3:120120120"), -151);
-    m.insert(String::from("TB3:120162162"), 69);
-    m.insert(String::from("TB4:108108108"), 306);
-    m.insert(String::from("TB4:108108120"), 84);
-    m.insert(String::from("TB4:108109108"), 595);
-    m.insert(String::from("TB4:108109109"), -67);
-    m.insert(String::from("TB4:108120108"), -129);
-    m.insert(String::from("TB4:109109109"), 423);
-    m.insert(String::from("TB4:120108108"), 90);
-    m.insert(String::from("TB4:120108120"), 61);
-    m.insert(String::from("TB4:120120108"), 14);
-    m.insert(String::from("TB4:162120108"), 14);
-    m.insert(String::from("TB4:162162162"), 297);
-    m.insert(String::from("TB4:162162999"), -491);
-    m.insert(String::from("TQ1:B108120108"), 75);
-    m.insert(String::from("TQ1:B108120120"), -172);
-    m.insert(String::from("TQ1:O108108108"), -281);
-    m.insert(String::from("TQ1:O108108120"), 87);
-    m.insert(String::from("TQ1:O108120108"), -410);
-    m.insert(String::from("TQ1:O120120120"), -14);
-    m.insert(String::from("TQ1:U120120108"), 43);
-    m.insert(String::from("TQ2:B108108108"), -269);
-    m.insert(String::from("TQ2:B120108120"), -336);
-    m.insert(String::from("TQ2:B120120108"), -76);
-    m.insert(String::from("TQ2:O108107108"), -212);
-    m.insert(String::from("TQ2:O108108108"), -194);
-    m.insert(String::from("TQ2:O108120108"), -160);
-    m.insert(String::from("TQ2:O109109109"), -279);
-    m.insert(String::from("TQ2:O120108108"), -128);
-    m.insert(String::from("TQ3:B108108120"), 158);
-    m.insert(String::from("TQ3:O107120120"), 60);
-    m.insert(String::from("TQ3:O108162120"), 52);
-    m.insert(String::from("TQ3:O120108108"), -53);
-    m.insert(String::from("TQ3:O120120107"), -25);
-    m.insert(String::from("TQ3:O162162162"), -324);
-    m.insert(String::from("TQ4:O108107120"), 204);
-    m.insert(String::from("TQ4:O120108120"), -102);
-    m.insert(String::from("TQ4:O120120120"), 208);
-    m.insert(String::from("TW1:ことも"), -13);
-    m.insert(String::from("TW1:という"), 264);
-    m.insert(String::from("TW2:ではな"), -505);
-    m.insert(String::from("TW2:気に入"), -1623);
-    m.insert(String::from("TW3:、ある"), -784);
-    m.insert(String::from("TW3:ている"), -761);
-    m.insert(String::from("TW3:てしま"), -305);
-    m.insert(String::from("TW3:である"), -318);
-    m.insert(String::from("TW3:という"), 248);
-    m.insert(String::from("TW3:と言っ"), -1204);
-    m.insert(String::from("TW3:らない"), -76);
-    m.insert(String::from("TW3:ること"), -38);
-    m.insert(String::from("TW4:かなり"), 680);
-    m.insert(String::from("TW4:くらい"), 835);
-    m.insert(String::from("TW4:こと。"), 118);
-    m.insert(String::from("TW4:ことが"), -409);
-    m.insert(String::from("TW4:ことに"), -160);
-    m.insert(String::from("TW4:ことも"), -39);
-    m.insert(String::from("TW4:ところ"), 607);
-    m.insert(String::from("TW4:ない。"), 39);
-    m.insert(String::from("UB1:108"), 192);
-    m.insert(String::from("UB1:162"), -208);
-    m.insert(String::from("UB2:108"), 97);
-    m.insert(String::from("UB2:120"), 38);
-    m.insert(String::from("UB3:072"), 236);
-    m.insert(String::from("UB3:107"), 714);
-    m.insert(String::from("UB3:108"), 169);
-    m.insert(String::from("UB3:109"), -456);
-    m.insert(String::from("UB3:120"), -213);
-    m.insert(String::from("UB4:072"), -103);
-    m.insert(String::from("UB4:087"), -877);
-    m.insert(String::from("UB4:107"), -1743);
-    m.insert(String::from("UB4:108"), -2271);
-    m.insert(String::from("UB4:109"), -440);
-    m.insert(String::from("UB4:120"), -421);
-    m.insert(String::from("UB4:162"), -257);
-    m.insert(String::from("UB5:107"), -851);
-    m.insert(String::from("UB5:108"), -66);
-    m.insert(String::from("UB5:120"), 60);
-    m.insert(String::from("UB5:162"), -311);
-    m.insert(String::from("UB6:107"), -76);
-    m.insert(String::from("UB6:109"), -136);
-    m.insert(String::from("UP1:B"), -101);
-    m.insert(String::from("UP1:U"), 251);
-    m.insert(String::from("UP2:O"), -53);
-    m.insert(String::from("UP2:U"), 113);
-    m.insert(String::from("UP3:B"), -1495);
-    m.insert(String::from("UP3:O"), 121);
-    m.insert(String::from("UQ1:O108"), -86);
-    m.insert(String::from("UQ1:U109"), 39);
-    m.insert(String::from("UQ2:O108"), 14);
-    m.insert(String::from("UQ2:O109"), -13);
-    m.insert(String::from("UQ2:O120"), -110);
-    m.insert(String::from("UQ3:B108"), -1112);
-    m.insert(String::from("UQ3:B120"), 626);
-    m.insert(String::from("UQ3:O108"), -21);
-    m.insert(String::from("UQ3:O162"), 222);
-    m.insert(String::from("UW1:、"), 17);
-    m.insert(String::from("UW1:「"), -53);
-    m.insert(String::from("UW1:あ"), 111);
-    m.insert(String::from("UW1:い"), -104);
-    m.insert(String::from("UW1:お"), -51);
-    m.insert(String::from("UW1:く"), 13);
-    m.insert(String::from("UW1:す"), 71);
-    m.insert(String::from("UW1:そ"), 204);
-    m.insert(String::from("UW1:っ"), 74);
-    m.insert(String::from("UW1:て"), 51);
-    m.insert(String::from("UW1:で"), -124);
-    m.insert(String::from("UW1:と"), 119);
-    m.insert(String::from("UW1:な"), -300);
-    m.insert(String::from("UW1:に"), -146);
-    m.insert(String::from("UW1:の"), -65);
-    m.insert(String::from("UW1:も"), 13);
-    m.insert(String::from("UW1:や"), -201);
-    m.insert(String::from("UW1:社"), 51);
-    m.insert(String::from("UW2:う"), -44);
-    m.insert(String::from("UW2:お"), -184);
-    m.insert(String::from("UW2:が"), 26);
-    m.insert(String::from("UW2:き"), 89);
-    m.insert(String::from("UW2:く"), -39);
-    m.insert(String::from("UW2:さ"), 238);
-    m.insert(String::from("UW2:し"), 33);
-    m.insert(String::from("UW2:そ"), -298);
-    m.insert(String::from("UW2:た"), -83);
-    m.insert(String::from("UW2:っ"), 55);
-    m.insert(String::from("UW2:て"), -213);
-    m.insert(String::from("UW2:で"), -227);
-    m.insert(String::from("UW2:と"), -94);
-    m.insert(String::from("UW2:の"), 218);
-    m.insert(String::from("UW2:ま"), 340);
-    m.insert(String::from("UW2:も"), -199);
-    m.insert(String::from("UW2:や"), -67);
-    m.insert(String::from("UW2:よ"), 156);
-    m.insert(String::from("UW2:る"), -273);
-    m.insert(String::from("UW2:ん"), 223);
-    m.insert(String::from("UW2:ー"), -30);
-    m.insert(String::from("UW2:一"), 760);
-    m.insert(String::from("UW2:人"), 27);
-    m.insert(String::from("UW2:全"), 435);
-    m.insert(String::from("UW2:少"), 449);
-    m.insert(String::from("UW2:思"), -15);
-    m.insert(String::from("UW2:最"), 406);
-    m.insert(String::from("UW2:毎"), 867);
-    m.insert(String::from("UW2:結"), 700);
-    m.insert(String::from("UW2:関"), 174);
-    m.insert(String::from("UW3:…"), 298);
-    m.insert(String::from("UW3:、"), 2324);
-    m.insert(String::from("UW3:。"), 140);
-    m.insert(String::from("UW3:「"), -514);
-    m.insert(String::from("UW3:い"), 166);
-    m.insert(String::from("UW3:う"), 464);
-    m.insert(String::from("UW3:お"), -424);
-    m.insert(String::from("UW3:か"), 439);
-    m.insert(String::from("UW3:が"), 2055);
-    m.insert(String::from("UW3:き"), -536);
-    m.insert(String::from("UW3:く"), 821);
-    m.insert(String::from("UW3:け"), -354);
-    m.insert(String::from("UW3:こ"), -194);
-    m.insert(String::from("UW3:ご"), -77);
-    m.insert(String::from("UW3:さ"), -328);
-    m.insert(String::from("UW3:し"), -827);
-    m.insert(String::from("UW3:た"), 484);
-    m.insert(String::from("UW3:だ"), -257);
-    m.insert(String::from("UW3:ち"), -780);
-    m.insert(String::from("UW3:っ"), -1870);
-    m.insert(String::from("UW3:て"), 445);
-    m.insert(String::from("UW3:で"), 756);
-    m.insert(String::from("UW3:と"), 1059);
-    m.insert(String::from("UW3:ど"), 134);
-    m.insert(String::from("UW3:な"), 215);
-    m.insert(String::from("UW3:に"), 1629);
-    m.insert(String::from("UW3:の"), 1199);
-    m.insert(String::from("UW3:は"), 2029);
-    m.insert(String::from("UW3:ば"), 464);
-    m.insert(String::from("UW3:べ"), -452);
-    m.insert(String::from("UW3:ま"), -959);
-    m.insert(String::from("UW3:み"), -339);
-    m.insert(String::from("UW3:も"), 1399);
-    m.insert(String::from("UW3:ゃ"), 13);
-    m.insert(String::from("UW3:や"), 285);
-    m.insert(String::from("UW3:よ"), -145);
-    m.insert(String::from("UW3:ら"), 324);
-    m.insert(String::from("UW3:り"), -223);
-    m.insert(String::from("UW3:る"), 756);
-    m.insert(String::from("UW3:れ"), -759);
-    m.insert(String::from("UW3:わ"), -106);
-    m.insert(String::from("UW3:を"), 2925);
-    m.insert(String::from("UW3:ん"), -302);
-    m.insert(String::from("UW3:・"), 567);
-    m.insert(String::from("UW3:ー"), 99);
-    m.insert(String::from("UW3:中"), 764);
-    m.insert(String::from("UW3:人"), 945);
-    m.insert(String::from("UW3:今"), 1340);
-    m.insert(String::from("UW3:光"), -182);
-    m.insert(String::from("UW3:分"), 178);
-    m.insert(String::from("UW3:変"), 91);
-    m.insert(String::from("UW3:度"), 90);
-    m.insert(String::from("UW3:後"), 1076);
-    m.insert(String::from("UW3:日"), 398);
-    m.insert(String::from("UW3:昔"), 637);
-    m.insert(String::from("UW3:真"), 185);
-    m.insert(String::from("UW3:間"), 905);
-    m.insert(String::from("UW3:電"), -286);
-    m.insert(String::from("UW3:，"), 1843);
-    m.insert(String::from("UW3:］"), 825);
-    m.insert(String::from("UW4:…"), -669);
-    m.insert(String::from("UW4:、"), -3540);
-    m.insert(String::from("UW4:。"), -1602);
-    m.insert(String::from("UW4:「"), 3298);
-    m.insert(String::from("UW4:『"), 979);
-    m.insert(String::from("UW4:あ"), 1188);
-    m.insert(String::from("UW4:い"), 380);
-    m.insert(String::from("UW4:お"), 3275);
-    m.insert(String::from("UW4:か"), -93);
-    m.insert(String::from("UW4:が"), -678);
-    m.insert(String::from("UW4:く"), -106);
-    m.insert(String::from("UW4:け"), -819);
-    m.insert(String::from("UW4:こ"), 1449);
-    m.insert(String::from("UW4:ご"), 1047);
-    m.insert(String::from("UW4:す"), 366);
-    m.insert(String::from("UW4:そ"), 1036);
-    m.insert(String::from("UW4:だ"), -569);
-    m.insert(String::from("UW4:っ"), -1536);
-    m.insert(String::from("UW4:つ"), 682);
-    m.insert(String::from("UW4:て"), -1379);
-    m.insert(String::from("UW4:で"), -924);
-    m.insert(String::from("UW4:と"), -26);
-    m.insert(String::from("UW4:ど"), 522);
-    m.insert(String::from("UW4:な"), 54);
-    m.insert(String::from("UW4:に"), -1407);
-    m.insert(String::from("UW4:の"), -960);
-    m.insert(String::from("UW4:は"), -608);
-    m.insert(String::from("UW4:ひ"), 1182);
-    m.insert(String::from("UW4:ほ"), 1294);
-    m.insert(String::from("UW4:ま"), 136);
-    m.insert(String::from("UW4:み"), 95);
-    m.insert(String::from("UW4:も"), -174);
-    m.insert(String::from("UW4:や"), 650);
-    m.insert(String::from("UW4:よ"), 310);
-    m.insert(String::from("UW4:ら"), -1582);
-    m.insert(String::from("UW4:り"), -690);
-    m.insert(String::from("UW4:る"), -1805);
-    m.insert(String::from("UW4:れ"), -1489);
-    m.insert(String::from("UW4:ろ"), -287);
-    m.insert(String::from("UW4:わ"), 430);
-    m.insert(String::from("UW4:を"), -1092);
-    m.insert(String::from("UW4:ん"), -649);
-    m.insert(String::from("UW4:・"), -2383);
-    m.insert(String::from("UW4:ー"), -655);
-    m.insert(String::from("UW4:使"), 204);
-    m.insert(String::from("UW4:出"), -72);
-    m.insert(String::from("UW4:前"), -559);
-    m.insert(String::from("UW4:合"), -148);
-    m.insert(String::from("UW4:思"), 445);
-    m.insert(String::from("UW4:物"), -245);
-    m.insert(String::from("UW4:私"), 12);
-    m.insert(String::from("UW4:笑"), -506);
-    m.insert(String::from("UW4:込"), -315);
-    m.insert(String::from("UW4:通"), -132);
-    m.insert(String::from("UW4:間"), -430);
-    m.insert(String::from("UW4:電"), -301);
-    m.insert(String::from("UW4:！"), -1144);
-    m.insert(String::from("UW4:（"), 483);
-    m.insert(String::from("UW4:，"), -2523);
-    m.insert(String::from("UW4:．"), -311);
-    m.insert(String::from("UW4:１"), 304);
-    m.insert(String::from("UW4:２"), 433);
-    m.insert(String::from("UW4:３"), 82);
-    m.insert(String::from("UW4:５"), 117);
-    m.insert(String::from("UW4:？"), -274);
-    m.insert(String::from("UW4:］"), -1127);
-    m.insert(String::from("UW5:々"), 556);
-    m.insert(String::from("UW5:あ"), -618);
-    m.insert(String::from("UW5:い"), 51);
-    m.insert(String::from("UW5:う"), 211);
-    m.insert(String::from("UW5:え"), 181);
-    m.insert(String::from("UW5:が"), -626);
-    m.insert(String::from("UW5:き"), 664);
-    m.insert(String::from("UW5:く"), 411);
-    m.insert(String::from("UW5:け"), -39);
-    m.insert(String::from("UW5:こ"), 104);
-    m.insert(String::from("UW5:ご"), 135);
-    m.insert(String::from("UW5:さ"), -26);
-    m.insert(String::from("UW5:し"), -357);
-    m.insert(String::from("UW5:す"), -771);
-    m.insert(String::from("UW5:ず"), 179);
-    m.insert(String::from("UW5:せ"), -122);
-    m.insert(String::from("UW5:そ"), -283);
-    m.insert(String::from("UW5:た"), -80);
-    m.insert(String::from("UW5:だ"), -217);
-    m.insert(String::from("UW5:ち"), 161);
-    m.insert(String::from("UW5:っ"), 772);
-    m.insert(String::from("UW5:つ"), 584);
-    m.insert(String::from("UW5:て"), 119);
-    m.insert(String::from("UW5:で"), -1030);
-    m.insert(String::from("UW5:と"), -476);
-    m.insert(String::from("UW5:な"), -645);
-    m.insert(String::from("UW5:に"), -569);
-    m.insert(String::from("UW5:の"), -519);
-    m.insert(String::from("UW5:は"), -560);
-    m.insert(String::from("UW5:べ"), 609);
-    m.insert(String::from("UW5:め"), 308);
-    m.insert(String::from("UW5:も"), -405);
-    m.insert(String::from("UW5:よ"), -26);
-    m.insert(String::from("UW5:る"), 13);
-    m.insert(String::from("UW5:れ"), 265);
-    m.insert(String::from("UW5:を"), -622);
-    m.insert(String::from("UW5:ん"), 676);
-    m.insert(String::from("UW5:イ"), 666);
-    m.insert(String::from("UW5:・"), -668);
-    m.insert(String::from("UW5:ー"), 41);
-    m.insert(String::from("UW5:年"), 428);
-    m.insert(String::from("UW5:料"), 317);
-    m.insert(String::from("UW5:来"), -31);
-    m.insert(String::from("UW6:、"), 31);
-    m.insert(String::from("UW6:。"), -99);
-    m.insert(String::from("UW6:あ"), -90);
-    m.insert(String::from("UW6:う"), -420);
-    m.insert(String::from("UW6:え"), -39);
-    m.insert(String::from("UW6:く"), 13);
-    m.insert(String::from("UW6:さ"), 40);
-    m.insert(String::from("UW6:し"), 13);
-    m.insert(String::from("UW6:た"), -284);
-    m.insert(String::from("UW6:だ"), -56);
-    m.insert(String::from("UW6:っ"), 222);
-    m.insert(String::from("UW6:て"), -186);
-    m.insert(String::from("UW6:と"), -69);
-    m.insert(String::from("UW6:に"), 92);
-    m.insert(String::from("UW6:の"), -137);
-    m.insert(String::from("UW6:は"), -26);
-    m.insert(String::from("UW6:め"), 118);
-    m.insert(String::from("UW6:や"), 68);
-    m.insert(String::from("UW6:り"), 273);
-    m.insert(String::from("UW6:る"), -13);
-    m.insert(String::from("UW6:れ"), -14);
-    m.insert(String::from("UW6:わ"), 80);
-    m.insert(String::from("UW6:を"), 84);
-    m.insert(String::from("UW6:ん"), 142);
-    m.insert(String::from("UW6:カ"), 303);
-    m.insert(String::from("UW6:思"), -109);
-    m.insert(String::from("UW6:０"), 392);
-
-    m
-});
+pub static MODEL: crate::models::StaticModel = crate::models::StaticModel::new(&[
+    ("BB1:108107", 307),
+    ("BB1:108108", -77),
+    ("BB1:109120", -359),
+    ("BB1:162120", 397),
+    ("BB1:162162", -251),
+    ("BB2:072999", 78),
+    ("BB2:087999", 836),
+    ("BB2:107162", -766),
+    ("BB2:107999", 3411),
+    ("BB2:108072", -977),
+    ("BB2:108108", -92),
+    ("BB2:108120", 1817),
+    ("BB2:108162", 338),
+    ("BB2:108999", 1504),
+    ("BB2:109109", -2151),
+    ("BB2:109999", 1320),
+    ("BB2:120120", -420),
+    ("BB2:120162", 170),
+    ("BB2:120999", 2291),
+    ("BB2:162162", -1613),
+    ("BB2:162999", 2668),
+    ("BB3:107999", -1305),
+    ("BB3:108108", 849),
+    ("BB3:108120", -298),
+    ("BB3:120108", 255),
+    ("BB3:120162", -27),
+    ("BB3:120999", -299),
+    ("BB3:162999", -1821),
+    ("BP2:OO", 790),
+    ("BQ1:B120120", 365),
+    ("BQ1:O107120", 180),
+    ("BQ1:O108108", -263),
+    ("BQ1:O120120", -120),
+    ("BQ2:B108108", -30),
+    ("BQ2:B108120", -221),
+    ("BQ2:O107108", -896),
+    ("BQ2:O108108", -40),
+    ("BQ2:O108120", 129),
+    ("BQ2:O120120", -379),
+    ("BQ3:O108107", 187),
+    ("BQ3:O162162", -248),
+    ("BQ4:B120108", -105),
+    ("BQ4:O108108", -136),
+    ("BQ4:O120109", -112),
+    ("BQ4:U120120", -303),
+    ("BW1:い、", -518),
+    ("BW1:いう", 298),
+    ("BW1:うに", -90),
+    ("BW1:かし", 627),
+    ("BW1:かも", -928),
+    ("BW1:から", 816),
+    ("BW1:こと", -434),
+    ("BW1:この", -91),
+    ("BW1:しい", 135),
+    ("BW1:しか", 228),
+    ("BW1:その", -159),
+    ("BW1:たら", 328),
+    ("BW1:った", 105),
+    ("BW1:てい", -357),
+    ("BW1:では", -648),
+    ("BW1:とか", 206),
+    ("BW1:とが", -1211),
+    ("BW1:とは", -13),
+    ("BW1:とも", -672),
+    ("BW1:ない", 238),
+    ("BW1:の間", 103),
+    ("BW1:より", 526),
+    ("BW1:るの", -148),
+    ("BW1:れて", -342),
+    ("BW1:んで", -191),
+    ("BW1:んな", 174),
+    ("BW1:少し", 106),
+    ("BW1:思い", -244),
+    ("BW2:、と", -13),
+    ("BW2:いも", 373),
+    ("BW2:くな", -404),
+    ("BW2:たい", -155),
+    ("BW2:てい", -522),
+    ("BW2:てお", -1727),
+    ("BW2:であ", -1719),
+    ("BW2:でき", -373),
+    ("BW2:でし", -337),
+    ("BW2:です", -1109),
+    ("BW2:とい", 691),
+    ("BW2:とし", 540),
+    ("BW2:と同", -602),
+    ("BW2:ない", -1335),
+    ("BW2:には", -422),
+    ("BW2:にも", -25),
+    ("BW2:のよ", -162),
+    ("BW2:はな", -154),
+    ("BW2:りし", 52),
+    ("BW2:帯電", -52),
+    ("BW3:いい", 767),
+    ("BW3:いう", 861),
+    ("BW3:かか", 52),
+    ("BW3:かけ", 1145),
+    ("BW3:かっ", -386),
+    ("BW3:から", -702),
+    ("BW3:こと", 522),
+    ("BW3:この", 830),
+    ("BW3:さん", -50),
+    ("BW3:した", 228),
+    ("BW3:して", 220),
+    ("BW3:すぐ", 660),
+    ("BW3:すご", 173),
+    ("BW3:そう", -112),
+    ("BW3:そし", -568),
+    ("BW3:その", 1208),
+    ("BW3:ため", 1073),
+    ("BW3:ちょ", 1343),
+    ("BW3:でき", 393),
+    ("BW3:とい", -1444),
+    ("BW3:とき", 1220),
+    ("BW3:とこ", 1286),
+    ("BW3:とて", 173),
+    ("BW3:どう", 258),
+    ("BW3:ない", 95),
+    ("BW3:なっ", -546),
+    ("BW3:もの", 2676),
+    ("BW3:よう", -892),
+    ("BW3:よく", 134),
+    ("BW3:わか", 536),
+    ("BW3:出来", -1035),
+    ("TB1:108107108", -673),
+    ("TB1:108108108", -91),
+    ("TB1:108120108", -243),
+    ("TB1:120108108", -188),
+    ("TB1:120108120", -97),
+    ("TB1:120120108", 125),
+    ("TB1:120120120", -242),
+    ("TB2:108108107", -145),
+    ("TB2:108108108", -114),
+    ("TB2:108108162", 26),
+    ("TB2:108120108", -413),
+    ("TB2:109109109", -543),
+    ("TB3:108108108", 562),
+    ("TB3:108108120", 26),
+    ("TB3:108109109", 1577),
+    ("TB3:108120108", -250),
+    ("TB3:108120120", 322),
+    ("TB3:108162162", 317),
+    ("TB3:109108108", 130),
+    ("TB3:109120108", 169),
+    ("TB3:109120120", -148),
+    ("TB3:120120120", -151),
+    ("TB3:120162162", 69),
+    ("TB4:108108108", 306),
+    ("TB4:108108120", 84),
+    ("TB4:108109108", 595),
+    ("TB4:108109109", -67),
+    ("TB4:108120108", -129),
+    ("TB4:109109109", 423),
+    ("TB4:120108108", 90),
+    ("TB4:120108120", 61),
+    ("TB4:120120108", 14),
+    ("TB4:162120108", 14),
+    ("TB4:162162162", 297),
+    ("TB4:162162999", -491),
+    ("TQ1:B108120108", 75),
+    ("TQ1:B108120120", -172),
+    ("TQ1:O108108108", -281),
+    ("TQ1:O108108120", 87),
+    ("TQ1:O108120108", -410),
+    ("TQ1:O120120120", -14),
+    ("TQ1:U120120108", 43),
+    ("TQ2:B108108108", -269),
+    ("TQ2:B120108120", -336),
+    ("TQ2:B120120108", -76),
+    ("TQ2:O108107108", -212),
+    ("TQ2:O108108108", -194),
+    ("TQ2:O108120108", -160),
+    ("TQ2:O109109109", -279),
+    ("TQ2:O120108108", -128),
+    ("TQ3:B108108120", 158),
+    ("TQ3:O107120120", 60),
+    ("TQ3:O108162120", 52),
+    ("TQ3:O120108108", -53),
+    ("TQ3:O120120107", -25),
+    ("TQ3:O162162162", -324),
+    ("TQ4:O108107120", 204),
+    ("TQ4:O120108120", -102),
+    ("TQ4:O120120120", 208),
+    ("TW1:ことも", -13),
+    ("TW1:という", 264),
+    ("TW2:ではな", -505),
+    ("TW2:気に入", -1623),
+    ("TW3:、ある", -784),
+    ("TW3:ている", -761),
+    ("TW3:てしま", -305),
+    ("TW3:である", -318),
+    ("TW3:という", 248),
+    ("TW3:と言っ", -1204),
+    ("TW3:らない", -76),
+    ("TW3:ること", -38),
+    ("TW4:かなり", 680),
+    ("TW4:くらい", 835),
+    ("TW4:こと。", 118),
+    ("TW4:ことが", -409),
+    ("TW4:ことに", -160),
+    ("TW4:ことも", -39),
+    ("TW4:ところ", 607),
+    ("TW4:ない。", 39),
+    ("UB1:108", 192),
+    ("UB1:162", -208),
+    ("UB2:108", 97),
+    ("UB2:120", 38),
+    ("UB3:072", 236),
+    ("UB3:107", 714),
+    ("UB3:108", 169),
+    ("UB3:109", -456),
+    ("UB3:120", -213),
+    ("UB4:072", -103),
+    ("UB4:087", -877),
+    ("UB4:107", -1743),
+    ("UB4:108", -2271),
+    ("UB4:109", -440),
+    ("UB4:120", -421),
+    ("UB4:162", -257),
+    ("UB5:107", -851),
+    ("UB5:108", -66),
+    ("UB5:120", 60),
+    ("UB5:162", -311),
+    ("UB6:107", -76),
+    ("UB6:109", -136),
+    ("UP1:B", -101),
+    ("UP1:U", 251),
+    ("UP2:O", -53),
+    ("UP2:U", 113),
+    ("UP3:B", -1495),
+    ("UP3:O", 121),
+    ("UQ1:O108", -86),
+    ("UQ1:U109", 39),
+    ("UQ2:O108", 14),
+    ("UQ2:O109", -13),
+    ("UQ2:O120", -110),
+    ("UQ3:B108", -1112),
+    ("UQ3:B120", 626),
+    ("UQ3:O108", -21),
+    ("UQ3:O162", 222),
+    ("UW1:、", 17),
+    ("UW1:「", -53),
+    ("UW1:あ", 111),
+    ("UW1:い", -104),
+    ("UW1:お", -51),
+    ("UW1:く", 13),
+    ("UW1:す", 71),
+    ("UW1:そ", 204),
+    ("UW1:っ", 74),
+    ("UW1:て", 51),
+    ("UW1:で", -124),
+    ("UW1:と", 119),
+    ("UW1:な", -300),
+    ("UW1:に", -146),
+    ("UW1:の", -65),
+    ("UW1:も", 13),
+    ("UW1:や", -201),
+    ("UW1:社", 51),
+    ("UW2:う", -44),
+    ("UW2:お", -184),
+    ("UW2:が", 26),
+    ("UW2:き", 89),
+    ("UW2:く", -39),
+    ("UW2:さ", 238),
+    ("UW2:し", 33),
+    ("UW2:そ", -298),
+    ("UW2:た", -83),
+    ("UW2:っ", 55),
+    ("UW2:て", -213),
+    ("UW2:で", -227),
+    ("UW2:と", -94),
+    ("UW2:の", 218),
+    ("UW2:ま", 340),
+    ("UW2:も", -199),
+    ("UW2:や", -67),
+    ("UW2:よ", 156),
+    ("UW2:る", -273),
+    ("UW2:ん", 223),
+    ("UW2:ー", -30),
+    ("UW2:一", 760),
+    ("UW2:人", 27),
+    ("UW2:全", 435),
+    ("UW2:少", 449),
+    ("UW2:思", -15),
+    ("UW2:最", 406),
+    ("UW2:毎", 867),
+    ("UW2:結", 700),
+    ("UW2:関", 174),
+    ("UW3:…", 298),
+    ("UW3:、", 2324),
+    ("UW3:。", 140),
+    ("UW3:「", -514),
+    ("UW3:い", 166),
+    ("UW3:う", 464),
+    ("UW3:お", -424),
+    ("UW3:か", 439),
+    ("UW3:が", 2055),
+    ("UW3:き", -536),
+    ("UW3:く", 821),
+    ("UW3:け", -354),
+    ("UW3:こ", -194),
+    ("UW3:ご", -77),
+    ("UW3:さ", -328),
+    ("UW3:し", -827),
+    ("UW3:た", 484),
+    ("UW3:だ", -257),
+    ("UW3:ち", -780),
+    ("UW3:っ", -1870),
+    ("UW3:て", 445),
+    ("UW3:で", 756),
+    ("UW3:と", 1059),
+    ("UW3:ど", 134),
+    ("UW3:な", 215),
+    ("UW3:に", 1629),
+    ("UW3:の", 1199),
+    ("UW3:は", 2029),
+    ("UW3:ば", 464),
+    ("UW3:べ", -452),
+    ("UW3:ま", -959),
+    ("UW3:み", -339),
+    ("UW3:も", 1399),
+    ("UW3:ゃ", 13),
+    ("UW3:や", 285),
+    ("UW3:よ", -145),
+    ("UW3:ら", 324),
+    ("UW3:り", -223),
+    ("UW3:る", 756),
+    ("UW3:れ", -759),
+    ("UW3:わ", -106),
+    ("UW3:を", 2925),
+    ("UW3:ん", -302),
+    ("UW3:・", 567),
+    ("UW3:ー", 99),
+    ("UW3:中", 764),
+    ("UW3:人", 945),
+    ("UW3:今", 1340),
+    ("UW3:光", -182),
+    ("UW3:分", 178),
+    ("UW3:変", 91),
+    ("UW3:度", 90),
+    ("UW3:後", 1076),
+    ("UW3:日", 398),
+    ("UW3:昔", 637),
+    ("UW3:真", 185),
+    ("UW3:間", 905),
+    ("UW3:電", -286),
+    ("UW3:，", 1843),
+    ("UW3:］", 825),
+    ("UW4:…", -669),
+    ("UW4:、", -3540),
+    ("UW4:。", -1602),
+    ("UW4:「", 3298),
+    ("UW4:『", 979),
+    ("UW4:あ", 1188),
+    ("UW4:い", 380),
+    ("UW4:お", 3275),
+    ("UW4:か", -93),
+    ("UW4:が", -678),
+    ("UW4:く", -106),
+    ("UW4:け", -819),
+    ("UW4:こ", 1449),
+    ("UW4:ご", 1047),
+    ("UW4:す", 366),
+    ("UW4:そ", 1036),
+    ("UW4:だ", -569),
+    ("UW4:っ", -1536),
+    ("UW4:つ", 682),
+    ("UW4:て", -1379),
+    ("UW4:で", -924),
+    ("UW4:と", -26),
+    ("UW4:ど", 522),
+    ("UW4:な", 54),
+    ("UW4:に", -1407),
+    ("UW4:の", -960),
+    ("UW4:は", -608),
+    ("UW4:ひ", 1182),
+    ("UW4:ほ", 1294),
+    ("UW4:ま", 136),
+    ("UW4:み", 95),
+    ("UW4:も", -174),
+    ("UW4:や", 650),
+    ("UW4:よ", 310),
+    ("UW4:ら", -1582),
+    ("UW4:り", -690),
+    ("UW4:る", -1805),
+    ("UW4:れ", -1489),
+    ("UW4:ろ", -287),
+    ("UW4:わ", 430),
+    ("UW4:を", -1092),
+    ("UW4:ん", -649),
+    ("UW4:・", -2383),
+    ("UW4:ー", -655),
+    ("UW4:使", 204),
+    ("UW4:出", -72),
+    ("UW4:前", -559),
+    ("UW4:合", -148),
+    ("UW4:思", 445),
+    ("UW4:物", -245),
+    ("UW4:私", 12),
+    ("UW4:笑", -506),
+    ("UW4:込", -315),
+    ("UW4:通", -132),
+    ("UW4:間", -430),
+    ("UW4:電", -301),
+    ("UW4:！", -1144),
+    ("UW4:（", 483),
+    ("UW4:，", -2523),
+    ("UW4:．", -311),
+    ("UW4:１", 304),
+    ("UW4:２", 433),
+    ("UW4:３", 82),
+    ("UW4:５", 117),
+    ("UW4:？", -274),
+    ("UW4:］", -1127),
+    ("UW5:々", 556),
+    ("UW5:あ", -618),
+    ("UW5:い", 51),
+    ("UW5:う", 211),
+    ("UW5:え", 181),
+    ("UW5:が", -626),
+    ("UW5:き", 664),
+    ("UW5:く", 411),
+    ("UW5:け", -39),
+    ("UW5:こ", 104),
+    ("UW5:ご", 135),
+    ("UW5:さ", -26),
+    ("UW5:し", -357),
+    ("UW5:す", -771),
+    ("UW5:ず", 179),
+    ("UW5:せ", -122),
+    ("UW5:そ", -283),
+    ("UW5:た", -80),
+    ("UW5:だ", -217),
+    ("UW5:ち", 161),
+    ("UW5:っ", 772),
+    ("UW5:つ", 584),
+    ("UW5:て", 119),
+    ("UW5:で", -1030),
+    ("UW5:と", -476),
+    ("UW5:な", -645),
+    ("UW5:に", -569),
+    ("UW5:の", -519),
+    ("UW5:は", -560),
+    ("UW5:べ", 609),
+    ("UW5:め", 308),
+    ("UW5:も", -405),
+    ("UW5:よ", -26),
+    ("UW5:る", 13),
+    ("UW5:れ", 265),
+    ("UW5:を", -622),
+    ("UW5:ん", 676),
+    ("UW5:イ", 666),
+    ("UW5:・", -668),
+    ("UW5:ー", 41),
+    ("UW5:年", 428),
+    ("UW5:料", 317),
+    ("UW5:来", -31),
+    ("UW6:、", 31),
+    ("UW6:。", -99),
+    ("UW6:あ", -90),
+    ("UW6:う", -420),
+    ("UW6:え", -39),
+    ("UW6:く", 13),
+    ("UW6:さ", 40),
+    ("UW6:し", 13),
+    ("UW6:た", -284),
+    ("UW6:だ", -56),
+    ("UW6:っ", 222),
+    ("UW6:て", -186),
+    ("UW6:と", -69),
+    ("UW6:に", 92),
+    ("UW6:の", -137),
+    ("UW6:は", -26),
+    ("UW6:め", 118),
+    ("UW6:や", 68),
+    ("UW6:り", 273),
+    ("UW6:る", -13),
+    ("UW6:れ", -14),
+    ("UW6:わ", 80),
+    ("UW6:を", 84),
+    ("UW6:ん", 142),
+    ("UW6:カ", 303),
+    ("UW6:思", -109),
+    ("UW6:０", 392),
+])
+.with_index(
+    &[
+        1, 1, 35, 5, 5, 12, 8, 0, 6, 53, 19, 15, 3, 190, 7, 146, 2, 30, 2, 6, 77, 587, 4, 21, 1,
+        482, 25, 47, 22, 3, 2, 14, 481, 5, 6, 9, 1, 341, 10, 26, 133, 5, 241, 27, 66, 3, 11, 187,
+        7, 147, 323, 1, 65, 150, 111, 70, 431, 794, 45, 9, 1, 4, 1, 133, 233, 5, 912, 782, 199,
+        230, 19, 1, 154, 46, 5, 22, 6, 18, 86, 1, 704, 51, 1, 0, 611, 1, 110, 62, 105, 1, 45, 31,
+        16, 8, 23, 1006, 79, 566, 441, 38, 128, 286, 40, 657, 270, 2152, 709, 3292, 43, 124, 2, 30,
+        852, 2729, 33, 29, 9704, 732, 389, 509, 20,
+    ],
+    &[
+        161, 9, 375, 102, 145, 204, 307, 156, 260, 25, 323, 203, 134, 193, 466, 5, 441, 404, 26,
+        340, 414, 357, 365, 225, 239, 202, 322, 412, 291, 90, 434, 18, 339, 120, 121, 210, 345,
+        437, 337, 385, 449, 242, 174, 458, 10, 309, 21, 305, 34, 80, 81, 285, 330, 182, 362, 226,
+        172, 290, 448, 40, 211, 212, 384, 44, 143, 235, 366, 306, 104, 57, 267, 244, 301, 250, 342,
+        86, 415, 455, 3, 206, 2, 336, 22, 60, 179, 24, 139, 467, 405, 69, 68, 326, 0, 160, 482, 94,
+        373, 272, 379, 356, 82, 281, 208, 402, 1, 319, 8, 478, 12, 313, 352, 275, 62, 227, 187,
+        386, 280, 155, 364, 61, 52, 224, 79, 247, 376, 207, 380, 282, 138, 317, 70, 199, 146, 454,
+        164, 215, 348, 464, 92, 73, 468, 47, 433, 331, 421, 264, 476, 186, 6, 344, 43, 177, 194,
+        271, 270, 286, 424, 427, 100, 129, 314, 71, 351, 15, 135, 446, 387, 355, 200, 479, 408,
+        435, 374, 371, 269, 55, 238, 315, 152, 320, 41, 288, 261, 112, 78, 329, 128, 118, 410, 127,
+        168, 350, 190, 163, 31, 180, 157, 240, 28, 213, 370, 191, 45, 391, 184, 338, 56, 148, 246,
+        245, 425, 159, 101, 76, 137, 49, 195, 368, 67, 473, 429, 332, 72, 141, 105, 192, 390, 256,
+        189, 142, 398, 50, 136, 93, 381, 276, 17, 438, 119, 295, 167, 133, 469, 89, 328, 444, 219,
+        443, 462, 400, 470, 252, 394, 171, 274, 35, 48, 117, 173, 83, 293, 237, 228, 395, 103, 452,
+        236, 442, 406, 254, 255, 302, 341, 300, 197, 445, 263, 327, 399, 4, 440, 175, 51, 481, 294,
+        111, 185, 98, 140, 358, 196, 360, 75, 216, 413, 411, 283, 287, 426, 353, 209, 369, 147,
+        205, 361, 84, 428, 220, 19, 461, 125, 74, 166, 325, 162, 7, 396, 316, 169, 114, 33, 122,
+        372, 393, 423, 32, 432, 107, 359, 456, 95, 304, 153, 403, 218, 431, 447, 303, 483, 214,
+        457, 389, 77, 232, 477, 419, 123, 29, 23, 91, 417, 233, 465, 471, 230, 30, 113, 37, 279,
+        475, 229, 27, 363, 130, 439, 450, 234, 65, 397, 409, 311, 343, 265, 420, 63, 151, 284, 39,
+        472, 335, 64, 474, 418, 273, 367, 257, 321, 201, 451, 110, 231, 318, 178, 58, 46, 20, 188,
+        198, 347, 416, 383, 109, 85, 154, 292, 430, 53, 150, 96, 243, 144, 259, 115, 258, 392, 222,
+        354, 165, 480, 333, 349, 158, 38, 278, 221, 463, 401, 87, 97, 388, 268, 66, 310, 308, 170,
+        36, 149, 266, 253, 99, 131, 277, 422, 16, 299, 262, 378, 54, 453, 126, 124, 241, 217, 296,
+        249, 108, 298, 460, 297, 176, 251, 289, 106, 59, 377, 132, 334, 181, 436, 42, 183, 88, 13,
+        382, 312, 324, 248, 346, 14, 11, 116, 407, 223, 459,
+    ],
+);
@@ -0,0 +1,323 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Error;
+
+/// MAX_DEPTH is maximum nesting depth of arrays and objects.
+const MAX_DEPTH: usize = 128;
+
+/// Value is json value, only objects and numbers keep their contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Value<'a> {
+    /// Object is members in order of appearance.
+    Object(Vec<(String, Value<'a>)>),
+    /// Number is number literal as it is.
+    Number(&'a str),
+    /// Other is string, array, boolean or null.
+    Other,
+}
+
+/// parse returns json value of s.
+pub(crate) fn parse(s: &str) -> Result<Value<'_>, Error> {
+    let mut parser = Parser { s, pos: 0 };
+
+    let value = parser.value(0)?;
+    parser.skip_whitespace();
+    if parser.pos < s.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    Ok(value)
+}
+
+/// Parser is recursive descent parser of json.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// error returns error at current position.
+    fn error(&self, reason: &'static str) -> Error {
+        Error::Json {
+            offset: self.pos,
+            reason,
+        }
+    }
+
+    /// peek returns next byte.
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    /// skip_whitespace skips whitespaces defined by json.
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    /// expect consumes literal, or returns error.
+    fn expect(&mut self, literal: &str) -> Result<(), Error> {
+        if !self.s[self.pos..].starts_with(literal) {
+            return Err(self.error("unexpected character"));
+        }
+
+        self.pos += literal.len();
+        Ok(())
+    }
+
+    /// value parses any json value.
+    fn value(&mut self, depth: usize) -> Result<Value<'a>, Error> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("too deeply nested"));
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(depth),
+            Some(b'[') => self.array(depth),
+            Some(b'"') => self.string().map(|_| Value::Other),
+            Some(b't') => self.expect("true").map(|_| Value::Other),
+            Some(b'f') => self.expect("false").map(|_| Value::Other),
+            Some(b'n') => self.expect("null").map(|_| Value::Other),
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("unexpected character")),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    /// object parses json object.
+    fn object(&mut self, depth: usize) -> Result<Value<'a>, Error> {
+        self.expect("{")?;
+        let mut members = Vec::new();
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected string"));
+            }
+            let key = self.string()?;
+
+            self.skip_whitespace();
+            self.expect(":")?;
+            let value = self.value(depth + 1)?;
+            members.push((key, value));
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(members));
+                }
+                Some(_) => return Err(self.error("expected ',' or '}'")),
+                None => return Err(self.error("unexpected end of input")),
+            }
+        }
+    }
+
+    /// array parses json array, elements are discarded.
+    fn array(&mut self, depth: usize) -> Result<Value<'a>, Error> {
+        self.expect("[")?;
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Other);
+        }
+
+        loop {
+            self.value(depth + 1)?;
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Other);
+                }
+                Some(_) => return Err(self.error("expected ',' or ']'")),
+                None => return Err(self.error("unexpected end of input")),
+            }
+        }
+    }
+
+    /// string parses json string, and returns unescaped string.
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect("\"")?;
+        let mut value = String::new();
+
+        loop {
+            let start = self.pos;
+            let len = self.s[start..]
+                .find(|c: char| c == '"' || c == '\\' || c < ' ')
+                .ok_or(Error::Json {
+                    offset: self.s.len(),
+                    reason: "unexpected end of input",
+                })?;
+            value.push_str(&self.s[start..start + len]);
+            self.pos += len;
+
+            match self.peek() {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(value);
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    value.push(self.escape()?);
+                }
+                _ => return Err(self.error("control character in string")),
+            }
+        }
+    }
+
+    /// escape parses escape sequence after backslash.
+    fn escape(&mut self) -> Result<char, Error> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{C}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+                let high = self.hex4()?;
+                if !(0xD800..0xDC00).contains(&high) {
+                    return char::from_u32(high)
+                        .ok_or_else(|| self.error("invalid unicode escape"));
+                }
+
+                // Surrogate pair.
+                self.expect("\\u")?;
+                let low = self.hex4()?;
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Err(self.error("invalid unicode escape"));
+                }
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"));
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+
+        self.pos += 1;
+        Ok(c)
+    }
+
+    /// hex4 parses 4 hex digits of unicode escape.
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self
+            .s
+            .get(self.pos..self.pos + 4)
+            .filter(|v| v.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    /// number parses json number, and returns it as it is.
+    fn number(&mut self) -> Result<Value<'a>, Error> {
+        let start = self.pos;
+
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+
+        match self.peek() {
+            Some(b'0') => self.pos += 1,
+            Some(b'1'..=b'9') => self.digits(),
+            _ => return Err(self.error("invalid number")),
+        }
+
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.digits();
+        }
+
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if !matches!(self.peek(), Some(b'0'..=b'9')) {
+                return Err(self.error("invalid number"));
+            }
+            self.digits();
+        }
+
+        Ok(Value::Number(&self.s[start..self.pos]))
+    }
+
+    /// digits skips digits.
+    fn digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use crate::Error;
+
+    fn error(s: &str) -> (usize, &'static str) {
+        match super::parse(s) {
+            Err(Error::Json { offset, reason }) => (offset, reason),
+            v => panic!("unexpected result: {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(super::parse(" {} ").unwrap(), Value::Object(vec![]));
+        assert_eq!(super::parse("-1.5e+3").unwrap(), Value::Number("-1.5e+3"));
+        assert_eq!(super::parse("0").unwrap(), Value::Number("0"));
+        assert_eq!(super::parse("[1, [true], {}]").unwrap(), Value::Other);
+        assert_eq!(super::parse("null").unwrap(), Value::Other);
+        assert_eq!(
+            super::parse(r#"{"a": 1, "bあ\n": {"😀": -2}, "c": "x"}"#).unwrap(),
+            Value::Object(vec![
+                (String::from("a"), Value::Number("1")),
+                (
+                    String::from("bあ\n"),
+                    Value::Object(vec![(String::from("😀"), Value::Number("-2"))])
+                ),
+                (String::from("c"), Value::Other),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(error(""), (0, "unexpected end of input"));
+        assert_eq!(error("{"), (1, "expected string"));
+        assert_eq!(error(r#"{"a" 1}"#), (5, "unexpected character"));
+        assert_eq!(error(r#"{"a": 1,}"#), (8, "expected string"));
+        assert_eq!(error(r#"{"a": 1"#), (7, "unexpected end of input"));
+        assert_eq!(error(r#"{"a": 1] "#), (7, "expected ',' or '}'"));
+        assert_eq!(error(r#"{"a": 01}"#), (7, "expected ',' or '}'"));
+        assert_eq!(error(r#"{"a": -}"#), (7, "invalid number"));
+        assert_eq!(error(r#"{"a": 1.}"#), (8, "invalid number"));
+        assert_eq!(error(r#"{"a": tru}"#), (6, "unexpected character"));
+        assert_eq!(error(r#"{"a\x": 1}"#), (4, "invalid escape"));
+        assert_eq!(error(r#"{"\ud83d": 1}"#), (8, "unexpected character"));
+        assert_eq!(error("{\"a\n\": 1}"), (3, "control character in string"));
+        assert_eq!(error(r#"{"a": 1} x"#), (9, "trailing characters"));
+        assert_eq!(error(&"[".repeat(200)), (129, "too deeply nested"));
+    }
+}
@@ -0,0 +1,325 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// OPENING_BRACKETS is opening brackets (JIS X 4051 cl-01), they must not end a line.
+const OPENING_BRACKETS: &str = "‘“（〔［｛〈《「『【〘〖〝｟«([{｢";
+
+/// CLOSING_BRACKETS is closing brackets (JIS X 4051 cl-02), they must not start a line.
+const CLOSING_BRACKETS: &str = "’”）〕］｝〉》」』】〙〗〟｠»)]}｣";
+
+/// HYPHENS is hyphens (JIS X 4051 cl-03).
+const HYPHENS: &str = "‐〜～゠–";
+
+/// DIVIDING_PUNCTUATION is dividing punctuation marks (JIS X 4051 cl-04).
+const DIVIDING_PUNCTUATION: &str = "？！‼⁇⁈⁉?!";
+
+/// MIDDLE_DOTS is middle dots (JIS X 4051 cl-05).
+const MIDDLE_DOTS: &str = "・：；:;･";
+
+/// FULL_STOPS is full stops (JIS X 4051 cl-06).
+const FULL_STOPS: &str = "。．.｡";
+
+/// COMMAS is commas (JIS X 4051 cl-07).
+const COMMAS: &str = "、，,､";
+
+/// ITERATION_MARKS is iteration marks (JIS X 4051 cl-09).
+const ITERATION_MARKS: &str = "ヽヾゝゞ々〻";
+
+/// PROLONGED_SOUND_MARKS is prolonged sound marks (JIS X 4051 cl-10).
+const PROLONGED_SOUND_MARKS: &str = "ーｰ";
+
+/// SMALL_KANA is small kana (JIS X 4051 cl-11).
+const SMALL_KANA: &str =
+    "ぁぃぅぇぉっゃゅょゎゕゖァィゥェォッャュョヮヵヶㇰㇱㇲㇳㇴㇵㇶㇷㇸㇹㇺㇻㇼㇽㇾㇿｧｨｩｪｫｬｭｮｯ";
+
+/// INSEPARABLE is inseparable characters (JIS X 4051 cl-08), a line must not break between two of them.
+const INSEPARABLE: &str = "—―…‥";
+
+/// Rule is set of line breaking rules of japanese (kinsoku shori), based on JIS X 4051.
+///
+/// Both rules prohibit opening brackets at the end of a line,
+/// and closing brackets, punctuation marks and so on at the start of a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// Strict prohibits all line start prohibited characters of JIS X 4051,
+    /// including small kana (e.g. "っ") and prolonged sound marks ("ー").
+    Strict,
+    /// Loose allows small kana and prolonged sound marks at the start of a line,
+    /// as relaxed rule of JIS X 4051.
+    Loose,
+}
+
+impl Rule {
+    /// is_start_prohibited returns true if c must not start a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use budoux::kinsoku::Rule;
+    ///
+    /// assert!(Rule::Strict.is_start_prohibited('。'));
+    /// assert!(Rule::Strict.is_start_prohibited('ー'));
+    /// assert!(!Rule::Loose.is_start_prohibited('ー'));
+    /// assert!(!Rule::Strict.is_start_prohibited('「'));
+    /// ```
+    pub fn is_start_prohibited(self, c: char) -> bool {
+        let relaxed = match self {
+            Rule::Strict => false,
+            Rule::Loose => SMALL_KANA.contains(c) || PROLONGED_SOUND_MARKS.contains(c),
+        };
+
+        !relaxed
+            && [
+                CLOSING_BRACKETS,
+                HYPHENS,
+                DIVIDING_PUNCTUATION,
+                MIDDLE_DOTS,
+                FULL_STOPS,
+                COMMAS,
+                ITERATION_MARKS,
+                PROLONGED_SOUND_MARKS,
+                SMALL_KANA,
+            ]
+            .iter()
+            .any(|v| v.contains(c))
+    }
+
+    /// is_end_prohibited returns true if c must not end a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use budoux::kinsoku::Rule;
+    ///
+    /// assert!(Rule::Strict.is_end_prohibited('「'));
+    /// assert!(Rule::Loose.is_end_prohibited('（'));
+    /// assert!(!Rule::Strict.is_end_prohibited('」'));
+    /// ```
+    pub fn is_end_prohibited(self, c: char) -> bool {
+        OPENING_BRACKETS.contains(c)
+    }
+
+    /// allows_break returns true if a line can break between before and after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use budoux::kinsoku::Rule;
+    ///
+    /// assert!(Rule::Strict.allows_break('は', 'テ'));
+    /// assert!(!Rule::Strict.allows_break('す', '。'));
+    /// assert!(!Rule::Strict.allows_break('「', 'テ'));
+    /// assert!(!Rule::Strict.allows_break('…', '…'));
+    /// ```
+    pub fn allows_break(self, before: char, after: char) -> bool {
+        if before == after && INSEPARABLE.contains(before) {
+            return false;
+        }
+
+        !self.is_end_prohibited(before) && !self.is_start_prohibited(after)
+    }
+}
+
+/// adjust returns boundaries of input adjusted by rule.
+///
+/// Line start prohibited characters after a boundary are moved into the previous phrase,
+/// and line end prohibited characters before a boundary are moved into the next phrase.
+/// A boundary is removed if it can not be adjusted inside the phrases around it.
+///
+/// * `input` - input sentences.
+/// * `boundaries` - sorted byte offsets of phrase boundaries (e.g. result of budoux::boundaries).
+/// * `rule` - line breaking rule.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::kinsoku::{adjust, Rule};
+///
+/// let input = "「こんにちは」と言った";
+///
+/// assert_eq!(adjust(input, &[18, 24], Rule::Strict), vec![21, 24]);
+/// assert_eq!(adjust(input, &[3, 18], Rule::Strict), vec![21]);
+/// ```
+pub fn adjust(input: &str, boundaries: &[usize], rule: Rule) -> Vec<usize> {
+    let mut adjusted = Vec::with_capacity(boundaries.len());
+    let mut prev = 0;
+
+    for (i, offset) in boundaries.iter().enumerate() {
+        let next = boundaries.get(i + 1).copied().unwrap_or(input.len());
+        let mut offset = *offset;
+
+        // Pull line start prohibited characters into the previous phrase.
+        while let Some(c) = input[offset..next].chars().next() {
+            if !rule.is_start_prohibited(c) {
+                break;
+            }
+            offset += c.len_utf8();
+        }
+
+        // Push line end prohibited characters into the next phrase.
+        while let Some(c) = input[prev..offset].chars().next_back() {
+            if !rule.is_end_prohibited(c) {
+                break;
+            }
+            offset -= c.len_utf8();
+        }
+
+        if offset <= prev || offset >= next {
+            continue;
+        }
+
+        let before = input[..offset].chars().next_back();
+        let after = input[offset..].chars().next();
+        if let (Some(before), Some(after)) = (before, after) {
+            if rule.allows_break(before, after) {
+                adjusted.push(offset);
+                prev = offset;
+            }
+        }
+    }
+
+    adjusted
+}
+
+/// apply returns phrases adjusted by rule, see budoux::kinsoku::adjust.
+///
+/// * `phrases` - phrases of sentences (e.g. result of budoux::parse_with_threshold).
+/// * `rule` - line breaking rule.
+///
+/// # Examples
+///
+/// ```
+/// let phrases = ["「こんにちは", "」と", "言った"];
+/// let phrases = budoux::kinsoku::apply(&phrases, budoux::kinsoku::Rule::Strict);
+///
+/// assert_eq!(phrases, vec!["「こんにちは」", "と", "言った"]);
+/// ```
+pub fn apply<S: AsRef<str>>(phrases: &[S], rule: Rule) -> Vec<String> {
+    let mut input = String::new();
+    let mut boundaries = Vec::with_capacity(phrases.len());
+    for phrase in phrases {
+        if !input.is_empty() {
+            boundaries.push(input.len());
+        }
+        input.push_str(phrase.as_ref());
+    }
+    boundaries.dedup();
+
+    let mut result = Vec::with_capacity(phrases.len());
+    let mut start = 0;
+    for end in adjust(&input, &boundaries, rule) {
+        result.push(input[start..end].to_string());
+        start = end;
+    }
+    result.push(input[start..].to_string());
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rule;
+
+    #[test]
+    fn test_rule() {
+        for c in "」）。、．，！？・：ー々ゝっャァ〜～".chars() {
+            assert!(Rule::Strict.is_start_prohibited(c), "{:?}", c);
+        }
+        for c in "」）。、！？・々".chars() {
+            assert!(Rule::Loose.is_start_prohibited(c), "{:?}", c);
+        }
+        for c in "ーっャァｰｯ".chars() {
+            assert!(!Rule::Loose.is_start_prohibited(c), "{:?}", c);
+        }
+        for c in "あア漢a「（".chars() {
+            assert!(!Rule::Strict.is_start_prohibited(c), "{:?}", c);
+            assert!(!Rule::Loose.is_start_prohibited(c), "{:?}", c);
+        }
+
+        for rule in [Rule::Strict, Rule::Loose] {
+            for c in "「（『【〈(".chars() {
+                assert!(rule.is_end_prohibited(c), "{:?}", c);
+            }
+            for c in "」）。あa".chars() {
+                assert!(!rule.is_end_prohibited(c), "{:?}", c);
+            }
+
+            assert!(!rule.allows_break('―', '―'));
+            assert!(rule.allows_break('―', 'あ'));
+            assert!(rule.allows_break('」', '「'));
+        }
+
+        // Fullwidth tilde is used in place of wave dash (e.g. "10～20").
+        assert!(!Rule::Strict.allows_break('0', '～'));
+        assert!(Rule::Strict.allows_break('～', '2'));
+    }
+
+    #[test]
+    fn test_adjust() {
+        let input = "これは「テスト」です。";
+        // これは|「テスト|」|です|。
+        let boundaries = [9, 21, 24, 30];
+
+        assert_eq!(super::adjust(input, &boundaries, Rule::Strict), vec![9, 24]);
+        assert_eq!(super::adjust(input, &[12], Rule::Strict), vec![9]);
+        assert_eq!(
+            super::adjust(input, &[30], Rule::Strict),
+            Vec::<usize>::new()
+        );
+        assert_eq!(super::adjust(input, &[], Rule::Strict), Vec::<usize>::new());
+        assert_eq!(super::adjust("", &[], Rule::Strict), Vec::<usize>::new());
+
+        let input = "コーヒーとチョコレート";
+        assert_eq!(super::adjust(input, &[3], Rule::Strict), vec![6]);
+        assert_eq!(super::adjust(input, &[3], Rule::Loose), vec![3]);
+        assert_eq!(super::adjust(input, &[9, 15], Rule::Strict), vec![12, 15]);
+
+        let input = "待って……ください";
+        assert_eq!(super::adjust(input, &[3], Rule::Loose), vec![3]);
+        assert_eq!(super::adjust(input, &[3], Rule::Strict), vec![6]);
+        assert_eq!(
+            super::adjust(input, &[12], Rule::Strict),
+            Vec::<usize>::new()
+        );
+        assert_eq!(super::adjust(input, &[9, 15], Rule::Strict), vec![9, 15]);
+    }
+
+    #[test]
+    fn test_apply() {
+        let phrases = ["これは", "「テスト", "」", "です", "。"];
+        assert_eq!(
+            super::apply(&phrases, Rule::Strict),
+            vec!["これは", "「テスト」", "です。"]
+        );
+
+        assert_eq!(super::apply::<&str>(&[], Rule::Strict), vec![""]);
+        assert_eq!(super::apply(&[""], Rule::Strict), vec![""]);
+        assert_eq!(
+            super::apply(&["", "水と", "", "油"], Rule::Strict),
+            vec!["水と", "油"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_apply_ja() {
+        let m = crate::models::default_japanese_model();
+
+        for input in [
+            "「こんにちは」と彼は言った。",
+            "ちょっと待ってください……本当に？",
+        ] {
+            let phrases = crate::parse(m, input);
+            for rule in [Rule::Strict, Rule::Loose] {
+                let adjusted = super::apply(&phrases, rule);
+                assert_eq!(adjusted.concat(), input);
+
+                for pair in adjusted.windows(2) {
+                    let before = pair[0].chars().next_back().unwrap();
+                    let after = pair[1].chars().next().unwrap();
+                    assert!(rule.allows_break(before, after), "{:?}", pair);
+                }
+            }
+        }
+    }
+}
@@ -6,29 +6,133 @@
 //! This project contains the deliverables of the [BudouX](https://github.com/google/budoux) project.
 //!
 //! Note:
-//! BudouX-rs supported plain text only, not supports html inputs.
+//! BudouX-rs splits plain text, and html fragments are supported by budoux::html module.
+//!
+//! Note:
+//! BudouX-rs supports `no_std` with `alloc`, by disabling `std` feature (enabled by default).
+//! Without `std` feature, budoux::Model is backed by ordered map instead of hash map,
+//! and loading models from readers and training are not available.
+//! budoux::models::StaticModel works as it is.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
-use std::collections::HashMap;
+extern crate alloc;
 
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+mod compiled;
+mod east_asian_width;
+mod error;
+mod features;
+mod format;
+mod grapheme;
+mod grapheme_break;
+mod json;
+mod model;
+mod parser;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod unicode_blocks;
+mod validate;
+
+/// eval provides evaluation of models with segmented sentences.
+pub mod eval;
+
+/// html provides splitting of html fragments.
+pub mod html;
+
+/// train provides training of models from segmented sentences, budoux::train::Trainer requires `std` feature.
+pub mod train;
+
+/// kinsoku provides line breaking rules of japanese (kinsoku shori).
+pub mod kinsoku;
 
 /// models provides trained machine learning model.
 pub mod models;
 
+/// protect provides matchers of protected spans (e.g. URLs), which are never split into phrases.
+pub mod protect;
+
+/// wrap provides wrapping of phrases into lines of a width.
+pub mod wrap;
+
+pub use compiled::CompiledModel;
+pub use error::Error;
+pub use format::{format, format_with_threshold, Separator};
+pub use model::{Metadata, Model, Schema};
+pub use parser::{Overflow, Parser};
+pub use validate::{validate, Issue, Problem, Report};
+
+/// Map is map of feature keys, hash map with `std` feature and ordered map without it.
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+
+/// Map is map of feature keys, hash map with `std` feature and ordered map without it.
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
 /// DEFAULT_THRESHOLD is default threshold for splitting a sentences.
 pub const DEFAULT_THRESHOLD: i32 = 1000;
 
-/// Model is type of trained machine learning model.
-/// key (String) is feature of character, value (i32) is score of feature.
-pub type Model = HashMap<String, i32>;
-
 /// INVALID_FEATURE is indicate for invalid feature.
 const INVALID_FEATURE: &str = "▔";
 
+/// Scorer is trained machine learning model, which scores a gap between characters.
+///
+/// It is implemented by budoux::Model, budoux::models::StaticModel and budoux::CompiledModel,
+/// so every parse function accepts all of them.
+pub trait Scorer {
+    /// lookup returns score of feature key (e.g. "UW3:あ"), None if model does not contain the key.
+    fn lookup(&self, key: &str) -> Option<i32>;
+
+    /// schema returns feature set used by the model.
+    fn schema(&self) -> Schema {
+        Schema::V1
+    }
+
+    /// base_score returns score added to every gap.
+    fn base_score(&self) -> i32 {
+        0
+    }
+
+    /// score returns score of the gap between `window.chars[2]` and `window.chars[3]`.
+    /// Default implementation is sum of base score and budoux::Scorer::lookup for every feature key of schema.
+    ///
+    /// * `window` - characters and features around the gap.
+    /// * `buf` - working buffer for building feature keys.
+    fn score(&self, window: &Window, buf: &mut String) -> i32 {
+        get_feature(self, buf, window)
+    }
+}
+
+/// Window is characters and features around a gap, from i - 3 to i + 2.
+/// The gap is between index 2 (i - 1) and index 3 (i).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window<'a> {
+    /// characters, empty if out of input.
+    pub chars: [&'a str; 6],
+    /// unicode block features of characters.
+    pub blocks: [&'a str; 6],
+    /// results of previous three gaps, "U" (unknown), "B" (positive) or "O" (negative).
+    pub results: [&'a str; 3],
+}
+
+impl<'a> Window<'a> {
+    /// slot returns value of window referred by feature template.
+    pub(crate) fn slot(&self, slot: features::Slot) -> &'a str {
+        match slot {
+            features::Slot::Char(i) => self.chars[i],
+            features::Slot::Block(i) => self.blocks[i],
+            features::Slot::Result(i) => self.results[i],
+        }
+    }
+}
+
 /// parse returns splitted string slice from input.
 /// It is shorthand for budoux::parse_with_threshold(model, input, budoux::DEFAULT_THRESHOLD).
 ///
-/// * `model` - trained machine learning model.
+/// * `model` - trained machine learning model (see budoux::Scorer).
 /// * `input` - input sentences.
 ///
 /// # Examples
@@ -36,10 +140,13 @@ const INVALID_FEATURE: &str = "▔";
 /// Split sentences with internal model.
 ///
 /// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
 /// let model = budoux::models::default_japanese_model();
 /// let words = budoux::parse(model, "これはテストです。");
 ///
 /// assert_eq!(words, vec!["これは", "テストです。"]);
+/// # }
 /// ```
 ///
 /// Load model from json file and split sentences using the loaded model.
@@ -47,18 +154,21 @@ const INVALID_FEATURE: &str = "▔";
 /// ```ignore
 /// let file = File::open(path_to_json).unwrap();
 /// let reader = BufReader::new(file);
-/// let model: budoux::Model = serde_json::from_reader(reader).unwrap();
+/// let model = budoux::Model::from_json_reader(reader).unwrap();
 /// let words = budoux::parse(&model, "これはテストです。");
 ///
 /// assert_eq!(words, vec!["これは", "テストです。"]);
 /// ```
-pub fn parse(model: &Model, input: &str) -> Vec<String> {
+pub fn parse<M: Scorer + ?Sized>(model: &M, input: &str) -> Vec<String> {
     parse_with_threshold(model, input, DEFAULT_THRESHOLD)
 }
 
 /// parse_with_threshold returns splitted string slice from input.
 ///
-/// * `model` - trained machine learning model.
+/// URLs, e-mail addresses, numbers and inline code are never split, see budoux::protect.
+/// Use budoux::Parser to register additional matchers of protected spans.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
 /// * `input` - input sentences.
 /// * `threshold` - threshold for splitting a sentences.
 ///
@@ -67,95 +177,665 @@ pub fn parse(model: &Model, input: &str) -> Vec<String> {
 /// Split sentences with internal model.
 ///
 /// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
 /// let model = budoux::models::default_japanese_model();
 /// let words = budoux::parse_with_threshold(model, "これはテストです。", budoux::DEFAULT_THRESHOLD);
 ///
 /// assert_eq!(words, vec!["これは", "テストです。"]);
+/// # }
 /// ```
 ///
 /// If you use a large threshold, will not be split.
 ///
 /// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
 /// let model = budoux::models::default_japanese_model();
 /// let words = budoux::parse_with_threshold(model, "これはテストです。", 100000000);
 ///
 /// assert_eq!(words, vec!["これはテストです。"]);
+/// # }
 /// ```
-pub fn parse_with_threshold(model: &Model, input: &str, threshold: i32) -> Vec<String> {
-    if input.is_empty() {
-        return vec![String::default()];
-    }
+pub fn parse_with_threshold<M: Scorer + ?Sized>(
+    model: &M,
+    input: &str,
+    threshold: i32,
+) -> Vec<String> {
+    parse_slices_with_threshold(model, input, threshold)
+        .into_iter()
+        .map(String::from)
+        .collect()
+}
 
-    let mut out: Vec<String> = Vec::new();
+/// parse_slices returns splitted string slice borrowed from input.
+/// It is shorthand for budoux::parse_slices_with_threshold(model, input, budoux::DEFAULT_THRESHOLD).
+///
+/// Unlike budoux::parse, returned phrases are not copied.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let words = budoux::parse_slices(model, "これはテストです。");
+///
+/// assert_eq!(words, vec!["これは", "テストです。"]);
+/// # }
+/// ```
+pub fn parse_slices<'a, M: Scorer + ?Sized>(model: &M, input: &'a str) -> Vec<&'a str> {
+    parse_slices_with_threshold(model, input, DEFAULT_THRESHOLD)
+}
 
-    let mut p1 = "U"; // unknown
-    let mut p2 = "U"; // unknown
-    let mut p3 = "U"; // unknown
+/// parse_slices_with_threshold returns splitted string slice borrowed from input.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let words = budoux::parse_slices_with_threshold(model, "これはテストです。", 100000000);
+///
+/// assert_eq!(words, vec!["これはテストです。"]);
+/// # }
+/// ```
+pub fn parse_slices_with_threshold<'a, M: Scorer + ?Sized>(
+    model: &M,
+    input: &'a str,
+    threshold: i32,
+) -> Vec<&'a str> {
+    phrases_with_threshold(model, input, threshold).collect()
+}
 
-    let mut chars = input.char_indices();
+/// phrases returns lazy iterator over phrases of input.
+/// It is shorthand for budoux::phrases_with_threshold(model, input, budoux::DEFAULT_THRESHOLD).
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+///
+/// # Examples
+///
+/// Stop splitting as soon as enough phrases are found.
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let mut phrases = budoux::phrases(model, "これはテストです。今日は晴天です。");
+///
+/// assert_eq!(phrases.next(), Some("これは"));
+/// assert_eq!(phrases.next(), Some("テストです。"));
+/// # }
+/// ```
+pub fn phrases<'m, 'a, M: Scorer + ?Sized>(model: &'m M, input: &'a str) -> Phrases<'m, 'a, M> {
+    phrases_with_threshold(model, input, DEFAULT_THRESHOLD)
+}
 
-    let (mut w1, mut b1) = ("", INVALID_FEATURE); // i - 3
-    let (mut w2, mut b2) = ("", INVALID_FEATURE); // i - 2
-    let (mut w3, mut s3, mut b3) = get_unicode_block_and_feature(input, &mut chars); // i - 1
-    let (mut w4, mut s4, mut b4) = get_unicode_block_and_feature(input, &mut chars); // i
-    let (mut w5, mut s5, mut b5) = get_unicode_block_and_feature(input, &mut chars); // i + 1
+/// phrases_with_threshold returns lazy iterator over phrases of input.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::phrases_with_threshold(model, "これはテストです。", 100000000);
+///
+/// assert_eq!(phrases.collect::<Vec<_>>(), vec!["これはテストです。"]);
+/// # }
+/// ```
+pub fn phrases_with_threshold<'m, 'a, M: Scorer + ?Sized>(
+    model: &'m M,
+    input: &'a str,
+    threshold: i32,
+) -> Phrases<'m, 'a, M> {
+    Phrases::new(model, input, threshold)
+}
 
-    let mut start: usize = 0;
-    let mut end = s3;
+/// parse_phrases returns phrases of input with their offsets and scores.
+/// It is shorthand for budoux::parse_phrases_with_threshold(model, input, budoux::DEFAULT_THRESHOLD).
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::parse_phrases(model, "これはテストです。");
+///
+/// assert_eq!(phrases.len(), 2);
+/// assert_eq!(phrases[0].text, "これは");
+/// assert_eq!((phrases[1].start, phrases[1].end), (9, 27));
+/// assert!(phrases[0].score.unwrap() > budoux::DEFAULT_THRESHOLD);
+/// assert_eq!(phrases[1].score, None);
+/// # }
+/// ```
+pub fn parse_phrases<'a, M: Scorer + ?Sized>(model: &M, input: &'a str) -> Vec<Phrase<'a>> {
+    parse_phrases_with_threshold(model, input, DEFAULT_THRESHOLD)
+}
 
-    let mut wb = String::with_capacity(20); // working buffer
+/// parse_phrases_with_threshold returns phrases of input with their offsets and scores.
+///
+/// Phrases are same as budoux::parse_slices_with_threshold, so empty input yields a single empty phrase.
+/// With `serde` feature, the result can be serialized as it is.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::parse_phrases_with_threshold(model, "これはテストです。", 100000000);
+///
+/// assert_eq!(phrases.len(), 1);
+/// assert_eq!(phrases[0].text, "これはテストです。");
+/// # }
+/// ```
+pub fn parse_phrases_with_threshold<'a, M: Scorer + ?Sized>(
+    model: &M,
+    input: &'a str,
+    threshold: i32,
+) -> Vec<Phrase<'a>> {
+    let mut phrases = Vec::new();
+    let mut start = 0;
 
-    while s3 != 0 {
-        let (w6, s6, b6) = get_unicode_block_and_feature(input, &mut chars);
+    for (end, score) in Scanner::new(model, input) {
+        if score > threshold && end < input.len() {
+            phrases.push(Phrase {
+                text: &input[start..end],
+                start,
+                end,
+                score: Some(score),
+            });
+            start = end;
+        }
+    }
 
-        let score = get_feature(
-            model, &mut wb, w1, w2, w3, w4, w5, w6, b1, b2, b3, b4, b5, b6, p1, p2, p3,
-        );
+    phrases.push(Phrase {
+        text: &input[start..],
+        start,
+        end: input.len(),
+        score: None,
+    });
+
+    phrases
+}
 
-        if score > threshold {
-            out.push(input[start..end].to_string());
+/// boundaries returns byte offsets of phrase boundaries in input.
+///
+/// Offsets are positions between phrases, so the start and the end of input are not included.
+/// A boundary is never placed inside an extended grapheme cluster,
+/// such as emoji sequences, flags and characters followed by combining marks or variation selectors.
+/// Neither is it placed inside a protected span, see budoux::protect::DEFAULT_MATCHERS.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let offsets = budoux::boundaries(model, "これはテストです。", budoux::DEFAULT_THRESHOLD);
+///
+/// assert_eq!(offsets, vec![9]);
+/// # }
+/// ```
+pub fn boundaries<M: Scorer + ?Sized>(model: &M, input: &str, threshold: i32) -> Vec<usize> {
+    Scanner::new(model, input)
+        .filter(|&(end, score)| score > threshold && end < input.len())
+        .map(|(end, _)| end)
+        .collect()
+}
+
+/// char_boundaries returns char offsets of phrase boundaries in input.
+///
+/// It is same as budoux::boundaries, but offsets are counted in chars instead of bytes.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `threshold` - threshold for splitting a sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let offsets = budoux::char_boundaries(model, "これはテストです。", budoux::DEFAULT_THRESHOLD);
+///
+/// assert_eq!(offsets, vec![3]);
+/// # }
+/// ```
+pub fn char_boundaries<M: Scorer + ?Sized>(model: &M, input: &str, threshold: i32) -> Vec<usize> {
+    let mut start = 0;
+    let mut count = 0;
+
+    Scanner::new(model, input)
+        .filter(|&(end, score)| score > threshold && end < input.len())
+        .map(|(end, _)| {
+            count += input[start..end].chars().count();
             start = end;
+            count
+        })
+        .collect()
+}
+
+/// scores returns byte offset and score of each character gap in input.
+///
+/// Scores do not depend on threshold, so a gap is a phrase boundary when its score is greater than threshold.
+/// The end of input and gaps inside an extended grapheme cluster or a protected span are not included.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let scores = budoux::scores(model, "水と油");
+///
+/// assert_eq!(scores.len(), 2);
+/// assert_eq!(scores[1].0, 6);
+/// assert!(scores[1].1 > budoux::DEFAULT_THRESHOLD);
+/// # }
+/// ```
+pub fn scores<M: Scorer + ?Sized>(model: &M, input: &str) -> Vec<(usize, i32)> {
+    Scanner::new(model, input)
+        .filter(|&(end, _)| end < input.len())
+        .collect()
+}
+
+/// explain returns features used for the boundary decision at offset.
+///
+/// It is intended for debugging of models, because it lists every feature key looked up at the gap.
+/// None is returned if offset is not a character gap inside input,
+/// or it is inside an extended grapheme cluster or a protected span.
+///
+/// * `model` - trained machine learning model (see budoux::Scorer).
+/// * `input` - input sentences.
+/// * `offset` - byte offset of the gap, same as budoux::boundaries.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let explanation = budoux::explain(model, "水と油", 6).unwrap();
+///
+/// assert!(explanation.score > budoux::DEFAULT_THRESHOLD);
+/// assert!(explanation.features.iter().any(|v| v.key == "UW3:と"));
+/// # }
+/// ```
+pub fn explain<M: Scorer + ?Sized>(model: &M, input: &str, offset: usize) -> Option<Explanation> {
+    if offset >= input.len() {
+        return None;
+    }
+
+    let mut scanner = Scanner::new(model, input);
+    while scanner.end < offset {
+        scanner.step()?;
+    }
+
+    if scanner.end != offset {
+        return None;
+    }
+
+    scanner.next_explained()
+}
+
+/// Phrase is a phrase of input with its position, see budoux::parse_phrases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Phrase<'a> {
+    /// text of the phrase, borrowed from input.
+    pub text: &'a str,
+    /// byte offset of the start of the phrase.
+    pub start: usize,
+    /// byte offset of the end of the phrase.
+    pub end: usize,
+    /// score of the boundary at the end, None for the last phrase.
+    pub score: Option<i32>,
+}
+
+/// Explanation is detail of the boundary decision at a gap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Explanation {
+    /// byte offset of the gap.
+    pub offset: usize,
+    /// score of the gap, it is sum of base score and feature scores.
+    pub score: i32,
+    /// base score of model, see budoux::Scorer::base_score.
+    pub base_score: i32,
+    /// features looked up at the gap, in lookup order.
+    pub features: Vec<Feature>,
+}
+
+/// Feature is a feature key looked up in model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Feature {
+    /// key of feature (e.g. "UW3:あ").
+    pub key: String,
+    /// score of feature, None if model does not contain the key.
+    pub score: Option<i32>,
+}
+
+/// Phrases is iterator over phrases borrowed from input.
+///
+/// Boundaries are computed on demand, so dropping the iterator early skips the rest of input.
+/// As well as budoux::parse, empty input yields a single empty phrase.
+pub struct Phrases<'m, 'a, M: ?Sized = Model> {
+    scanner: Scanner<'m, 'a, M>,
+    input: &'a str,
+    threshold: i32,
+    start: usize,
+    finished: bool,
+}
+
+impl<'m, 'a, M: Scorer + ?Sized> Phrases<'m, 'a, M> {
+    fn new(model: &'m M, input: &'a str, threshold: i32) -> Self {
+        Phrases {
+            scanner: Scanner::new(model, input),
+            input,
+            threshold,
+            start: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<'m, 'a, M: Scorer + ?Sized> Iterator for Phrases<'m, 'a, M> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        for (end, score) in &mut self.scanner {
+            if score > self.threshold {
+                let phrase = &self.input[self.start..end];
+                self.start = end;
+                return Some(phrase);
+            }
+        }
+
+        self.finished = true;
+
+        if self.start < self.input.len() || self.input.is_empty() {
+            return Some(&self.input[self.start..]);
         }
 
-        p1 = p2;
-        p2 = p3;
+        None
+    }
+}
+
+impl<'m, 'a, M: Scorer + ?Sized> core::iter::FusedIterator for Phrases<'m, 'a, M> {}
+
+/// Scanner is iterator over scores of each character gap.
+/// Item is byte offset of the gap (after i - 1) and its score.
+/// Gaps inside an extended grapheme cluster (e.g. before a combining mark) or a protected span are skipped.
+struct Scanner<'m, 'a, M: ?Sized> {
+    model: &'m M,
+    input: &'a str,
+    chars: core::str::CharIndices<'a>,
+    wb: String, // working buffer
+
+    window: Window<'a>,
+    clusters: grapheme::Clusters,
+    spans: protect::Spans<'m>,
+
+    s3: usize, // size of i - 1
+    s4: usize, // size of i
+    s5: usize, // size of i + 1
+
+    end: usize,
+}
+
+impl<'m, 'a, M: Scorer + ?Sized> Scanner<'m, 'a, M> {
+    fn new(model: &'m M, input: &'a str) -> Self {
+        Scanner::with_buffer(
+            model,
+            input,
+            String::with_capacity(20),
+            &protect::DEFAULT_MATCHERS,
+            Vec::new(),
+        )
+    }
+
+    /// with_buffer returns scanner using wb as working buffer, matchers of protected spans
+    /// and cache of their matches as buffer, they are given by budoux::Parser.
+    fn with_buffer(
+        model: &'m M,
+        input: &'a str,
+        wb: String,
+        matchers: &'m [&'m dyn protect::Matcher],
+        cache: protect::Cache,
+    ) -> Self {
+        let mut chars = input.char_indices();
+
+        let (w3, s3, b3) = get_unicode_block_and_feature(input, &mut chars);
+        let (w4, s4, b4) = get_unicode_block_and_feature(input, &mut chars);
+        let (w5, s5, b5) = get_unicode_block_and_feature(input, &mut chars);
+
+        Scanner {
+            model,
+            input,
+            chars,
+            wb,
+            window: Window {
+                chars: ["", "", w3, w4, w5, ""],
+                blocks: [
+                    INVALID_FEATURE,
+                    INVALID_FEATURE,
+                    b3,
+                    b4,
+                    b5,
+                    INVALID_FEATURE,
+                ],
+                results: ["U", "U", "U"], // unknown
+            },
+            clusters: grapheme::Clusters::default(),
+            spans: protect::Spans::new(matchers, cache),
+            s3,
+            s4,
+            s5,
+            end: s3,
+        }
+    }
+
+    /// fill reads next character into the end of window, and returns its size.
+    fn fill(&mut self) -> usize {
+        let (w6, s6, b6) = get_unicode_block_and_feature(self.input, &mut self.chars);
+        self.window.chars[5] = w6;
+        self.window.blocks[5] = b6;
+
+        s6
+    }
+
+    /// is_cluster_boundary returns true if current gap is not inside an extended grapheme cluster.
+    /// It must be called once for each gap, before advance.
+    fn is_cluster_boundary(&mut self) -> bool {
+        let before = self.window.chars[2].chars().next();
+        let after = self.window.chars[3].chars().next();
+
+        match (before, after) {
+            (Some(before), Some(after)) => self.clusters.is_boundary(before, after),
+            _ => true,
+        }
+    }
+
+    /// is_candidate returns true if current gap can be a phrase boundary,
+    /// it is neither inside an extended grapheme cluster nor inside a protected span.
+    /// It must be called once for each gap, before advance.
+    fn is_candidate(&mut self) -> bool {
+        let cluster = self.is_cluster_boundary();
+
+        cluster && !self.spans.covers(self.input, self.end)
+    }
+
+    /// advance shifts window to next character, and returns offset and score of current gap.
+    fn advance(&mut self, s6: usize, score: i32) -> (usize, i32) {
+        let end = self.end;
+
+        self.window.results.rotate_left(1);
 
         if score > 0 {
-            p3 = "B"; // positive
+            self.window.results[2] = "B"; // positive
         } else {
-            p3 = "O"; // negative
+            self.window.results[2] = "O"; // negative
+        }
+
+        self.window.chars.rotate_left(1);
+        self.window.blocks.rotate_left(1);
+
+        self.s3 = self.s4;
+        self.s4 = self.s5;
+        self.s5 = s6;
+
+        self.end += self.s3;
+
+        (end, score)
+    }
+}
+
+impl<'m, 'a, M: Scorer + ?Sized> Scanner<'m, 'a, M> {
+    /// step scores next gap and advances scanner, it returns whether the gap is a boundary candidate as well.
+    ///
+    /// Gaps inside an extended grapheme cluster or a protected span are scored to keep results of window,
+    /// but they are never yielded as boundary candidates.
+    fn step(&mut self) -> Option<(usize, i32, bool)> {
+        if self.s3 == 0 {
+            return None;
+        }
+
+        let candidate = self.is_candidate();
+        let s6 = self.fill();
+        let score = self.model.score(&self.window, &mut self.wb);
+        let (end, score) = self.advance(s6, score);
+
+        Some((end, score, candidate))
+    }
+
+    /// next_explained returns explanation of next gap, and advances scanner.
+    /// None is returned if the gap is inside an extended grapheme cluster or a protected span.
+    fn next_explained(&mut self) -> Option<Explanation> {
+        if self.s3 == 0 || !self.is_candidate() {
+            return None;
         }
 
-        w1 = w2;
-        w2 = w3;
-        w3 = w4;
-        w4 = w5;
-        w5 = w6;
+        let s6 = self.fill();
+
+        let model = self.model;
+        let base_score = model.base_score();
+        let mut features = Vec::new();
+        visit_features(&mut self.wb, &self.window, model.schema(), &mut |key| {
+            features.push(Feature {
+                key: key.to_string(),
+                score: model.lookup(key),
+            })
+        });
+
+        let score = features.iter().fold(base_score, |acc, v| {
+            acc.saturating_add(v.score.unwrap_or(0))
+        });
+        let (offset, score) = self.advance(s6, score);
 
-        b1 = b2;
-        b2 = b3;
-        b3 = b4;
-        b4 = b5;
-        b5 = b6;
+        Some(Explanation {
+            offset,
+            score,
+            base_score,
+            features,
+        })
+    }
+}
 
-        s3 = s4;
-        s4 = s5;
-        s5 = s6;
+impl<'m, 'a, M: Scorer + ?Sized> Iterator for Scanner<'m, 'a, M> {
+    type Item = (usize, i32);
 
-        end += s3;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (end, score, candidate) = self.step()?;
+            if candidate {
+                return Some((end, score));
+            }
+        }
     }
+}
+
+/// Labels is scorer which only provides schema, used by visit_labeled_features.
+#[cfg(feature = "std")]
+struct Labels(Schema);
 
-    if start < input.len() {
-        out.push(input[start..].to_string());
+#[cfg(feature = "std")]
+impl Scorer for Labels {
+    fn lookup(&self, _key: &str) -> Option<i32> {
+        None
     }
 
-    out
+    fn schema(&self) -> Schema {
+        self.0
+    }
+}
+
+/// visit_labeled_features calls visit with byte offset, label and feature keys of each character gap in input.
+/// The end of input is not included.
+///
+/// Previous results of window are given labels instead of scores, as well as training of BudouX.
+#[cfg(feature = "std")]
+fn visit_labeled_features(
+    input: &str,
+    schema: Schema,
+    is_boundary: impl Fn(usize) -> bool,
+    visit: &mut impl FnMut(usize, bool, &str),
+) {
+    let labels = Labels(schema);
+    let mut scanner = Scanner::new(&labels, input);
+
+    while scanner.s3 != 0 && scanner.end < input.len() {
+        let s6 = scanner.fill();
+        let offset = scanner.end;
+        let label = is_boundary(offset);
+
+        visit_features(&mut scanner.wb, &scanner.window, schema, &mut |key| {
+            visit(offset, label, key)
+        });
+
+        scanner.advance(s6, if label { 1 } else { -1 });
+    }
 }
 
 /// get_unicode_block_and_feature returns unicode character and block feature from char slice.
 fn get_unicode_block_and_feature<'a>(
     input: &'a str,
-    chars: &mut std::str::CharIndices,
+    chars: &mut core::str::CharIndices,
 ) -> (&'a str, usize, &'a str) {
     let v = chars.next();
     if v.is_none() {
@@ -177,86 +857,31 @@ fn get_unicode_block_and_feature<'a>(
     )
 }
 
-/// get_feature returns feature list.
-#[allow(clippy::too_many_arguments)]
-fn get_feature(
-    model: &Model,
-    buf: &mut String, // working buffer
-    w1: &str,
-    w2: &str,
-    w3: &str,
-    w4: &str,
-    w5: &str,
-    w6: &str,
-    b1: &str,
-    b2: &str,
-    b3: &str,
-    b4: &str,
-    b5: &str,
-    b6: &str,
-    p1: &str,
-    p2: &str,
-    p3: &str,
-) -> i32 {
-    let mut score: i32 = 0;
-
-    // UP is means unigram of previous results.
-    score += model.get(key(buf, &["UP1:", p1])).unwrap_or(&0);
-    score += model.get(key(buf, &["UP2:", p2])).unwrap_or(&0);
-    score += model.get(key(buf, &["UP3:", p3])).unwrap_or(&0);
-    // BP is means bigram of previous results.
-    score += model.get(key(buf, &["BP1:", p1, p2])).unwrap_or(&0);
-    score += model.get(key(buf, &["BP2:", p2, p3])).unwrap_or(&0);
-    // UW is means unigram of words.
-    score += model.get(key(buf, &["UW1:", w1])).unwrap_or(&0);
-    score += model.get(key(buf, &["UW2:", w2])).unwrap_or(&0);
-    score += model.get(key(buf, &["UW3:", w3])).unwrap_or(&0);
-    score += model.get(key(buf, &["UW4:", w4])).unwrap_or(&0);
-    score += model.get(key(buf, &["UW5:", w5])).unwrap_or(&0);
-    score += model.get(key(buf, &["UW6:", w6])).unwrap_or(&0);
-    // BW is means bigram of words.
-    score += model.get(key(buf, &["BW1:", w2, w3])).unwrap_or(&0);
-    score += model.get(key(buf, &["BW2:", w3, w4])).unwrap_or(&0);
-    score += model.get(key(buf, &["BW3:", w4, w5])).unwrap_or(&0);
-    // TW is means trigram of words.
-    score += model.get(key(buf, &["TW1:", w1, w2, w3])).unwrap_or(&0);
-    score += model.get(key(buf, &["TW2:", w2, w3, w4])).unwrap_or(&0);
-    score += model.get(key(buf, &["TW3:", w3, w4, w5])).unwrap_or(&0);
-    score += model.get(key(buf, &["TW4:", w4, w5, w6])).unwrap_or(&0);
-    // UB is means unigram of unicode blocks.
-    score += model.get(key(buf, &["UB1:", b1])).unwrap_or(&0);
-    score += model.get(key(buf, &["UB2:", b2])).unwrap_or(&0);
-    score += model.get(key(buf, &["UB3:", b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["UB4:", b4])).unwrap_or(&0);
-    score += model.get(key(buf, &["UB5:", b5])).unwrap_or(&0);
-    score += model.get(key(buf, &["UB6:", b6])).unwrap_or(&0);
-    // BB is means bigram of unicode blocks.
-    score += model.get(key(buf, &["BB1:", b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["BB2:", b3, b4])).unwrap_or(&0);
-    score += model.get(key(buf, &["BB3:", b4, b5])).unwrap_or(&0);
-    // TB is means trigram of unicode blocks.
-    score += model.get(key(buf, &["TB1:", b1, b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["TB2:", b2, b3, b4])).unwrap_or(&0);
-    score += model.get(key(buf, &["TB3:", b3, b4, b5])).unwrap_or(&0);
-    score += model.get(key(buf, &["TB4:", b4, b5, b6])).unwrap_or(&0);
-    // UQ is combination of UP and UB.
-    score += model.get(key(buf, &["UQ1:", p1, b1])).unwrap_or(&0);
-    score += model.get(key(buf, &["UQ2:", p2, b2])).unwrap_or(&0);
-    score += model.get(key(buf, &["UQ3:", p3, b3])).unwrap_or(&0);
-    // BQ is combination of UP and BB.
-    score += model.get(key(buf, &["BQ1:", p2, b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["BQ2:", p2, b3, b4])).unwrap_or(&0);
-    score += model.get(key(buf, &["BQ3:", p3, b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["BQ4:", p3, b3, b4])).unwrap_or(&0);
-    // TQ is combination of UP and TB.
-    score += model.get(key(buf, &["TQ1:", p2, b1, b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["TQ2:", p2, b2, b3, b4])).unwrap_or(&0);
-    score += model.get(key(buf, &["TQ3:", p3, b1, b2, b3])).unwrap_or(&0);
-    score += model.get(key(buf, &["TQ4:", p3, b2, b3, b4])).unwrap_or(&0);
+/// get_feature returns score of features.
+fn get_feature<M: Scorer + ?Sized>(model: &M, buf: &mut String, window: &Window) -> i32 {
+    let mut score: i32 = model.base_score();
+
+    visit_features(buf, window, model.schema(), &mut |key| {
+        score = score.saturating_add(model.lookup(key).unwrap_or(0))
+    });
 
     score
 }
 
+/// visit_features calls visit with key of each feature used by schema.
+fn visit_features(buf: &mut String, window: &Window, schema: Schema, visit: &mut impl FnMut(&str)) {
+    let mut params = [""; 5];
+
+    for (prefix, slots) in features::templates(schema) {
+        params[0] = prefix;
+        for (i, slot) in slots.iter().enumerate() {
+            params[i + 1] = window.slot(*slot);
+        }
+
+        visit(key(buf, &params[..=slots.len()]));
+    }
+}
+
 /// key returns feature key.
 fn key<'a>(buf: &'a mut String, params: &[&str]) -> &'a str {
     buf.clear();
@@ -270,6 +895,7 @@ fn key<'a>(buf: &'a mut String, params: &[&str]) -> &'a str {
 #[cfg(test)]
 mod tests {
     #[test]
+    #[cfg(feature = "model-ja")]
     fn test_parse() {
         let m = super::models::default_japanese_model();
 
@@ -332,6 +958,271 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_parse_slices() {
+        let m = super::models::default_japanese_model();
+
+        assert_eq!(super::parse_slices(m, ""), vec![""]);
+        assert_eq!(super::parse_slices(m, "水と油"), vec!["水と", "油"]);
+
+        let input = "日本語の文章において語の区切りに空白を挟んで記述すること";
+        let words = super::parse_slices(m, input);
+        assert_eq!(words, super::parse(m, input));
+
+        let range = input.as_bytes().as_ptr_range();
+        for word in words {
+            assert!(range.contains(&word.as_ptr()));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_phrases() {
+        let m = super::models::default_japanese_model();
+
+        let mut phrases = super::phrases(m, "");
+        assert_eq!(phrases.next(), Some(""));
+        assert_eq!(phrases.next(), None);
+        assert_eq!(phrases.next(), None);
+
+        let mut phrases = super::phrases(m, "これはテストです。今日は晴天です。");
+        assert_eq!(phrases.next(), Some("これは"));
+        assert_eq!(phrases.next(), Some("テストです。"));
+        assert_eq!(phrases.next(), Some("今日は"));
+        assert_eq!(phrases.next(), Some("晴天です。"));
+        assert_eq!(phrases.next(), None);
+
+        let input = "日本語の文章において語の区切りに空白を挟んで記述すること";
+        assert_eq!(
+            super::phrases(m, input).take(3).collect::<Vec<_>>(),
+            vec!["日本語の", "文章に", "おいて"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_parse_phrases() {
+        let m = super::models::default_japanese_model();
+
+        let phrases = super::parse_phrases(m, "");
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].text, "");
+        assert_eq!((phrases[0].start, phrases[0].end), (0, 0));
+        assert_eq!(phrases[0].score, None);
+
+        let input = "これはテストです。今日は晴天です。";
+        let phrases = super::parse_phrases(m, input);
+        let texts: Vec<&str> = phrases.iter().map(|v| v.text).collect();
+        assert_eq!(texts, super::parse_slices(m, input));
+
+        let scores = super::scores(m, input);
+        for phrase in &phrases {
+            assert_eq!(phrase.text, &input[phrase.start..phrase.end]);
+            if phrase.end < input.len() {
+                let score = scores.iter().find(|(end, _)| *end == phrase.end).unwrap().1;
+                assert_eq!(phrase.score, Some(score));
+            }
+        }
+        assert_eq!(phrases.last().unwrap().end, input.len());
+        assert_eq!(phrases.last().unwrap().score, None);
+
+        let phrases = super::parse_phrases_with_threshold(m, input, 100000000);
+        assert_eq!(phrases.len(), 1);
+        assert_eq!(phrases[0].text, input);
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_boundaries() {
+        let m = super::models::default_japanese_model();
+        let t = super::DEFAULT_THRESHOLD;
+
+        assert_eq!(super::boundaries(m, "", t), Vec::<usize>::new());
+        assert_eq!(super::boundaries(m, "日本語", t), Vec::<usize>::new());
+        assert_eq!(super::boundaries(m, "水と油", t), vec![6]);
+        assert_eq!(
+            super::boundaries(m, "PythonとJavaScriptとGolang", t),
+            vec![9, 22]
+        );
+        assert_eq!(
+            super::boundaries(m, "これはテストです。", 100000000),
+            Vec::<usize>::new()
+        );
+
+        assert_eq!(super::char_boundaries(m, "", t), Vec::<usize>::new());
+        assert_eq!(super::char_boundaries(m, "水と油", t), vec![2]);
+        assert_eq!(
+            super::char_boundaries(m, "PythonとJavaScriptとGolang", t),
+            vec![7, 18]
+        );
+
+        let input = "日本語の文章において語の区切りに空白を挟んで記述すること";
+        let mut start = 0;
+        let mut phrases = Vec::new();
+        for end in super::boundaries(m, input, t) {
+            phrases.push(&input[start..end]);
+            start = end;
+        }
+        phrases.push(&input[start..]);
+        assert_eq!(phrases, super::parse_slices(m, input));
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_scores() {
+        let m = super::models::default_japanese_model();
+
+        assert_eq!(super::scores(m, ""), Vec::<(usize, i32)>::new());
+        assert_eq!(super::scores(m, "日"), Vec::<(usize, i32)>::new());
+
+        let input = "これはテストです。今日は晴天です。";
+        let scores = super::scores(m, input);
+        assert_eq!(scores.len(), input.chars().count() - 1);
+
+        for threshold in [-1000, 0, super::DEFAULT_THRESHOLD, 3000] {
+            let offsets: Vec<usize> = scores
+                .iter()
+                .filter(|(_, score)| *score > threshold)
+                .map(|(end, _)| *end)
+                .collect();
+            assert_eq!(offsets, super::boundaries(m, input, threshold));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_explain() {
+        let m = super::models::default_japanese_model();
+        let input = "これはテストです。今日は晴天です。";
+
+        assert_eq!(super::explain(m, input, 0), None);
+        assert_eq!(super::explain(m, input, 1), None);
+        assert_eq!(super::explain(m, input, input.len()), None);
+        assert_eq!(super::explain(m, input, input.len() + 3), None);
+
+        for (offset, score) in super::scores(m, input) {
+            let explanation = super::explain(m, input, offset).unwrap();
+            assert_eq!(explanation.offset, offset);
+            assert_eq!(explanation.score, score);
+            assert_eq!(explanation.features.len(), 42);
+            assert_eq!(
+                explanation
+                    .features
+                    .iter()
+                    .filter_map(|v| v.score)
+                    .sum::<i32>(),
+                score
+            );
+        }
+
+        let explanation = super::explain(m, input, 9).unwrap();
+        let feature = |key: &str| {
+            explanation
+                .features
+                .iter()
+                .find(|v| v.key == key)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(explanation.base_score, 0);
+        assert_eq!(feature("UW3:は").score, m.get("UW3:は"));
+        assert_eq!(feature("UP1:U").score, Some(251));
+        assert_eq!(feature("UW1:こ").score, None);
+        assert_eq!(feature("TW2:れはテ").score, None);
+    }
+
+    #[test]
+    fn test_protected_spans() {
+        let mut m = super::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        let t = i32::MIN; // every gap is a boundary candidate
+
+        assert_eq!(
+            super::parse_with_threshold(&m, "詳細はhttps://example.com/path?a=1をご覧", t),
+            vec![
+                "詳",
+                "細",
+                "は",
+                "https://example.com/path?a=1",
+                "を",
+                "ご",
+                "覧"
+            ]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "v1.2.3の変更点", t),
+            vec!["v1.2.3", "の", "変", "更", "点"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "a@b.jpと`x y`", t),
+            vec!["a@b.jp", "と", "`x y`"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "（https://a.jp)と１．５", t),
+            vec!["（", "https://a.jp", ")", "と", "１．５"]
+        );
+
+        let input = "値は3.14";
+        assert_eq!(super::boundaries(&m, input, t), vec![3, 6]);
+        assert_eq!(super::char_boundaries(&m, input, t), vec![1, 2]);
+        assert_eq!(super::scores(&m, input).len(), 2);
+        assert_eq!(super::explain(&m, input, 7), None);
+        assert!(super::explain(&m, input, 6).is_some());
+    }
+
+    #[test]
+    fn test_grapheme_clusters() {
+        let mut m = super::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        let t = i32::MIN; // every gap is a boundary candidate
+
+        assert_eq!(
+            super::parse_with_threshold(&m, "🇯🇵と🇺🇸", t),
+            vec!["🇯🇵", "と", "🇺🇸"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "👍🏽👨\u{200D}👩\u{200D}👧", t),
+            vec!["👍🏽", "👨\u{200D}👩\u{200D}👧"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "か\u{3099}き\u{3099}", t),
+            vec!["か\u{3099}", "き\u{3099}"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "葛\u{E0100}城と辻\u{E0101}", t),
+            vec!["葛\u{E0100}", "城", "と", "辻\u{E0101}"]
+        );
+
+        // Thai vowels and tone marks above or below a consonant are combining characters.
+        assert_eq!(
+            super::parse_with_threshold(&m, "ผู้ใหญ่", t),
+            vec!["ผู้", "ใ", "ห", "ญ่"]
+        );
+        assert_eq!(
+            super::parse_with_threshold(&m, "วันนี้", t),
+            vec!["วั", "น", "นี้"]
+        );
+
+        let input = "葛\u{E0100}城と👍🏽";
+        assert_eq!(super::boundaries(&m, input, t), vec![7, 10, 13]);
+        assert_eq!(super::char_boundaries(&m, input, t), vec![2, 3, 4]);
+        assert_eq!(super::scores(&m, input).len(), 3);
+        assert_eq!(super::explain(&m, input, 3), None);
+        assert_eq!(super::explain(&m, input, 17), None);
+        assert_eq!(super::explain(&m, input, 13).unwrap().score, 2000);
+
+        let mut parser = super::Parser::with_threshold(&m, t);
+        let mut offsets = Vec::new();
+        parser.boundaries_into(input, &mut offsets);
+        assert_eq!(offsets, vec![7, 10, 13]);
+        assert_eq!(
+            super::phrases_with_threshold(&m, input, t).collect::<Vec<_>>(),
+            vec!["葛\u{E0100}", "城", "と", "👍🏽"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-zh-hans")]
     fn test_parse_zh_hans() {
         let m = super::models::default_simplified_chinese_model();
 
@@ -398,6 +1289,22 @@ mod tests {
             ("安", 3, "120",)
         );
 
+        let input = "ไทย";
+        let mut chars = input.char_indices();
+        seek(&mut chars, 0);
+        assert_eq!(
+            super::get_unicode_block_and_feature(input, &mut chars),
+            ("ไ", 3, "032",)
+        );
+
+        let input = "นี้";
+        let mut chars = input.char_indices();
+        seek(&mut chars, 2);
+        assert_eq!(
+            super::get_unicode_block_and_feature(input, &mut chars),
+            ("้", 3, "032",)
+        );
+
         let input = "範囲外アクセス";
         let mut chars = input.char_indices();
         seek(&mut chars, 7);
@@ -0,0 +1,541 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::json::{self, Value};
+use crate::{Error, Map, Scorer, DEFAULT_THRESHOLD};
+
+/// Schema is feature set used by a model.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Schema {
+    /// V1 is schema of BudouX v0.1 models, stored as flat map (e.g. `{"UW1:あ": 123}`).
+    /// It uses all features, including unicode blocks and previous results.
+    #[default]
+    V1,
+    /// V2 is schema of newer BudouX models, stored as nested map (e.g. `{"UW1": {"あ": 123}}`).
+    /// It uses only features of characters (UW, BW and TW) and a base score.
+    V2,
+}
+
+/// Metadata is summary of a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Metadata {
+    /// feature set used by the model.
+    pub schema: Schema,
+    /// number of features.
+    pub features: usize,
+    /// sum of all scores.
+    pub total: i64,
+    /// score added to every gap, see budoux::Scorer::base_score.
+    pub base_score: i32,
+}
+
+impl Metadata {
+    /// new returns metadata of model from its schema, number of features and sum of all scores.
+    pub(crate) fn new(schema: Schema, features: usize, total: i64) -> Self {
+        Metadata {
+            schema,
+            features,
+            total,
+            base_score: base_score(schema, total),
+        }
+    }
+}
+
+/// Model is trained machine learning model.
+/// It holds score of each feature key (e.g. "UW1:あ"), and schema of the features.
+///
+/// # Examples
+///
+/// Make model from flat map of BudouX v0.1 (with `std` feature).
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use std::collections::HashMap;
+///
+/// let mut features = HashMap::new();
+/// features.insert(String::from("UW3:と"), 2000);
+///
+/// let model = budoux::Model::from(features);
+///
+/// assert_eq!(model.schema(), budoux::Schema::V1);
+/// assert_eq!(budoux::parse(&model, "水と油"), vec!["水と", "油"]);
+/// # }
+/// ```
+///
+/// Make model from nested map of newer BudouX (with `std` feature).
+///
+/// ```
+/// # #[cfg(feature = "std")]
+/// # {
+/// use std::collections::HashMap;
+///
+/// let mut group = HashMap::new();
+/// group.insert(String::from("と"), 2000);
+/// group.insert(String::from("油"), -1000);
+///
+/// let mut features = HashMap::new();
+/// features.insert(String::from("UW3"), group);
+///
+/// let model = budoux::Model::from(features);
+///
+/// assert_eq!(model.schema(), budoux::Schema::V2);
+/// assert_eq!(model.get("UW3:と"), Some(2000));
+/// assert_eq!(budoux::parse(&model, "水と油"), vec!["水と", "油"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Model {
+    schema: Schema,
+    features: Map<String, i32>,
+    total: i64, // sum of all scores
+}
+
+impl Model {
+    /// new returns empty model of budoux::Schema::V1.
+    pub fn new() -> Self {
+        Model::default()
+    }
+
+    /// with_schema returns empty model of schema.
+    pub fn with_schema(schema: Schema) -> Self {
+        Model {
+            schema,
+            ..Model::default()
+        }
+    }
+
+    /// from_json_str returns model loaded from json.
+    ///
+    /// Both flat map of BudouX v0.1 (budoux::Schema::V1) and nested map of newer BudouX (budoux::Schema::V2) are supported,
+    /// the schema is decided by the first member.
+    /// It returns error if json is malformed, a score is not integer in range of i32,
+    /// a feature key is never looked up by the schema, or the model has no features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let model = budoux::Model::from_json_str(r#"{"UW3": {"と": 2000}}"#).unwrap();
+    ///
+    /// assert_eq!(model.schema(), budoux::Schema::V2);
+    /// assert_eq!(budoux::parse(&model, "水と油"), vec!["水と", "油"]);
+    ///
+    /// let err = budoux::Model::from_json_str(r#"{"UW3:と": 0.5}"#).unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), r#"score of feature "UW3:と" is not i32"#);
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, Error> {
+        let members = match json::parse(json)? {
+            Value::Object(v) => v,
+            _ => {
+                return Err(Error::Json {
+                    offset: json.len() - json.trim_start().len(),
+                    reason: "expected object",
+                })
+            }
+        };
+
+        Model::from_members(members.into_iter().map(|(k, v)| (k, member(v))).collect())
+    }
+
+    /// from_json_reader returns model loaded from json of reader, see budoux::Model::from_json_str.
+    /// It is available with `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let file = File::open(path_to_json)?;
+    /// let model = budoux::Model::from_json_reader(BufReader::new(file))?;
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_json_reader<R: std::io::Read>(mut reader: R) -> Result<Self, Error> {
+        let mut json = String::new();
+        reader.read_to_string(&mut json)?;
+
+        Model::from_json_str(&json)
+    }
+
+    /// from_members returns model from members of model file, see budoux::Model::from_json_str.
+    pub(crate) fn from_members(members: Vec<(String, Member)>) -> Result<Self, Error> {
+        let nested = matches!(members.first(), Some((_, Member::Group(_))));
+        let mut model = Model::with_schema(if nested { Schema::V2 } else { Schema::V1 });
+
+        for (key, value) in members {
+            if !nested {
+                model.insert_member(key, value)?;
+                continue;
+            }
+
+            let known = crate::features::templates(model.schema)
+                .iter()
+                .any(|(prefix, _)| prefix.strip_suffix(':') == Some(&key));
+            if !known {
+                return Err(Error::UnknownFeature(key));
+            }
+
+            let features = match value {
+                Member::Group(v) => v,
+                Member::Score(_) => return Err(Error::InvalidScore(key)),
+            };
+            for (feature, value) in features {
+                model.insert_member(format!("{}:{}", key, feature), value)?;
+            }
+        }
+
+        if model.is_empty() {
+            return Err(Error::EmptyModel);
+        }
+
+        Ok(model)
+    }
+
+    /// insert_member sets score of feature key from member, it must be known feature and integer.
+    fn insert_member(&mut self, key: String, value: Member) -> Result<(), Error> {
+        let known = crate::features::templates(self.schema)
+            .iter()
+            .any(|(prefix, _)| key.starts_with(prefix));
+        if !known {
+            return Err(Error::UnknownFeature(key));
+        }
+
+        match value {
+            Member::Score(Some(v)) => {
+                self.insert(key, v);
+                Ok(())
+            }
+            _ => Err(Error::InvalidScore(key)),
+        }
+    }
+
+    /// schema returns feature set used by the model.
+    pub fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    /// get returns score of feature key.
+    pub fn get(&self, key: &str) -> Option<i32> {
+        self.features.get(key).copied()
+    }
+
+    /// insert sets score of feature key, and returns previous score.
+    pub fn insert(&mut self, key: String, score: i32) -> Option<i32> {
+        let old = self.features.insert(key, score);
+        self.total += score as i64 - old.unwrap_or(0) as i64;
+
+        old
+    }
+
+    /// len returns number of features.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// is_empty returns true if model has no features.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// iter returns iterator over pairs of feature key and score.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.features.iter().map(|(k, v)| (k.as_str(), *v))
+    }
+
+    /// base_score returns score added to every gap.
+    ///
+    /// It is zero for budoux::Schema::V1.
+    /// For budoux::Schema::V2, newer BudouX splits at a gap if the sum of feature scores exceeds half of total score.
+    /// So base score is offset from it to budoux::DEFAULT_THRESHOLD, and budoux::parse works with both schemas.
+    pub fn base_score(&self) -> i32 {
+        base_score(self.schema, self.total)
+    }
+
+    /// metadata returns summary of the model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let model = budoux::Model::from_json_str(r#"{"UW3": {"と": 2000}}"#).unwrap();
+    /// let metadata = model.metadata();
+    ///
+    /// assert_eq!(metadata.schema, budoux::Schema::V2);
+    /// assert_eq!(metadata.features, 1);
+    /// assert_eq!(metadata.base_score, 0);
+    /// ```
+    pub fn metadata(&self) -> Metadata {
+        Metadata::new(self.schema, self.len(), self.total)
+    }
+}
+
+/// Member is value of member of model file, used by loaders of budoux::Model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Member {
+    /// Score is score of feature, None if it is not integer in range of i32.
+    Score(Option<i32>),
+    /// Group is features of group of nested model.
+    Group(Vec<(String, Member)>),
+}
+
+/// member returns member of model file from json value.
+fn member(value: Value) -> Member {
+    match value {
+        Value::Object(v) => Member::Group(v.into_iter().map(|(k, v)| (k, member(v))).collect()),
+        Value::Number(v) => Member::Score(v.parse().ok()),
+        Value::Other => Member::Score(None),
+    }
+}
+
+/// base_score returns score added to every gap of schema, from sum of all scores.
+/// It saturates at bounds of i32, if scores are too large.
+pub(crate) fn base_score(schema: Schema, total: i64) -> i32 {
+    match schema {
+        Schema::V1 => 0,
+        Schema::V2 => (DEFAULT_THRESHOLD as i64 - total.div_euclid(2))
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+    }
+}
+
+/// Make model of budoux::Schema::V1 from flat map of feature key and score.
+#[cfg(feature = "std")]
+impl From<HashMap<String, i32>> for Model {
+    fn from(features: HashMap<String, i32>) -> Self {
+        let total = features.values().map(|v| *v as i64).sum();
+
+        Model {
+            schema: Schema::V1,
+            features,
+            total,
+        }
+    }
+}
+
+/// Make model of budoux::Schema::V2 from nested map of feature group, feature and score.
+#[cfg(feature = "std")]
+impl From<HashMap<String, HashMap<String, i32>>> for Model {
+    fn from(groups: HashMap<String, HashMap<String, i32>>) -> Self {
+        let mut model = Model::with_schema(Schema::V2);
+
+        for (group, features) in groups {
+            for (feature, score) in features {
+                model.insert(format!("{}:{}", group, feature), score);
+            }
+        }
+
+        model
+    }
+}
+
+/// Make model of budoux::Schema::V1 from pairs of feature key and score.
+impl core::iter::FromIterator<(String, i32)> for Model {
+    fn from_iter<T: IntoIterator<Item = (String, i32)>>(iter: T) -> Self {
+        let mut model = Model::new();
+        for (key, score) in iter {
+            model.insert(key, score);
+        }
+
+        model
+    }
+}
+
+impl Scorer for Model {
+    fn lookup(&self, key: &str) -> Option<i32> {
+        self.get(key)
+    }
+
+    fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    fn base_score(&self) -> i32 {
+        Model::base_score(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::collections::HashMap;
+
+    use super::{Model, Schema};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_flat() {
+        let mut features = HashMap::new();
+        features.insert(String::from("UW1:あ"), 10);
+        features.insert(String::from("UB1:108"), -3);
+
+        let m = Model::from(features);
+        assert_eq!(m.schema(), Schema::V1);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get("UW1:あ"), Some(10));
+        assert_eq!(m.get("UB1:108"), Some(-3));
+        assert_eq!(m.get("UW1:い"), None);
+        assert_eq!(m.base_score(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_nested() {
+        let mut uw1 = HashMap::new();
+        uw1.insert(String::from("あ"), 10);
+        uw1.insert(String::from("い"), 20);
+        let mut tw4 = HashMap::new();
+        tw4.insert(String::from("あいう"), -5);
+
+        let mut groups = HashMap::new();
+        groups.insert(String::from("UW1"), uw1);
+        groups.insert(String::from("TW4"), tw4);
+
+        let m = Model::from(groups);
+        assert_eq!(m.schema(), Schema::V2);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m.get("UW1:あ"), Some(10));
+        assert_eq!(m.get("UW1:い"), Some(20));
+        assert_eq!(m.get("TW4:あいう"), Some(-5));
+        assert_eq!(m.base_score(), crate::DEFAULT_THRESHOLD - 12);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut m = Model::with_schema(Schema::V2);
+        assert!(m.is_empty());
+        assert_eq!(m.base_score(), crate::DEFAULT_THRESHOLD);
+
+        assert_eq!(m.insert(String::from("UW1:あ"), 10), None);
+        assert_eq!(m.insert(String::from("UW1:あ"), 30), Some(10));
+        assert_eq!(m.insert(String::from("UW1:い"), -5), None);
+        assert_eq!(m.base_score(), crate::DEFAULT_THRESHOLD - 12);
+
+        let m: Model = vec![(String::from("UW1:あ"), 10)].into_iter().collect();
+        assert_eq!(m.schema(), Schema::V1);
+        assert_eq!(m.iter().collect::<Vec<_>>(), vec![("UW1:あ", 10)]);
+    }
+
+    #[test]
+    fn test_from_json_str() {
+        let m = Model::from_json_str(r#"{"UW1:あ": 10, "UB1:108": -3, "UW1:あ": 20}"#).unwrap();
+        assert_eq!(m.schema(), Schema::V1);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.get("UW1:あ"), Some(20));
+        assert_eq!(m.get("UB1:108"), Some(-3));
+
+        let m = Model::from_json_str(
+            r#" {"UW1": {"あ": 10, "\u3044": 20}, "TW4": {}, "BW1": {"あい": -2147483648}} "#,
+        )
+        .unwrap();
+        assert_eq!(m.schema(), Schema::V2);
+        assert_eq!(m.len(), 3);
+        assert_eq!(m.get("UW1:い"), Some(20));
+        assert_eq!(m.get("BW1:あい"), Some(i32::MIN));
+    }
+
+    #[test]
+    fn test_from_json_str_error() {
+        use crate::Error;
+
+        let err = |json: &str| Model::from_json_str(json).unwrap_err();
+
+        assert!(matches!(err(""), Error::Json { offset: 0, .. }));
+        assert!(matches!(
+            err(r#"{"UW1:あ": 1"#),
+            Error::Json { offset: 13, .. }
+        ));
+        assert!(matches!(
+            err(" [1]"),
+            Error::Json {
+                offset: 1,
+                reason: "expected object"
+            }
+        ));
+
+        for json in [
+            r#"{"UW1:あ": 1.5}"#,
+            r#"{"UW1:あ": 1e3}"#,
+            r#"{"UW1:あ": "1"}"#,
+            r#"{"UW1:あ": 2147483648}"#,
+            r#"{"UW1": {"あ": null}}"#,
+        ] {
+            assert!(
+                matches!(err(json), Error::InvalidScore(ref v) if v == "UW1:あ"),
+                "{}",
+                json
+            );
+        }
+        assert!(
+            matches!(err(r#"{"UW1": {"あ": 1}, "UW2": 1}"#), Error::InvalidScore(ref v) if v == "UW2")
+        );
+
+        assert!(matches!(err(r#"{"XX1:あ": 1}"#), Error::UnknownFeature(ref v) if v == "XX1:あ"));
+        assert!(
+            matches!(err(r#"{"UB1": {"108": 1}}"#), Error::UnknownFeature(ref v) if v == "UB1")
+        );
+        assert!(matches!(err(r#"{"UW7": {"あ": 1}}"#), Error::UnknownFeature(ref v) if v == "UW7"));
+        assert!(
+            matches!(err(r#"{"UW1:あ": {"い": 1}}"#), Error::UnknownFeature(ref v) if v == "UW1:あ")
+        );
+
+        assert!(matches!(err("{}"), Error::EmptyModel));
+        assert!(matches!(err(r#"{"UW1": {}}"#), Error::EmptyModel));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_json_reader() {
+        let reader = std::io::Cursor::new(r#"{"UW3": {"と": 2000}}"#);
+        let m = Model::from_json_reader(reader).unwrap();
+        assert_eq!(crate::parse(&m, "水と油"), vec!["水と", "油"]);
+
+        let reader = std::io::Cursor::new(b"{\"UW1:\xff\": 1}".to_vec());
+        assert!(matches!(
+            Model::from_json_reader(reader).unwrap_err(),
+            crate::Error::Io(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_v2() {
+        let mut m = Model::with_schema(Schema::V2);
+        m.insert(String::from("UW3:と"), 400);
+        m.insert(String::from("UW4:空"), -300);
+        m.insert(String::from("UW1:x"), 100); // never matches
+
+        // base score is DEFAULT_THRESHOLD - 100 (half of total).
+        assert_eq!(m.base_score(), crate::DEFAULT_THRESHOLD - 100);
+        assert_eq!(crate::parse(&m, "水と油"), vec!["水と", "油"]);
+        assert_eq!(crate::parse(&m, "空と水"), vec!["空と", "水"]);
+        assert_eq!(crate::parse(&m, "水と空"), vec!["水と空"]);
+
+        let scores = crate::scores(&m, "水と油");
+        assert_eq!(scores, vec![(3, m.base_score()), (6, m.base_score() + 400)]);
+    }
+
+    #[test]
+    fn test_base_score_saturation() {
+        let mut m = Model::with_schema(Schema::V2);
+        m.insert(String::from("UW1:あ"), i32::MAX);
+        m.insert(String::from("UW2:あ"), i32::MAX);
+        m.insert(String::from("UW3:あ"), i32::MAX);
+        assert_eq!(m.base_score(), i32::MIN);
+        assert_eq!(m.metadata().base_score, i32::MIN);
+
+        let mut m = Model::with_schema(Schema::V2);
+        m.insert(String::from("UW1:あ"), i32::MIN);
+        m.insert(String::from("UW2:あ"), i32::MIN);
+        m.insert(String::from("UW3:あ"), i32::MIN);
+        assert_eq!(m.base_score(), i32::MAX);
+
+        // Scores of gaps saturate as well, instead of overflow.
+        assert_eq!(crate::scores(&m, "あああ"), vec![(3, -1), (6, i32::MIN)]);
+        assert_eq!(crate::explain(&m, "あああ", 6).unwrap().score, i32::MIN);
+        assert_eq!(
+            crate::scores(&crate::CompiledModel::from(&m), "あああ"),
+            vec![(3, -1), (6, i32::MIN)]
+        );
+        assert_eq!(crate::parse(&m, "あああ"), vec!["あああ"]);
+    }
+}
@@ -1,38 +1,309 @@
+use alloc::string::ToString;
+
+#[cfg(feature = "model-ja")]
 #[path = "ja_knbc.rs"]
 mod ja_knbc;
 
+#[cfg(feature = "model-zh-hans")]
 #[path = "zh_hans.rs"]
 mod zh_hans;
 
 /// default_japanese_model returns trained machine learning model for japanese.
-pub fn default_japanese_model() -> &'static crate::Model {
+///
+/// It is available with `model-ja` feature (enabled by default).
+#[cfg(feature = "model-ja")]
+pub fn default_japanese_model() -> &'static StaticModel {
     &ja_knbc::MODEL
 }
 
 /// default_simplified_chinese_model returns trained machine learning model for simplified chinese.
-pub fn default_simplified_chinese_model() -> &'static crate::Model {
+///
+/// It is available with `model-zh-hans` feature (enabled by default).
+#[cfg(feature = "model-zh-hans")]
+pub fn default_simplified_chinese_model() -> &'static StaticModel {
     &zh_hans::MODEL
 }
 
+/// StaticModel is trained machine learning model stored in static table.
+/// It needs no initialization and no heap allocation, so internal models use it.
+///
+/// Internal models are looked up by perfect hash tables generated by gen/generate.go,
+/// and other models are looked up by binary search of sorted features.
+///
+/// # Examples
+///
+/// ```
+/// static MODEL: budoux::models::StaticModel =
+///     budoux::models::StaticModel::new(&[("UW3:と", 2000), ("UW4:油", 100)]);
+///
+/// assert_eq!(MODEL.get("UW3:と"), Some(2000));
+/// assert_eq!(budoux::parse(&MODEL, "水と油"), vec!["水と", "油"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticModel {
+    schema: crate::Schema,
+    features: &'static [(&'static str, i32)],
+    index: Option<Index>,
+    total: i64, // sum of all scores
+}
+
+/// Index is perfect hash table of features (hash and displace).
+///
+/// Hash of a key chooses its bucket, and hash mixed with seed of the bucket chooses its slot.
+/// Each slot holds index of features, so a lookup needs a single hash and a single comparison of keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Index {
+    seeds: &'static [u32],
+    slots: &'static [u16],
+}
+
+impl Index {
+    /// get returns index of features where key is stored, if key is in features.
+    fn get(&self, key: &str) -> usize {
+        let h = hash(key);
+        let bucket = h as usize % self.seeds.len();
+        let slot = mix(h ^ self.seeds[bucket]) as usize % self.slots.len();
+
+        self.slots[slot] as usize
+    }
+}
+
+/// hash returns FNV-1a hash of key, followed by budoux::models::mix.
+///
+/// It must be same as hash of gen/generate.go.
+fn hash(key: &str) -> u32 {
+    let mut h: u32 = 0x811c_9dc5;
+    for b in key.bytes() {
+        h ^= b as u32;
+        h = h.wrapping_mul(0x0100_0193);
+    }
+
+    mix(h)
+}
+
+/// mix returns h mixed by finalizer of MurmurHash3.
+///
+/// It must be same as mix of gen/generate.go.
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+
+    h
+}
+
+impl StaticModel {
+    /// new returns model of budoux::Schema::V1 from features.
+    ///
+    /// * `features` - pairs of feature key and score, it must be sorted by key.
+    pub const fn new(features: &'static [(&'static str, i32)]) -> Self {
+        StaticModel::with_schema(crate::Schema::V1, features)
+    }
+
+    /// with_schema returns model of schema from features.
+    ///
+    /// * `schema` - feature set used by the model.
+    /// * `features` - pairs of feature key and score, it must be sorted by key.
+    pub const fn with_schema(
+        schema: crate::Schema,
+        features: &'static [(&'static str, i32)],
+    ) -> Self {
+        let mut total: i64 = 0;
+        let mut i = 0;
+        while i < features.len() {
+            total += features[i].1 as i64;
+            i += 1;
+        }
+
+        StaticModel {
+            schema,
+            features,
+            index: None,
+            total,
+        }
+    }
+
+    /// with_index returns model looked up by perfect hash table, it is used by generated models.
+    ///
+    /// * `seeds` - seed of each bucket.
+    /// * `slots` - index of features in each slot, it has same length as features.
+    #[cfg_attr(
+        not(any(feature = "model-ja", feature = "model-zh-hans")),
+        allow(dead_code)
+    )]
+    pub(crate) const fn with_index(mut self, seeds: &'static [u32], slots: &'static [u16]) -> Self {
+        self.index = Some(Index { seeds, slots });
+        self
+    }
+
+    /// schema returns feature set used by the model.
+    pub fn schema(&self) -> crate::Schema {
+        self.schema
+    }
+
+    /// get returns score of feature key.
+    pub fn get(&self, key: &str) -> Option<i32> {
+        let i = match self.index {
+            Some(index) => Some(index.get(key)).filter(|&i| self.features[i].0 == key),
+            None => self.features.binary_search_by(|(k, _)| (*k).cmp(key)).ok(),
+        };
+
+        i.map(|i| self.features[i].1)
+    }
+
+    /// len returns number of features.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// is_empty returns true if model has no features.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// iter returns iterator over pairs of feature key and score.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, i32)> {
+        self.features.iter().copied()
+    }
+
+    /// metadata returns summary of the model.
+    pub fn metadata(&self) -> crate::Metadata {
+        crate::Metadata::new(self.schema, self.len(), self.total)
+    }
+
+    /// to_model returns copy of model as budoux::Model.
+    pub fn to_model(&self) -> crate::Model {
+        let mut model = crate::Model::with_schema(self.schema);
+        for (k, v) in self.iter() {
+            model.insert(k.to_string(), v);
+        }
+
+        model
+    }
+}
+
+impl crate::Scorer for StaticModel {
+    fn lookup(&self, key: &str) -> Option<i32> {
+        self.get(key)
+    }
+
+    fn schema(&self) -> crate::Schema {
+        self.schema
+    }
+
+    fn base_score(&self) -> i32 {
+        crate::model::base_score(self.schema, self.total)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
+    #[cfg(feature = "model-ja")]
     fn test_multiple_ref() {
         let m1 = super::default_japanese_model();
         let m2 = super::default_japanese_model();
 
         assert_eq!(m1, m2);
-        assert!(m1.len() > 0);
-        assert!(m2.len() > 0);
+        assert!(!m1.is_empty());
+        assert!(!m2.is_empty());
     }
 
     #[test]
+    #[cfg(feature = "model-zh-hans")]
     fn test_multiple_ref_zh_hans() {
         let m1 = super::default_simplified_chinese_model();
         let m2 = super::default_simplified_chinese_model();
 
         assert_eq!(m1, m2);
-        assert!(m1.len() > 0);
-        assert!(m2.len() > 0);
+        assert!(!m1.is_empty());
+        assert!(!m2.is_empty());
+    }
+
+    #[test]
+    #[cfg(all(feature = "model-ja", feature = "model-zh-hans"))]
+    fn test_sorted() {
+        let models = [
+            super::default_japanese_model(),
+            super::default_simplified_chinese_model(),
+        ];
+
+        for m in models {
+            let keys: Vec<&str> = m.iter().map(|(k, _)| k).collect();
+            assert!(keys.windows(2).all(|v| v[0] < v[1]));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_get() {
+        let m = super::default_japanese_model();
+
+        assert_eq!(m.get("BB1:108107"), Some(307));
+        assert_eq!(m.get("UW6:０"), Some(392));
+        assert_eq!(m.get("UW6:思"), Some(-109));
+        assert_eq!(m.get("UW6:"), None);
+        assert_eq!(m.get(""), None);
+
+        let model = m.to_model();
+        assert_eq!(model.schema(), crate::Schema::V1);
+        assert_eq!(model.len(), m.len());
+        for (k, v) in m.iter() {
+            assert_eq!(model.get(k), Some(v));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "model-ja", feature = "model-zh-hans"))]
+    fn test_index() {
+        let models = [
+            super::default_japanese_model(),
+            super::default_simplified_chinese_model(),
+        ];
+
+        for m in models {
+            let index = m.index.unwrap();
+            assert_eq!(index.slots.len(), m.len());
+
+            // Every slot refers to a distinct feature, and every feature is found.
+            let mut slots: Vec<u16> = index.slots.to_vec();
+            slots.sort_unstable();
+            slots.dedup();
+            assert_eq!(slots.len(), m.len());
+
+            for (k, v) in m.iter() {
+                assert_eq!(m.get(k), Some(v));
+            }
+            for k in ["", "UW1:", "UW3:あい", "BB1:999999", "TW4:水と油"] {
+                assert_eq!(m.get(k), None, "{:?}", k);
+            }
+        }
+
+        assert_eq!(super::hash(""), 0xab3e_7c0b);
+        assert_eq!(super::mix(0), 0);
+        assert_ne!(super::hash("UW3:と"), super::hash("UW3:に"));
+    }
+
+    #[test]
+    fn test_with_schema() {
+        static MODEL: super::StaticModel = super::StaticModel::with_schema(
+            crate::Schema::V2,
+            &[("BW2:水と", -300), ("UW1:x", 100), ("UW3:と", 400)],
+        );
+
+        let model = MODEL.to_model();
+        assert_eq!(model.schema(), crate::Schema::V2);
+        assert_eq!(crate::Scorer::base_score(&MODEL), model.base_score());
+
+        for input in ["", "水と油", "水と空"] {
+            assert_eq!(crate::scores(&MODEL, input), crate::scores(&model, input));
+            assert_eq!(crate::parse(&MODEL, input), crate::parse(&model, input));
+        }
+
+        assert_eq!(MODEL.metadata(), model.metadata());
+        assert_eq!(MODEL.metadata().features, 3);
+        assert_eq!(MODEL.metadata().total, 200);
     }
 }
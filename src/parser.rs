@@ -0,0 +1,291 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::protect::{Cache, Matcher, DEFAULT_MATCHERS};
+use crate::{Model, Scanner, Scorer, DEFAULT_THRESHOLD};
+
+/// Parser is reusable parser, which owns working buffers for building feature keys and finding protected spans.
+///
+/// Outputs are written into buffers given by caller, so parsing with the same parser and buffers
+/// does not allocate once the buffers are large enough.
+/// Use budoux::CompiledModel as model, if hash of feature keys is too slow for you.
+///
+/// Protected spans found by budoux::protect::DEFAULT_MATCHERS are never split,
+/// and additional matchers can be registered by budoux::Parser::with_matcher.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let mut parser = budoux::Parser::new(model);
+/// let mut offsets = Vec::new();
+///
+/// for input in ["これはテストです。", "今日は晴天です。"] {
+///     parser.boundaries_into(input, &mut offsets);
+///     assert_eq!(offsets, vec![9]);
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Parser<'m, M: ?Sized = Model> {
+    model: &'m M,
+    threshold: i32,
+    matchers: Vec<&'m dyn Matcher>,
+    wb: String,   // working buffer
+    cache: Cache, // next matches of matchers
+}
+
+impl<'m, M: Scorer + ?Sized> Parser<'m, M> {
+    /// new returns parser of model with budoux::DEFAULT_THRESHOLD.
+    pub fn new(model: &'m M) -> Self {
+        Parser::with_threshold(model, DEFAULT_THRESHOLD)
+    }
+
+    /// with_threshold returns parser of model with threshold for splitting a sentences.
+    pub fn with_threshold(model: &'m M, threshold: i32) -> Self {
+        Parser {
+            model,
+            threshold,
+            matchers: DEFAULT_MATCHERS.to_vec(),
+            wb: String::with_capacity(20),
+            cache: Vec::with_capacity(DEFAULT_MATCHERS.len()),
+        }
+    }
+
+    /// with_matcher returns parser which also protects spans found by matcher.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "model-ja")]
+    /// # {
+    /// let model = budoux::models::default_japanese_model();
+    /// let hashtag = |input: &str, start: usize| {
+    ///     let begin = start + input[start..].find('#')?;
+    ///     let end = input[begin..].find(' ').map_or(input.len(), |i| begin + i);
+    ///     Some((begin, end))
+    /// };
+    /// let mut phrases = Vec::new();
+    ///
+    /// budoux::Parser::new(model).phrases_into("#今日は晴天 です", &mut phrases);
+    /// assert_eq!(phrases, vec!["#今日は", "晴天 です"]);
+    ///
+    /// let mut parser = budoux::Parser::new(model).with_matcher(&hashtag);
+    /// parser.phrases_into("#今日は晴天 です", &mut phrases);
+    /// assert_eq!(phrases, vec!["#今日は晴天 です"]);
+    /// # }
+    /// ```
+    pub fn with_matcher(mut self, matcher: &'m dyn Matcher) -> Self {
+        self.matchers.push(matcher);
+        self
+    }
+
+    /// without_matchers returns parser which protects no spans, even budoux::protect::DEFAULT_MATCHERS.
+    /// Extended grapheme clusters are never split regardless of matchers.
+    pub fn without_matchers(mut self) -> Self {
+        self.matchers.clear();
+        self
+    }
+
+    /// threshold returns threshold for splitting a sentences.
+    pub fn threshold(&self) -> i32 {
+        self.threshold
+    }
+
+    /// boundaries_into writes byte offsets of phrase boundaries in input into out, see budoux::boundaries.
+    ///
+    /// out is cleared first, so it can be reused across calls.
+    pub fn boundaries_into(&mut self, input: &str, out: &mut Vec<usize>) {
+        out.clear();
+        self.visit(input, |end| out.push(end));
+    }
+
+    /// boundaries_into_slice writes byte offsets of phrase boundaries in input into out, see budoux::boundaries.
+    ///
+    /// It returns number of written offsets.
+    /// If out is too small, out is filled with the first boundaries and budoux::Overflow is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "model-ja")]
+    /// # {
+    /// let model = budoux::models::default_japanese_model();
+    /// let mut parser = budoux::Parser::new(model);
+    /// let mut offsets = [0; 4];
+    ///
+    /// let n = parser.boundaries_into_slice("これはテストです。", &mut offsets).unwrap();
+    /// assert_eq!(&offsets[..n], &[9]);
+    ///
+    /// let err = parser
+    ///     .boundaries_into_slice("これはテストです。今日は晴天です。", &mut offsets[..2])
+    ///     .unwrap_err();
+    /// assert_eq!(err.required, 3);
+    /// assert_eq!(&offsets[..2], &[9, 27]);
+    /// # }
+    /// ```
+    pub fn boundaries_into_slice(
+        &mut self,
+        input: &str,
+        out: &mut [usize],
+    ) -> Result<usize, Overflow> {
+        let mut n = 0;
+        self.visit(input, |end| {
+            if let Some(v) = out.get_mut(n) {
+                *v = end;
+            }
+            n += 1;
+        });
+
+        if n > out.len() {
+            return Err(Overflow { required: n });
+        }
+
+        Ok(n)
+    }
+
+    /// phrases_into writes phrases borrowed from input into out, see budoux::parse_slices.
+    ///
+    /// out is cleared first, so it can be reused across calls.
+    pub fn phrases_into<'a>(&mut self, input: &'a str, out: &mut Vec<&'a str>) {
+        out.clear();
+
+        let mut start = 0;
+        self.visit(input, |end| {
+            out.push(&input[start..end]);
+            start = end;
+        });
+        out.push(&input[start..]);
+    }
+
+    /// visit calls f with byte offset of each phrase boundary in input.
+    fn visit(&mut self, input: &str, mut f: impl FnMut(usize)) {
+        let wb = core::mem::take(&mut self.wb);
+        let cache = core::mem::take(&mut self.cache);
+        let mut scanner = Scanner::with_buffer(self.model, input, wb, &self.matchers, cache);
+
+        for (end, score) in &mut scanner {
+            if score > self.threshold && end < input.len() {
+                f(end);
+            }
+        }
+
+        self.wb = scanner.wb;
+        self.cache = scanner.spans.into_cache();
+    }
+}
+
+impl<'m, M: fmt::Debug + ?Sized> fmt::Debug for Parser<'m, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parser")
+            .field("model", &self.model)
+            .field("threshold", &self.threshold)
+            .field("matchers", &self.matchers.len())
+            .finish()
+    }
+}
+
+/// Overflow is error of budoux::Parser::boundaries_into_slice, buffer is too small for all boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Overflow {
+    /// number of boundaries in input, it is required length of buffer.
+    pub required: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer is too small for {} boundaries", self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Overflow {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Overflow, Parser};
+
+    #[test]
+    fn test_parser() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        m.insert(String::from("UW3:は"), 500);
+
+        let mut parser = Parser::new(&m);
+        assert_eq!(parser.threshold(), crate::DEFAULT_THRESHOLD);
+
+        let mut offsets = vec![1, 2, 3];
+        let mut phrases = Vec::new();
+        for input in ["", "水", "水と油", "空は青い", "水と空と海"] {
+            parser.boundaries_into(input, &mut offsets);
+            assert_eq!(
+                offsets,
+                crate::boundaries(&m, input, crate::DEFAULT_THRESHOLD)
+            );
+
+            parser.phrases_into(input, &mut phrases);
+            assert_eq!(phrases, crate::parse_slices(&m, input));
+        }
+
+        let mut parser = Parser::with_threshold(&m, 0);
+        parser.boundaries_into("空は青い", &mut offsets);
+        assert_eq!(offsets, vec![6]);
+    }
+
+    #[test]
+    fn test_matchers() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        let mut phrases = Vec::new();
+
+        let mut parser = Parser::with_threshold(&m, i32::MIN);
+        parser.phrases_into("水と1.5", &mut phrases);
+        assert_eq!(phrases, vec!["水", "と", "1.5"]);
+
+        let mut parser = Parser::with_threshold(&m, i32::MIN).without_matchers();
+        parser.phrases_into("水と1.5", &mut phrases);
+        assert_eq!(phrases, vec!["水", "と", "1", ".", "5"]);
+
+        let oil = |input: &str, start: usize| {
+            let begin = start + input[start..].find("と油")?;
+            Some((begin, begin + "と油".len()))
+        };
+        let mut parser = Parser::new(&m).with_matcher(&oil);
+        parser.phrases_into("空と水と油", &mut phrases);
+        assert_eq!(phrases, vec!["空と", "水と油"]);
+        assert!(format!("{:?}", parser).contains("matchers: 5"));
+    }
+
+    #[test]
+    fn test_boundaries_into_slice() {
+        let mut m = crate::Model::new();
+        m.insert(String::from("UW3:と"), 2000);
+        let mut parser = Parser::new(&m);
+
+        let mut offsets = [0; 2];
+        assert_eq!(parser.boundaries_into_slice("", &mut offsets), Ok(0));
+        assert_eq!(parser.boundaries_into_slice("水と油", &mut offsets), Ok(1));
+        assert_eq!(offsets, [6, 0]);
+        assert_eq!(
+            parser.boundaries_into_slice("水と空と海", &mut offsets),
+            Ok(2)
+        );
+        assert_eq!(offsets, [6, 12]);
+
+        let err = parser.boundaries_into_slice("水と空と海と油", &mut offsets);
+        assert_eq!(err, Err(Overflow { required: 3 }));
+        assert_eq!(offsets, [6, 12]);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "buffer is too small for 3 boundaries"
+        );
+
+        assert_eq!(
+            parser.boundaries_into_slice("水と油", &mut []),
+            Err(Overflow { required: 1 })
+        );
+    }
+}
@@ -0,0 +1,499 @@
+use alloc::vec::Vec;
+
+/// Matcher finds protected spans of input, which are never split into phrases.
+///
+/// It is implemented by closures of `Fn(&str, usize) -> Option<(usize, usize)>`,
+/// and by `regex::Regex` with `regex` feature.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::Matcher;
+///
+/// // Hashtags like "#BudouX" are protected.
+/// let hashtag = |input: &str, start: usize| {
+///     let begin = start + input[start..].find('#')?;
+///     let len = input[begin + 1..]
+///         .find(|c: char| !c.is_ascii_alphanumeric())
+///         .unwrap_or(input.len() - begin - 1);
+///     Some((begin, begin + 1 + len))
+/// };
+///
+/// assert_eq!(hashtag.find_at("今日の#BudouXです", 0), Some((9, 16)));
+/// ```
+pub trait Matcher {
+    /// find_at returns byte range of the first match in input, which starts at or after start.
+    ///
+    /// start is always at a char boundary, and the whole input is given for looking behind.
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)>;
+}
+
+impl<F: Fn(&str, usize) -> Option<(usize, usize)>> Matcher for F {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        self(input, start)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Matcher for regex::Regex {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        regex::Regex::find_at(self, input, start).map(|m| (m.start(), m.end()))
+    }
+}
+
+/// DEFAULT_MATCHERS is matchers used by parse functions, URLs, e-mail addresses, numbers and inline code.
+pub const DEFAULT_MATCHERS: [&dyn Matcher; 4] = [&Url, &Email, &Number, &Code];
+
+/// Url matches URLs starting with "http://" or "https://".
+///
+/// Trailing punctuation marks (e.g. "." at the end of a sentence) and unbalanced closing parentheses
+/// (e.g. ")" of "(see https://example.com)") are not included.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::{Matcher, Url};
+///
+/// assert_eq!(Url.find_at("詳細はhttps://example.com/a?b=1を", 0), Some((9, 34)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Url;
+
+impl Matcher for Url {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        let mut offset = start;
+
+        while let Some(pos) = input[offset..].find("://") {
+            let colon = offset + pos;
+            offset = colon + 3;
+
+            let scheme = if input[..colon].ends_with("https") {
+                colon - 5
+            } else if input[..colon].ends_with("http") {
+                colon - 4
+            } else {
+                continue;
+            };
+
+            let end = offset
+                + input[offset..]
+                    .find(|c| !is_url_char(c))
+                    .unwrap_or(input.len() - offset);
+            let end = offset + trim_url(&input[offset..end]).len();
+            if scheme >= start && end > offset {
+                return Some((scheme, end));
+            }
+        }
+
+        None
+    }
+}
+
+/// trim_url returns url without trailing punctuation marks and unbalanced closing parentheses.
+fn trim_url(url: &str) -> &str {
+    let mut url = url;
+    loop {
+        let trimmed = url.trim_end_matches(|c| ".,:;!?'".contains(c));
+        url = match trimmed.strip_suffix(')') {
+            Some(rest) if trimmed.matches(')').count() > trimmed.matches('(').count() => rest,
+            _ => return trimmed,
+        };
+    }
+}
+
+/// is_url_char returns true if c can be a part of URL (RFC 3986).
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "-._~:/?#[]@!$&'()*+,;=%".contains(c)
+}
+
+/// Email matches e-mail addresses (e.g. "user@example.com").
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::{Email, Matcher};
+///
+/// assert_eq!(Email.find_at("宛先はuser@example.comです", 0), Some((9, 25)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Email;
+
+impl Matcher for Email {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        let mut offset = start;
+
+        while let Some(pos) = input[offset..].find('@') {
+            let at = offset + pos;
+            offset = at + 1;
+
+            // Local part is ASCII, so number of chars is its size.
+            let local = at
+                - input[start..at]
+                    .chars()
+                    .rev()
+                    .take_while(|&c| is_local_char(c))
+                    .count();
+            let end = offset
+                + input[offset..]
+                    .find(|c: char| !c.is_ascii_alphanumeric() && c != '-' && c != '.')
+                    .unwrap_or(input.len() - offset);
+            let domain = input[offset..end].trim_end_matches(['.', '-']);
+
+            if local < at
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.contains("..")
+            {
+                return Some((local, offset + domain.len()));
+            }
+        }
+
+        None
+    }
+}
+
+/// is_local_char returns true if c can be a part of local part of e-mail address.
+fn is_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "._%+-".contains(c)
+}
+
+/// Number matches digits joined by separators (e.g. "3.14", "1,000", "2024/01/01" and "v1.2.3").
+///
+/// A separator is a part of number only if digits follow it, so "1." at the end of a sentence matches "1".
+/// Fullwidth digits and separators are matched as well (e.g. "１．５" and "１，０００").
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::{Matcher, Number};
+///
+/// assert_eq!(Number.find_at("v1.2.3の変更点", 0), Some((0, 6)));
+/// assert_eq!(Number.find_at("価格は1,000円", 0), Some((9, 14)));
+/// assert_eq!(Number.find_at("約１．５倍", 0), Some((3, 12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Number;
+
+impl Matcher for Number {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        let begin = start + input[start..].find(is_digit)?;
+
+        let mut end = begin;
+        let mut rest = &input[begin..];
+        loop {
+            let digits = rest.find(|c| !is_digit(c)).unwrap_or(rest.len());
+            end += digits;
+            rest = &rest[digits..];
+
+            let mut chars = rest.chars();
+            match (chars.next(), chars.next()) {
+                (Some(sep), Some(next)) if ".,:/-．，：／".contains(sep) && is_digit(next) => {
+                    end += sep.len_utf8();
+                    rest = &rest[sep.len_utf8()..];
+                }
+                _ => break,
+            }
+        }
+
+        // Include version prefix (e.g. "v1.2"), if it is not a part of word.
+        let mut before = input[..begin].chars().rev();
+        let begin = match (before.next(), before.next()) {
+            (Some('v' | 'V'), prev)
+                if begin > start && !prev.is_some_and(|c| c.is_ascii_alphanumeric()) =>
+            {
+                begin - 1
+            }
+            _ => begin,
+        };
+
+        Some((begin, end))
+    }
+}
+
+/// is_digit returns true if c is an ASCII or fullwidth digit.
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit() || ('０'..='９').contains(&c)
+}
+
+/// Code matches inline code enclosed by backticks (e.g. "`cargo build`").
+///
+/// Code is enclosed by the same number of backticks, and it does not contain line breaks.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::{Code, Matcher};
+///
+/// assert_eq!(Code.find_at("`cargo test`を実行", 0), Some((0, 12)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code;
+
+impl Matcher for Code {
+    fn find_at(&self, input: &str, start: usize) -> Option<(usize, usize)> {
+        let mut offset = start;
+
+        while let Some(pos) = input[offset..].find('`') {
+            let begin = offset + pos;
+            let fence = input[begin..].len() - input[begin..].trim_start_matches('`').len();
+            let body = begin + fence;
+            offset = body;
+
+            let line = input[body..].find('\n').map_or(input.len(), |i| body + i);
+            if let Some(i) = input[body..line].find(&input[begin..body]) {
+                return Some((begin, body + i + fence));
+            }
+        }
+
+        None
+    }
+}
+
+/// spans returns sorted byte ranges of protected spans in input found by matchers.
+///
+/// Overlapped matches are resolved by the earliest start, and then by the longest end.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::protect::{spans, DEFAULT_MATCHERS};
+///
+/// let input = "v1.2.3はhttps://example.comで公開";
+///
+/// assert_eq!(spans(input, &DEFAULT_MATCHERS), vec![(0, 6), (9, 28)]);
+/// ```
+pub fn spans(input: &str, matchers: &[&dyn Matcher]) -> Vec<(usize, usize)> {
+    let mut spans = Spans::new(matchers, Vec::new());
+    let mut result = Vec::new();
+    while let Some(span) = spans.next_span(input) {
+        result.push(span);
+    }
+
+    result
+}
+
+/// Cache is next match of each matcher, None if the matcher is not searched yet.
+pub(crate) type Cache = Vec<Option<Option<(usize, usize)>>>;
+
+/// Spans finds protected spans of input one by one, it is used by scanner of parse functions.
+///
+/// Next match of each matcher is cached, and a matcher is searched again only after
+/// the search passes start of its cached match, so input is scanned in linear time.
+#[derive(Clone)]
+pub(crate) struct Spans<'p> {
+    matchers: &'p [&'p dyn Matcher],
+    cache: Cache,
+    span: Option<(usize, usize)>,
+    offset: usize, // start of next search
+}
+
+impl<'p> Spans<'p> {
+    /// new returns spans found by matchers, cache is reused as buffer (e.g. budoux::Parser).
+    pub(crate) fn new(matchers: &'p [&'p dyn Matcher], mut cache: Cache) -> Self {
+        cache.clear();
+        cache.resize(matchers.len(), None);
+
+        Spans {
+            matchers,
+            cache,
+            span: None,
+            offset: 0,
+        }
+    }
+
+    /// into_cache returns cache to reuse it as buffer.
+    pub(crate) fn into_cache(self) -> Cache {
+        self.cache
+    }
+
+    /// covers returns true if offset is inside of a protected span (not at its start or end).
+    /// Offsets must be given in ascending order.
+    pub(crate) fn covers(&mut self, input: &str, offset: usize) -> bool {
+        loop {
+            if let Some((start, end)) = self.span {
+                if offset < end {
+                    return start < offset;
+                }
+            }
+
+            if self.next_span(input).is_none() {
+                return false;
+            }
+        }
+    }
+
+    /// next_span returns next protected span after the previous one.
+    fn next_span(&mut self, input: &str) -> Option<(usize, usize)> {
+        let mut found: Option<(usize, usize)> = None;
+
+        for (matcher, cached) in self.matchers.iter().zip(self.cache.iter_mut()) {
+            if let Some(Some((begin, _))) = *cached {
+                if begin < self.offset {
+                    *cached = None;
+                }
+            }
+
+            let next = *cached.get_or_insert_with(|| find_non_empty(*matcher, input, self.offset));
+            if let Some((begin, end)) = next {
+                if found.map_or(true, |(b, e)| begin < b || (begin == b && end > e)) {
+                    found = Some((begin, end));
+                }
+            }
+        }
+
+        self.span = found;
+        self.offset = found.map_or(input.len(), |(_, end)| end);
+
+        found
+    }
+}
+
+/// find_non_empty returns the first non-empty match of matcher at or after start.
+/// Empty matches are skipped, because they can not protect anything.
+fn find_non_empty(matcher: &dyn Matcher, input: &str, mut start: usize) -> Option<(usize, usize)> {
+    while let Some((begin, end)) = matcher.find_at(input, start) {
+        if begin < end {
+            return Some((begin, end));
+        }
+
+        match input[end..].chars().next() {
+            Some(c) => start = end + c.len_utf8(),
+            None => break,
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Code, Email, Matcher, Number, Url, DEFAULT_MATCHERS};
+
+    /// matches returns all texts matched by matcher.
+    fn matches<'a>(matcher: &dyn Matcher, input: &'a str) -> Vec<&'a str> {
+        super::spans(input, &[matcher])
+            .into_iter()
+            .map(|(start, end)| &input[start..end])
+            .collect()
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            matches(&Url, "詳細はhttps://example.com/path?a=1をご覧ください"),
+            vec!["https://example.com/path?a=1"]
+        );
+        assert_eq!(
+            matches(&Url, "http://a.jp/。https://b.jp/x.html."),
+            vec!["http://a.jp/", "https://b.jp/x.html"]
+        );
+        assert_eq!(matches(&Url, "ftp://a.jp http:// ://"), Vec::<&str>::new());
+        assert_eq!(Url.find_at("https://a.jp", 1), None);
+        assert_eq!(
+            matches(&Url, "(see https://example.com) (https://a.jp/x_(y))."),
+            vec!["https://example.com", "https://a.jp/x_(y)"]
+        );
+        assert_eq!(
+            matches(
+                &Url,
+                "https://en.wikipedia.org/wiki/Rust_(programming_language)"
+            ),
+            vec!["https://en.wikipedia.org/wiki/Rust_(programming_language)"]
+        );
+    }
+
+    #[test]
+    fn test_email() {
+        assert_eq!(
+            matches(&Email, "連絡先はfoo.bar+baz@example.co.jpまで"),
+            vec!["foo.bar+baz@example.co.jp"]
+        );
+        assert_eq!(matches(&Email, "a@b.c、x@y.z."), vec!["a@b.c", "x@y.z"]);
+        assert_eq!(
+            matches(&Email, "@example.com user@ a@b a@.com"),
+            Vec::<&str>::new()
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(
+            matches(&Number, "v1.2.3の変更点は2024/01/01に1,000件。"),
+            vec!["v1.2.3", "2024/01/01", "1,000"]
+        );
+        assert_eq!(matches(&Number, "第3章、3.と-1"), vec!["3", "3", "1"]);
+        assert_eq!(matches(&Number, "rev2 V2.0"), vec!["2", "V2.0"]);
+        assert_eq!(matches(&Number, "数字なし"), Vec::<&str>::new());
+        assert_eq!(
+            matches(&Number, "１．５倍の１，０００円、第３章。"),
+            vec!["１．５", "１，０００", "３"]
+        );
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(
+            matches(&Code, "`cargo test`と``a ` b``を実行"),
+            vec!["`cargo test`", "``a ` b``"]
+        );
+        assert_eq!(matches(&Code, "`閉じない\n`"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_spans() {
+        let input = "v1.2はhttps://example.com/v2.0とa@b.jpと`x 1.0`";
+        assert_eq!(
+            super::spans(input, &DEFAULT_MATCHERS)
+                .into_iter()
+                .map(|(start, end)| &input[start..end])
+                .collect::<Vec<_>>(),
+            vec!["v1.2", "https://example.com/v2.0", "a@b.jp", "`x 1.0`"]
+        );
+
+        let empty = |_: &str, start: usize| Some((start, start));
+        assert_eq!(super::spans("abc", &[&empty]), Vec::<(usize, usize)>::new());
+
+        let mut spans = super::Spans::new(&DEFAULT_MATCHERS, Vec::new());
+        let covered: Vec<usize> = (0..=8).filter(|&i| spans.covers("ab1.23cd", i)).collect();
+        assert_eq!(covered, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_spans_long() {
+        use core::cell::Cell;
+
+        let input = "第12章と3.45節、".repeat(20_000);
+
+        // Each matcher is searched once per its match, not once per span found by any matcher.
+        let (numbers, urls) = (Cell::new(0), Cell::new(0));
+        let number = |input: &str, start: usize| {
+            numbers.set(numbers.get() + 1);
+            Number.find_at(input, start)
+        };
+        let url = |input: &str, start: usize| {
+            urls.set(urls.get() + 1);
+            Url.find_at(input, start)
+        };
+
+        let spans = super::spans(&input, &[&number, &url]);
+        assert_eq!(spans.len(), 40_000);
+        assert_eq!(numbers.get(), 40_001);
+        assert_eq!(urls.get(), 1);
+
+        let mut spans = super::Spans::new(&DEFAULT_MATCHERS, Vec::new());
+        let covered = (0..=input.len())
+            .filter(|&i| input.is_char_boundary(i) && spans.covers(&input, i))
+            .count();
+        assert_eq!(covered, 80_000);
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_regex() {
+        let re = regex::Regex::new(r"#\w+").unwrap();
+        assert_eq!(
+            matches(&re, "今日は#晴天 #budoux"),
+            vec!["#晴天", "#budoux"]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::model::Member;
+use crate::models::StaticModel;
+use crate::{Model, Schema};
+
+/// Serialize model as json of BudouX, see budoux::Model::from_json_str.
+impl Serialize for Model {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_features(self.schema(), self.iter(), serializer)
+    }
+}
+
+/// Serialize model as json of BudouX, see budoux::Model::from_json_str.
+impl Serialize for StaticModel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_features(self.schema(), self.iter(), serializer)
+    }
+}
+
+/// serialize_features serializes features sorted by key,
+/// as flat map for budoux::Schema::V1 and nested map for budoux::Schema::V2.
+fn serialize_features<'k, S: Serializer>(
+    schema: Schema,
+    features: impl Iterator<Item = (&'k str, i32)>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let features: BTreeMap<&str, i32> = features.collect();
+
+    match schema {
+        Schema::V1 => features.serialize(serializer),
+        Schema::V2 => {
+            let mut groups: BTreeMap<&str, BTreeMap<&str, i32>> = BTreeMap::new();
+            for (key, score) in features {
+                let (group, feature) = key.split_at(key.find(':').unwrap_or(key.len()));
+                groups
+                    .entry(group)
+                    .or_default()
+                    .insert(feature.get(1..).unwrap_or(""), score);
+            }
+
+            groups.serialize(serializer)
+        }
+    }
+}
+
+/// Deserialize model from json of BudouX, it is checked as well as budoux::Model::from_json_str.
+impl<'de> Deserialize<'de> for Model {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let members = deserializer.deserialize_map(MembersVisitor)?;
+
+        Model::from_members(members).map_err(de::Error::custom)
+    }
+}
+
+/// MembersVisitor is visitor of members of model.
+struct MembersVisitor;
+
+impl<'de> Visitor<'de> for MembersVisitor {
+    type Value = Vec<(String, Member)>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("map of feature and score")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut members = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, MemberValue>()? {
+            members.push((key, value.0));
+        }
+
+        Ok(members)
+    }
+}
+
+/// MemberValue is deserializable wrapper of Member.
+struct MemberValue(Member);
+
+impl<'de> Deserialize<'de> for MemberValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(MemberVisitor).map(MemberValue)
+    }
+}
+
+/// MemberVisitor is visitor of score or group of features.
+struct MemberVisitor;
+
+impl<'de> Visitor<'de> for MemberVisitor {
+    type Value = Member;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("score or map of feature and score")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(Member::Score(i32::try_from(v).ok()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Member::Score(i32::try_from(v).ok()))
+    }
+
+    fn visit_f64<E: de::Error>(self, _v: f64) -> Result<Self::Value, E> {
+        Ok(Member::Score(None))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        MembersVisitor.visit_map(map).map(Member::Group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::StaticModel;
+    use crate::{Model, Schema};
+
+    #[test]
+    fn test_model() {
+        let json = r#"{"UB1:108":-3,"UW1:あ":10}"#;
+        let m: Model = serde_json::from_str(json).unwrap();
+        assert_eq!(m, Model::from_json_str(json).unwrap());
+        assert_eq!(serde_json::to_string(&m).unwrap(), json);
+
+        let json = r#"{"TW4":{"あいう":-5},"UW1":{"あ":10,"い":20}}"#;
+        let m: Model = serde_json::from_str(json).unwrap();
+        assert_eq!(m.schema(), Schema::V2);
+        assert_eq!(m, Model::from_json_str(json).unwrap());
+        assert_eq!(serde_json::to_string(&m).unwrap(), json);
+
+        static MODEL: StaticModel = StaticModel::with_schema(
+            Schema::V2,
+            &[("TW4:あいう", -5), ("UW1:あ", 10), ("UW1:い", 20)],
+        );
+        assert_eq!(serde_json::to_string(&MODEL).unwrap(), json);
+    }
+
+    #[test]
+    fn test_model_error() {
+        let err = |json: &str| serde_json::from_str::<Model>(json).unwrap_err().to_string();
+
+        assert!(err(r#"{"UW1:あ": 1.5}"#).starts_with(r#"score of feature "UW1:あ" is not i32"#));
+        assert!(err(r#"{"UW1:あ": 2147483648}"#).starts_with("score of feature"));
+        assert!(err(r#"{"XX1:あ": 1}"#).starts_with(r#"unknown feature "XX1:あ""#));
+        assert!(err(r#"{"UW1": {"あ": {}}}"#).starts_with("score of feature"));
+        assert!(err("{}").starts_with("model has no features"));
+        assert!(err(r#"{"UW1:あ": "1"}"#).contains("expected score"));
+        assert!(err("[]").contains("expected map"));
+    }
+
+    #[test]
+    fn test_metadata() {
+        let m = Model::from_json_str(r#"{"UW1": {"あ": 10, "い": 20}}"#).unwrap();
+        let metadata = m.metadata();
+
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"schema":"V2","features":2,"total":30,"base_score":985}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<crate::Metadata>(&serde_json::to_string(&metadata).unwrap())
+                .unwrap(),
+            metadata
+        );
+    }
+
+    #[test]
+    fn test_phrases() {
+        let m = Model::from_json_str(r#"{"UW3:と": 2000}"#).unwrap();
+        let phrases = crate::parse_phrases(&m, "水と油");
+
+        assert_eq!(
+            serde_json::to_string(&phrases).unwrap(),
+            r#"[{"text":"水と","start":0,"end":6,"score":2000},{"text":"油","start":6,"end":9,"score":null}]"#
+        );
+
+        let explanation = crate::explain(&m, "水と油", 6).unwrap();
+        let json = serde_json::to_string(&explanation).unwrap();
+        assert!(json.starts_with(
+            r#"{"offset":6,"score":2000,"base_score":0,"features":[{"key":"UP1:U","score":null}"#
+        ));
+        assert_eq!(
+            serde_json::from_str::<crate::Explanation>(&json).unwrap(),
+            explanation
+        );
+    }
+}
@@ -0,0 +1,308 @@
+#[cfg(feature = "std")]
+use std::cmp::Ordering;
+
+#[cfg(feature = "std")]
+use crate::{Map, Model, Schema, DEFAULT_THRESHOLD};
+
+/// SEPARATOR is separator of phrases in segmented sentence, same as BudouX (e.g. "これは▁テストです。").
+pub const SEPARATOR: char = '▁';
+
+/// SCALE is multiplier from weight of weak classifier to score, same as BudouX.
+#[cfg(feature = "std")]
+const SCALE: f64 = 1000.0;
+
+/// EPSILON is minimum error rate of weak classifier, it avoids infinite weight.
+#[cfg(feature = "std")]
+const EPSILON: f64 = 1e-10;
+
+/// Trainer is AdaBoost trainer of model, same as BudouX.
+///
+/// Each feature key is a weak classifier, which predicts a boundary if the gap has the feature.
+/// Features are extracted by the same templates as budoux::parse,
+/// but previous results are labels of the sentence instead of predictions.
+/// It is available with `std` feature, because training needs floating point functions of std.
+///
+/// # Examples
+///
+/// ```
+/// let mut trainer = budoux::train::Trainer::new(budoux::Schema::V2);
+/// trainer.add_sentence("水と▁油");
+/// trainer.add_sentence("空と▁海");
+/// trainer.add_sentence("海と▁山と▁川");
+///
+/// let model = trainer.train(100);
+///
+/// assert_eq!(budoux::parse(&model, "水と空"), vec!["水と", "空"]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct Trainer {
+    schema: Schema,
+    keys: Vec<String>,       // feature keys
+    ids: Map<String, usize>, // index of feature keys
+    examples: Vec<Example>,
+}
+
+/// Example is features and label of a gap.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Example {
+    features: Vec<usize>, // sorted index of feature keys
+    label: bool,          // true if the gap is a boundary
+}
+
+#[cfg(feature = "std")]
+impl Trainer {
+    /// new returns trainer of model of schema.
+    pub fn new(schema: Schema) -> Self {
+        Trainer {
+            schema,
+            ..Trainer::default()
+        }
+    }
+
+    /// schema returns feature set of trained model.
+    pub fn schema(&self) -> Schema {
+        self.schema
+    }
+
+    /// len returns number of examples, it is number of gaps in added sentences.
+    pub fn len(&self) -> usize {
+        self.examples.len()
+    }
+
+    /// is_empty returns true if trainer has no examples.
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+
+    /// add_sentence adds segmented sentence, phrases are separated by budoux::train::SEPARATOR.
+    pub fn add_sentence(&mut self, sentence: &str) {
+        let phrases: Vec<&str> = sentence.split(SEPARATOR).collect();
+        self.add_phrases(&phrases);
+    }
+
+    /// add_phrases adds sentence of phrases.
+    pub fn add_phrases(&mut self, phrases: &[&str]) {
+        let input = phrases.concat();
+        let mut boundaries = Vec::with_capacity(phrases.len());
+        let mut end = 0;
+        for phrase in phrases {
+            end += phrase.len();
+            boundaries.push(end);
+        }
+
+        let start = self.examples.len();
+        let mut last = None;
+        let (keys, ids, examples) = (&mut self.keys, &mut self.ids, &mut self.examples);
+
+        crate::visit_labeled_features(
+            &input,
+            self.schema,
+            |offset| boundaries.binary_search(&offset).is_ok(),
+            &mut |offset, label, key| {
+                if last != Some(offset) {
+                    last = Some(offset);
+                    examples.push(Example {
+                        features: Vec::new(),
+                        label,
+                    });
+                }
+
+                let id = match ids.get(key) {
+                    Some(v) => *v,
+                    None => {
+                        keys.push(key.to_string());
+                        ids.insert(key.to_string(), keys.len() - 1);
+                        keys.len() - 1
+                    }
+                };
+
+                examples.last_mut().unwrap().features.push(id);
+            },
+        );
+
+        for example in &mut self.examples[start..] {
+            example.features.sort_unstable();
+            example.features.dedup();
+        }
+    }
+
+    /// train returns model trained by AdaBoost with iterations.
+    ///
+    /// Weights of weak classifiers are scaled so that budoux::DEFAULT_THRESHOLD splits sentences.
+    /// For budoux::Schema::V2, scores are weights multiplied by 1000, as well as BudouX.
+    /// For budoux::Schema::V1, which has no base score, scores are scaled so that half of total is budoux::DEFAULT_THRESHOLD.
+    ///
+    /// * `iterations` - maximum number of boosting rounds, training stops early if no feature is better than random.
+    pub fn train(&self, iterations: usize) -> Model {
+        let mut weights = vec![1.0 / self.examples.len() as f64; self.examples.len()];
+        let mut alphas = vec![0.0; self.keys.len()];
+        let mut gains = vec![0.0; self.keys.len()];
+
+        for _ in 0..iterations {
+            let (best, error) = match self.best_feature(&weights, &mut gains) {
+                Some(v) => v,
+                None => break,
+            };
+
+            let error = error.clamp(EPSILON, 1.0 - EPSILON);
+            let alpha = 0.5 * ((1.0 - error) / error).ln();
+            alphas[best] += alpha;
+
+            let mut sum = 0.0;
+            for (example, weight) in self.examples.iter().zip(&mut weights) {
+                let predicted = example.features.binary_search(&best).is_ok();
+                if predicted == example.label {
+                    *weight *= (-alpha).exp();
+                } else {
+                    *weight *= alpha.exp();
+                }
+                sum += *weight;
+            }
+            for weight in &mut weights {
+                *weight /= sum;
+            }
+        }
+
+        let total: f64 = alphas.iter().sum();
+        let scale = match self.schema {
+            Schema::V1 if total > 0.0 => 2.0 * DEFAULT_THRESHOLD as f64 / total,
+            _ => SCALE,
+        };
+
+        let mut model = Model::with_schema(self.schema);
+        for (key, alpha) in self.keys.iter().zip(alphas) {
+            let score = (alpha * scale).round() as i32;
+            if score != 0 {
+                model.insert(key.clone(), score);
+            }
+        }
+
+        model
+    }
+
+    /// best_feature returns index and weighted error rate of the best weak classifier.
+    /// None is returned if no feature is better than random.
+    fn best_feature(&self, weights: &[f64], gains: &mut [f64]) -> Option<(usize, f64)> {
+        // Error of a feature is weight of positive examples,
+        // minus weight of positive examples with the feature, plus weight of negative examples with the feature.
+        let mut positive = 0.0;
+        gains.iter_mut().for_each(|v| *v = 0.0);
+
+        for (example, weight) in self.examples.iter().zip(weights) {
+            let gain = if example.label {
+                positive += weight;
+                *weight
+            } else {
+                -*weight
+            };
+
+            for id in &example.features {
+                gains[*id] += gain;
+            }
+        }
+
+        let (best, gain) = gains
+            .iter()
+            .enumerate()
+            .map(|(i, gain)| (i, (positive - gain - 0.5).abs()))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            .map(|(i, _)| (i, gains[i]))?;
+        let error = positive - gain;
+
+        if (error - 0.5).abs() < EPSILON {
+            return None;
+        }
+
+        Some((best, error))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+    use super::Trainer;
+    use crate::Schema;
+
+    const CORPUS: [&str; 6] = [
+        "水と▁油",
+        "空と▁海",
+        "海と▁山と▁川",
+        "山は▁青い",
+        "川は▁長い",
+        "青い▁空と▁長い▁川",
+    ];
+
+    fn keys(t: &Trainer, i: usize) -> Vec<&str> {
+        t.examples[i]
+            .features
+            .iter()
+            .map(|v| t.keys[*v].as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_add_sentence() {
+        let mut t = Trainer::new(Schema::V1);
+        assert!(t.is_empty());
+
+        t.add_sentence("");
+        t.add_sentence("水");
+        assert!(t.is_empty());
+
+        t.add_sentence("水と▁油");
+        assert_eq!(t.len(), 2);
+        assert_eq!(
+            t.examples.iter().map(|v| v.label).collect::<Vec<_>>(),
+            vec![false, true]
+        );
+
+        assert_eq!(keys(&t, 0).len(), 42);
+        assert!(keys(&t, 0).contains(&"UW3:水"));
+        assert!(keys(&t, 0).contains(&"UP3:U"));
+        assert!(keys(&t, 1).contains(&"BW2:と油"));
+        assert!(keys(&t, 1).contains(&"UP3:O"));
+
+        t.add_phrases(&["空と", "海"]);
+        assert_eq!(t.len(), 4);
+        assert!(keys(&t, 2).contains(&"UW3:空"));
+        assert_eq!(t.examples[0].features.len(), t.examples[2].features.len());
+
+        let mut t = Trainer::new(Schema::V2);
+        t.add_sentence("水と▁油");
+        assert_eq!(t.examples[0].features.len(), 13);
+    }
+
+    #[test]
+    fn test_train() {
+        for schema in [Schema::V1, Schema::V2] {
+            let mut t = Trainer::new(schema);
+            for sentence in CORPUS {
+                t.add_sentence(sentence);
+            }
+
+            let m = t.train(100);
+            assert_eq!(m.schema(), schema);
+            assert!(!m.is_empty());
+
+            for sentence in CORPUS {
+                let phrases: Vec<&str> = sentence.split(super::SEPARATOR).collect();
+                assert_eq!(crate::parse(&m, &phrases.concat()), phrases);
+            }
+
+            assert_eq!(crate::parse(&m, "水と空"), vec!["水と", "空"]);
+        }
+    }
+
+    #[test]
+    fn test_train_empty() {
+        let t = Trainer::new(Schema::V2);
+        assert!(t.train(10).is_empty());
+
+        let mut t = Trainer::new(Schema::V2);
+        t.add_sentence("水と油");
+        let m = t.train(10);
+        assert_eq!(crate::parse(&m, "水と油"), vec!["水と油"]);
+    }
+}
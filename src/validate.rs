@@ -0,0 +1,280 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::features::{templates, Slot};
+use crate::unicode_blocks::BLOCK_FEATURES;
+use crate::{Model, INVALID_FEATURE};
+
+/// Problem is reason why a feature key is never looked up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Problem {
+    /// UnknownPrefix is prefix not used by any template of schema of the model (e.g. "XX1:").
+    UnknownPrefix,
+    /// WrongLength is number of characters, block IDs or previous results different from the template.
+    WrongLength,
+    /// UnknownBlock is block ID not in unicode block table.
+    UnknownBlock(String),
+    /// InvalidResult is previous result other than "U", "B" or "O".
+    InvalidResult(char),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::UnknownPrefix => write!(f, "unknown prefix"),
+            Problem::WrongLength => write!(f, "wrong length for template"),
+            Problem::UnknownBlock(id) => write!(f, "unknown block id {:?}", id),
+            Problem::InvalidResult(c) => write!(f, "invalid previous result {:?}", c),
+        }
+    }
+}
+
+/// Issue is a feature key which is never looked up.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Issue {
+    /// key of feature (e.g. "UW1:あい").
+    pub key: String,
+    /// score of feature.
+    pub score: i32,
+    /// reason why the key is never looked up.
+    pub problem: Problem,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.key, self.problem)
+    }
+}
+
+/// Report is result of budoux::validate.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// number of checked feature keys.
+    pub checked: usize,
+    /// issues of feature keys, sorted by key.
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    /// is_valid returns true if there are no issues.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// validate returns report of feature keys which are never looked up by the model.
+///
+/// Keys are checked against the templates of schema of the model,
+/// so it finds typos and keys of other tools, which are silently ignored by budoux::parse.
+///
+/// # Examples
+///
+/// ```
+/// let mut model = budoux::Model::new();
+/// model.insert(String::from("UW3:と"), 2000);
+/// model.insert(String::from("UW3:とは"), 100);
+/// model.insert(String::from("UP1:X"), 100);
+///
+/// let report = budoux::validate(&model);
+///
+/// assert!(!report.is_valid());
+/// assert_eq!(report.checked, 3);
+/// assert_eq!(report.issues[0].key, "UP1:X");
+/// assert_eq!(report.issues[0].problem, budoux::Problem::InvalidResult('X'));
+/// assert_eq!(report.issues[1].key, "UW3:とは");
+/// assert_eq!(report.issues[1].problem, budoux::Problem::WrongLength);
+/// ```
+pub fn validate(model: &Model) -> Report {
+    let templates = templates(model.schema());
+
+    let mut report = Report::default();
+    for (key, score) in model.iter() {
+        report.checked += 1;
+
+        let problem = match templates.iter().find(|(prefix, _)| key.starts_with(prefix)) {
+            Some((prefix, slots)) => check(&key[prefix.len()..], slots),
+            None => Some(Problem::UnknownPrefix),
+        };
+
+        if let Some(problem) = problem {
+            report.issues.push(Issue {
+                key: key.to_string(),
+                score,
+                problem,
+            });
+        }
+    }
+
+    report.issues.sort_by(|a, b| a.key.cmp(&b.key));
+    report
+}
+
+/// check returns problem of key without prefix, None if the key can be looked up.
+fn check(mut value: &str, slots: &[Slot]) -> Option<Problem> {
+    if slots.iter().all(|v| matches!(v, Slot::Char(_))) {
+        // Characters before i - 1 and after i are empty at the start and the end of input.
+        let min = slots
+            .iter()
+            .filter(|v| matches!(v, Slot::Char(2) | Slot::Char(3)))
+            .count();
+        let count = value.chars().count();
+        if count < min || count > slots.len() {
+            return Some(Problem::WrongLength);
+        }
+
+        return None;
+    }
+
+    for slot in slots {
+        match slot {
+            Slot::Char(_) => unreachable!("templates do not mix characters and others"),
+            Slot::Result(_) => {
+                let c = match value.chars().next() {
+                    Some(v) => v,
+                    None => return Some(Problem::WrongLength),
+                };
+                if !matches!(c, 'U' | 'B' | 'O') {
+                    return Some(Problem::InvalidResult(c));
+                }
+                value = &value[c.len_utf8()..];
+            }
+            Slot::Block(_) => {
+                if let Some(rest) = value.strip_prefix(INVALID_FEATURE) {
+                    value = rest;
+                    continue;
+                }
+
+                let len = value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len())
+                    .min(3);
+                if len < 3 {
+                    return Some(Problem::WrongLength);
+                }
+
+                let id = &value[..len];
+                if !BLOCK_FEATURES.contains(&id) {
+                    return Some(Problem::UnknownBlock(id.to_string()));
+                }
+                value = &value[len..];
+            }
+        }
+    }
+
+    if !value.is_empty() {
+        return Some(Problem::WrongLength);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Problem;
+    use crate::{Model, Schema};
+
+    fn problems(schema: Schema, keys: &[&str]) -> Vec<(String, Problem)> {
+        let mut m = Model::with_schema(schema);
+        for key in keys {
+            m.insert(key.to_string(), 1);
+        }
+
+        let report = super::validate(&m);
+        assert_eq!(report.checked, keys.len());
+
+        report
+            .issues
+            .into_iter()
+            .map(|v| (v.key, v.problem))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let valid = [
+            "UW1:",
+            "UW1:あ",
+            "BW1:あ",
+            "BW2:あい",
+            "TW1:あいう",
+            "TW4:あ",
+            "UB1:▔",
+            "UB1:001",
+            "BB1:▔108",
+            "TB4:108108▔",
+            "UP1:U",
+            "BP1:BO",
+            "UQ1:U▔",
+            "BQ4:O108120",
+            "TQ1:B▔▔108",
+        ];
+        assert_eq!(
+            problems(Schema::V1, &valid),
+            Vec::<(String, Problem)>::new()
+        );
+
+        let invalid = [
+            ("XX1:あ", Problem::UnknownPrefix),
+            ("UW7:あ", Problem::UnknownPrefix),
+            ("UW1:あい", Problem::WrongLength),
+            ("BW2:あ", Problem::WrongLength),
+            ("TW2:あいうえ", Problem::WrongLength),
+            ("UB1:12", Problem::WrongLength),
+            ("UB1:1234", Problem::WrongLength),
+            ("UB1:", Problem::WrongLength),
+            ("BB1:108", Problem::WrongLength),
+            ("UB2:999", Problem::UnknownBlock(String::from("999"))),
+            ("BB2:108308", Problem::UnknownBlock(String::from("308"))),
+            ("UP1:X", Problem::InvalidResult('X')),
+            ("UP1:", Problem::WrongLength),
+            ("UP2:UU", Problem::WrongLength),
+            ("BP1:Bo", Problem::InvalidResult('o')),
+            ("UQ1:108", Problem::InvalidResult('1')),
+            ("TQ1:B108108", Problem::WrongLength),
+        ];
+        let keys: Vec<&str> = invalid.iter().map(|(k, _)| *k).collect();
+        let mut expected: Vec<(String, Problem)> = invalid
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect();
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(problems(Schema::V1, &keys), expected);
+    }
+
+    #[test]
+    fn test_validate_v2() {
+        assert_eq!(
+            problems(Schema::V2, &["UW1:あ", "TW4:あいう", "UB1:108", "UP1:U"]),
+            vec![
+                (String::from("UB1:108"), Problem::UnknownPrefix),
+                (String::from("UP1:U"), Problem::UnknownPrefix),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_validate_ja() {
+        let m = crate::models::default_japanese_model();
+        let report = super::validate(&m.to_model());
+        assert_eq!(report.checked, m.len());
+
+        // Block ID "999" of BudouX v0.1 is never produced by unicode block table.
+        assert_eq!(report.issues.len(), 11);
+        for issue in report.issues {
+            assert!(issue.key.ends_with("999"), "{}", issue);
+            assert_eq!(issue.problem, Problem::UnknownBlock(String::from("999")));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "model-zh-hans")]
+    fn test_validate_zh_hans() {
+        let m = crate::models::default_simplified_chinese_model();
+        let report = super::validate(&m.to_model());
+
+        assert_eq!(report.checked, m.len());
+        assert!(report.is_valid(), "{:?}", report.issues);
+    }
+}
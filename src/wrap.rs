@@ -0,0 +1,630 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::east_asian_width::WIDE_RANGES;
+use crate::grapheme::{self, Clusters, Property};
+use crate::kinsoku::{self, Rule};
+use crate::Phrase;
+
+/// char_width returns columns of c by east asian width.
+///
+/// Wide (W) and fullwidth (F) characters are 2 columns, and others including ambiguous (A) are 1 column.
+/// Combining marks (Extend) and zero width joiner (ZWJ) are 0 column, because they are part of a preceding character,
+/// except halfwidth voiced sound marks ("ﾞ" and "ﾟ"), which take a halfwidth column of their own.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(budoux::wrap::char_width('a'), 1);
+/// assert_eq!(budoux::wrap::char_width('あ'), 2);
+/// assert_eq!(budoux::wrap::char_width('Ａ'), 2);
+/// assert_eq!(budoux::wrap::char_width('ｱ'), 1);
+/// assert_eq!(budoux::wrap::char_width('\u{3099}'), 0);
+/// assert_eq!(budoux::wrap::char_width('ﾞ'), 1);
+/// ```
+pub fn char_width(c: char) -> usize {
+    match (c, Property::of(c)) {
+        ('\u{FF9E}' | '\u{FF9F}', _) => return 1,
+        (_, Property::Extend | Property::ZWJ) => return 0,
+        _ => {}
+    }
+
+    let code = c as u32;
+    let found = WIDE_RANGES
+        .binary_search_by(|(first, last)| {
+            if *last < code {
+                core::cmp::Ordering::Less
+            } else if *first > code {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok();
+
+    if found {
+        2
+    } else {
+        1
+    }
+}
+
+/// width returns columns of s by east asian width, it is sum of budoux::wrap::char_width.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(budoux::wrap::width("Rustで分割"), 10);
+/// ```
+pub fn width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// wrap returns lines of phrases, each line fits in width columns of east asian width.
+/// It is shorthand for budoux::wrap::wrap_with(phrases, width, budoux::wrap::char_width).
+///
+/// * `phrases` - phrases of sentences (e.g. result of budoux::parse_with_threshold).
+/// * `width` - maximum width of line in columns.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::parse(model, "これはテストです。今日は晴天です。");
+/// let lines = budoux::wrap::wrap(&phrases, 20);
+///
+/// assert_eq!(lines, vec!["これはテストです。", "今日は晴天です。"]);
+/// # }
+/// ```
+pub fn wrap<S: AsRef<str>>(phrases: &[S], width: usize) -> Vec<String> {
+    wrap_with(phrases, width, char_width)
+}
+
+/// wrap_with returns lines of phrases, each line fits in width measured by char_width.
+///
+/// Phrases are packed into lines greedily, and a line breaks only between phrases.
+/// Phrases longer than width are broken between grapheme clusters,
+/// and a grapheme cluster wider than width is put on its own line.
+/// Whitespaces are kept as they are, and empty phrases yield a single empty line.
+///
+/// * `phrases` - phrases of sentences (e.g. result of budoux::parse_with_threshold).
+/// * `width` - maximum width of line.
+/// * `char_width` - width of each character.
+///
+/// # Examples
+///
+/// Count every character as 1 column.
+///
+/// ```
+/// let phrases = ["これは", "テストです。"];
+/// let lines = budoux::wrap::wrap_with(&phrases, 4, |_| 1);
+///
+/// assert_eq!(lines, vec!["これは", "テストで", "す。"]);
+/// ```
+pub fn wrap_with<S: AsRef<str>, F: Fn(char) -> usize>(
+    phrases: &[S],
+    width: usize,
+    char_width: F,
+) -> Vec<String> {
+    pack(phrases, width, char_width, |_, _| true)
+}
+
+/// wrap_with_rule returns lines of phrases as well as budoux::wrap::wrap_with, following rule of kinsoku shori.
+///
+/// Phrase boundaries are adjusted by budoux::kinsoku::apply,
+/// and phrases longer than width are broken only between grapheme clusters allowed by rule.
+/// A line exceeds width if there is no allowed break.
+///
+/// * `phrases` - phrases of sentences (e.g. result of budoux::parse_with_threshold).
+/// * `width` - maximum width of line.
+/// * `char_width` - width of each character (e.g. budoux::wrap::char_width).
+/// * `rule` - line breaking rule.
+///
+/// # Examples
+///
+/// ```
+/// use budoux::kinsoku::Rule;
+/// use budoux::wrap::{char_width, wrap_with, wrap_with_rule};
+///
+/// let phrases = ["これは", "テストです。"];
+///
+/// assert_eq!(wrap_with(&phrases, 10, char_width), vec!["これは", "テストです", "。"]);
+/// assert_eq!(wrap_with_rule(&phrases, 10, char_width, Rule::Strict), vec!["これは", "テストで", "す。"]);
+/// ```
+pub fn wrap_with_rule<S: AsRef<str>, F: Fn(char) -> usize>(
+    phrases: &[S],
+    width: usize,
+    char_width: F,
+    rule: Rule,
+) -> Vec<String> {
+    let phrases = kinsoku::apply(phrases, rule);
+
+    pack(&phrases, width, char_width, |before, after| {
+        rule.allows_break(before, after)
+    })
+}
+
+/// wrap_optimal returns lines of phrases, each line fits in width columns of east asian width.
+/// It is shorthand for budoux::wrap::wrap_optimal_with(phrases, width, budoux::wrap::char_width).
+///
+/// * `phrases` - phrases of sentences with scores (e.g. result of budoux::parse_phrases_with_threshold).
+/// * `width` - maximum width of line in columns.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::parse_phrases(model, "これはテストです。今日は晴天です。");
+/// let lines = budoux::wrap::wrap_optimal(&phrases, 20);
+///
+/// assert_eq!(lines, vec!["これはテストです。", "今日は晴天です。"]);
+/// # }
+/// ```
+pub fn wrap_optimal(phrases: &[Phrase], width: usize) -> Vec<String> {
+    wrap_optimal_with(phrases, width, char_width)
+}
+
+/// wrap_optimal_with returns lines of phrases, which minimize raggedness of the whole paragraph (total-fit, like Knuth–Plass).
+///
+/// Cost of lines is sum of squared unused width of every line, including the last line,
+/// and penalties of breaks.
+/// Phrase boundaries are preferred breakpoints, and a break at boundary costs more as its score is lower
+/// (it is free for scores at least 5000, and costs 1 per 100 of score below it).
+/// Breaks between grapheme clusters inside a phrase are allowed only at a high fixed cost,
+/// so phrases longer than width are broken between grapheme clusters,
+/// and a grapheme cluster wider than width is put on its own line.
+/// Empty phrases yield a single empty line.
+///
+/// * `phrases` - phrases of sentences with scores (e.g. result of budoux::parse_phrases_with_threshold).
+/// * `width` - maximum width of line.
+/// * `char_width` - width of each character.
+///
+/// # Examples
+///
+/// Greedy packing leaves a short last line, but total-fit balances lines.
+///
+/// ```
+/// # #[cfg(feature = "model-ja")]
+/// # {
+/// let model = budoux::models::default_japanese_model();
+/// let phrases = budoux::parse_phrases(model, "これはテストです。今日は晴天です。");
+/// let texts: Vec<&str> = phrases.iter().map(|v| v.text).collect();
+///
+/// assert_eq!(
+///     budoux::wrap::wrap_with(&texts, 28, budoux::wrap::char_width),
+///     vec!["これはテストです。今日は", "晴天です。"]
+/// );
+/// assert_eq!(
+///     budoux::wrap::wrap_optimal_with(&phrases, 28, budoux::wrap::char_width),
+///     vec!["これはテストです。", "今日は晴天です。"]
+/// );
+/// # }
+/// ```
+pub fn wrap_optimal_with<F: Fn(char) -> usize>(
+    phrases: &[Phrase],
+    width: usize,
+    char_width: F,
+) -> Vec<String> {
+    // Ends of phrases are byte offsets of text, with penalty of a break there.
+    let mut text = String::new();
+    let mut ends = Vec::with_capacity(phrases.len());
+
+    for (i, phrase) in phrases.iter().enumerate() {
+        text.push_str(phrase.text);
+        let penalty = match phrase.score {
+            Some(score) if i + 1 < phrases.len() => boundary_penalty(score),
+            _ => 0,
+        };
+        ends.push((text.len(), penalty));
+    }
+
+    // Breakpoints are ends of phrases and grapheme cluster boundaries inside phrases,
+    // with byte offset, total width before it and penalty.
+    let mut breaks = vec![(0, 0, 0)];
+    let mut total = 0;
+    let mut clusters = Clusters::default();
+    let mut ends = ends.iter().peekable();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        let offset = offset + c.len_utf8();
+        total += char_width(c);
+
+        let boundary = match chars.peek() {
+            Some(&(_, after)) => clusters.is_boundary(c, after),
+            None => true,
+        };
+
+        while ends.next_if(|&&(end, _)| end < offset).is_some() {}
+        match ends.peek() {
+            Some(&&(end, penalty)) if end == offset => breaks.push((offset, total, penalty)),
+            _ if boundary => breaks.push((offset, total, CHAR_BREAK_PENALTY)),
+            _ => {}
+        }
+    }
+
+    if let Some(last) = breaks.last_mut() {
+        last.2 = 0;
+    }
+
+    // best[j] is minimum cost of lines until breakpoint j, and breakpoint of the start of its last line.
+    let mut best: Vec<(u64, usize)> = vec![(u64::MAX, 0); breaks.len()];
+    best[0] = (0, 0);
+
+    for j in 1..breaks.len() {
+        for i in (0..j).rev() {
+            let line_width = breaks[j].1 - breaks[i].1;
+            if line_width > width && i + 1 < j {
+                break;
+            }
+
+            let slack = width.saturating_sub(line_width) as u64;
+            let cost = best[i]
+                .0
+                .saturating_add(slack * slack)
+                .saturating_add(breaks[j].2);
+            if cost < best[j].0 {
+                best[j] = (cost, i);
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut j = breaks.len() - 1;
+    while j > 0 {
+        let i = best[j].1;
+        lines.push(text[breaks[i].0..breaks[j].0].to_string());
+        j = i;
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines.reverse();
+    lines
+}
+
+/// STRONG_SCORE is score of phrase boundary, at which a break has no penalty.
+const STRONG_SCORE: i32 = 5000;
+
+/// SCORE_PER_PENALTY is score below STRONG_SCORE, which costs 1 of penalty.
+const SCORE_PER_PENALTY: i32 = 100;
+
+/// CHAR_BREAK_PENALTY is penalty of a break between characters inside a phrase.
+const CHAR_BREAK_PENALTY: u64 = 10_000;
+
+/// boundary_penalty returns penalty of a break at phrase boundary of score.
+fn boundary_penalty(score: i32) -> u64 {
+    (STRONG_SCORE.saturating_sub(score).max(0) / SCORE_PER_PENALTY) as u64
+}
+
+/// pack returns lines of phrases packed greedily,
+/// phrases longer than width are broken between grapheme clusters where allows_break returns true.
+fn pack<S: AsRef<str>, F: Fn(char) -> usize>(
+    phrases: &[S],
+    width: usize,
+    char_width: F,
+    allows_break: impl Fn(char, char) -> bool,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+
+    for phrase in phrases {
+        let phrase = phrase.as_ref();
+        let phrase_width: usize = phrase.chars().map(&char_width).sum();
+
+        if line_width + phrase_width <= width {
+            line.push_str(phrase);
+            line_width += phrase_width;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(core::mem::take(&mut line));
+            line_width = 0;
+        }
+
+        if phrase_width <= width {
+            line.push_str(phrase);
+            line_width = phrase_width;
+            continue;
+        }
+
+        // Phrase longer than width falls back to breaks between grapheme clusters.
+        // The line holds only clusters of the phrase here, and starts are their byte offsets in line.
+        let mut starts: Vec<usize> = Vec::new();
+        for cluster in grapheme::clusters(phrase) {
+            let w: usize = cluster.chars().map(&char_width).sum();
+            if line_width + w > width && !line.is_empty() {
+                // Break at the last allowed position, the line exceeds width if there is none.
+                let found = core::iter::once(line.len())
+                    .chain(starts.iter().rev().copied())
+                    .filter(|&i| i > 0)
+                    .find(|&i| {
+                        let before = line[..i].chars().next_back();
+                        let after = line[i..].chars().chain(cluster.chars()).next();
+                        match (before, after) {
+                            (Some(before), Some(after)) => allows_break(before, after),
+                            _ => false,
+                        }
+                    });
+
+                if let Some(i) = found {
+                    let rest = line.split_off(i);
+                    lines.push(core::mem::replace(&mut line, rest));
+                    line_width = line.chars().map(&char_width).sum();
+                    starts.retain(|&start| start >= i);
+                    for start in &mut starts {
+                        *start -= i;
+                    }
+                }
+            }
+
+            starts.push(line.len());
+            line.push_str(cluster);
+            line_width += w;
+        }
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_char_width() {
+        for c in [
+            'a',
+            ' ',
+            'ｱ',
+            '°',
+            'é',
+            '\u{303F}',
+            '\u{A4D0}',
+            '\u{10FFFF}',
+        ] {
+            assert_eq!(super::char_width(c), 1, "{:?}", c);
+        }
+        for c in [
+            'あ',
+            '漢',
+            '　',
+            '！',
+            '\u{1100}',
+            '\u{3400}',
+            '\u{9FFF}',
+            '\u{1F600}',
+            '\u{20000}',
+            '\u{3FFFD}',
+        ] {
+            assert_eq!(super::char_width(c), 2, "{:?}", c);
+        }
+        for c in ['\u{3099}', '\u{0301}', '\u{200D}', '\u{FE0F}', '\u{1F3FD}'] {
+            assert_eq!(super::char_width(c), 0, "{:?}", c);
+        }
+
+        assert_eq!(super::width(""), 0);
+        assert_eq!(super::width("水とwater"), 9);
+        assert_eq!(super::width("か\u{3099}"), 2);
+        assert_eq!(super::width("ｶﾞ"), 2);
+        assert_eq!(super::width("ﾊﾟｿｺﾝ"), 5);
+        assert_eq!(super::width("👨\u{200D}👩\u{200D}👧"), 6);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let phrases = ["これは", "テストです。", "今日は", "晴天です。"];
+
+        assert_eq!(
+            super::wrap(&phrases, 18),
+            vec!["これはテストです。", "今日は晴天です。"]
+        );
+        assert_eq!(
+            super::wrap(&phrases, 16),
+            vec!["これは", "テストです。", "今日は晴天です。"]
+        );
+        assert_eq!(
+            super::wrap(&phrases, 8),
+            vec!["これは", "テストで", "す。", "今日は", "晴天です", "。"]
+        );
+        assert_eq!(
+            super::wrap(&phrases, 1),
+            "これはテストです。今日は晴天です。"
+                .chars()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(super::wrap(&["水と", "oil"], 6), vec!["水と", "oil"]);
+        assert_eq!(super::wrap(&["水と", "oil"], 7), vec!["水とoil"]);
+
+        let phrases: Vec<String> = phrases.iter().map(|v| v.to_string()).collect();
+        assert_eq!(super::wrap(&phrases, 18), super::wrap(&phrases, 19));
+    }
+
+    #[test]
+    fn test_wrap_empty() {
+        assert_eq!(super::wrap::<&str>(&[], 10), vec![""]);
+        assert_eq!(super::wrap(&[""], 10), vec![""]);
+        assert_eq!(super::wrap(&["", "あ", ""], 10), vec!["あ"]);
+        assert_eq!(super::wrap(&["あい"], 0), vec!["あ", "い"]);
+    }
+
+    #[test]
+    fn test_wrap_clusters() {
+        use crate::kinsoku::Rule;
+
+        let family = "👨\u{200D}👩\u{200D}👧";
+        assert_eq!(super::wrap(&[family], 2), vec![family]);
+        assert_eq!(
+            super::wrap(&["あ", family, "い"], 4),
+            vec!["あ", family, "い"]
+        );
+        assert_eq!(
+            super::wrap(&["か\u{3099}き\u{3099}"], 2),
+            vec!["か\u{3099}", "き\u{3099}"]
+        );
+        assert_eq!(
+            super::wrap(&["か\u{3099}き\u{3099}"], 4),
+            vec!["か\u{3099}き\u{3099}"]
+        );
+        assert_eq!(super::wrap(&["ｶﾞｷﾞ"], 2), vec!["ｶﾞ", "ｷﾞ"]);
+        assert_eq!(super::wrap(&["ｶﾞｷﾞ"], 3), vec!["ｶﾞ", "ｷﾞ"]);
+        assert_eq!(super::wrap(&["ｶﾞｷﾞ"], 4), vec!["ｶﾞｷﾞ"]);
+        assert_eq!(super::wrap_with(&["🇯🇵🇺🇸"], 1, |_| 1), vec!["🇯🇵", "🇺🇸"]);
+        assert_eq!(
+            super::wrap_with_rule(&["か\u{3099}ー"], 2, super::char_width, Rule::Strict),
+            vec!["か\u{3099}ー"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_with() {
+        let phrases = ["ab", "cde", "f"];
+
+        assert_eq!(super::wrap_with(&phrases, 3, |_| 1), vec!["ab", "cde", "f"]);
+        assert_eq!(super::wrap_with(&phrases, 4, |_| 1), vec!["ab", "cdef"]);
+        assert_eq!(
+            super::wrap_with(&phrases, 4, |c| if c == 'e' { 0 } else { 1 }),
+            vec!["abcde", "f"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_with_rule() {
+        use crate::kinsoku::Rule;
+
+        let wrap = |phrases: &[&str], width, rule| {
+            super::wrap_with_rule(phrases, width, super::char_width, rule)
+        };
+
+        // Phrase boundaries.
+        let phrases = ["彼は", "「こんにちは", "」と", "言った。"];
+        assert_eq!(
+            wrap(&phrases, 16, Rule::Strict),
+            vec!["彼は", "「こんにちは」と", "言った。"]
+        );
+        assert_eq!(
+            super::wrap(&phrases, 16),
+            vec!["彼は「こんにちは", "」と言った。"]
+        );
+
+        // Breaks between characters.
+        let phrases = ["コーヒーカップ"];
+        assert_eq!(
+            wrap(&phrases, 2, Rule::Strict),
+            vec!["コー", "ヒー", "カッ", "プ"]
+        );
+        assert_eq!(
+            wrap(&phrases, 2, Rule::Loose),
+            vec!["コ", "ー", "ヒ", "ー", "カ", "ッ", "プ"]
+        );
+        assert_eq!(
+            wrap(&phrases, 6, Rule::Strict),
+            vec!["コー", "ヒー", "カップ"]
+        );
+        assert_eq!(wrap(&["「」「」"], 2, Rule::Strict), vec!["「」", "「」"]);
+        assert_eq!(wrap(&["……"], 2, Rule::Strict), vec!["……"]);
+
+        assert_eq!(wrap(&[], 10, Rule::Strict), vec![""]);
+    }
+
+    fn phrases<'a>(texts: &[&'a str], scores: &[i32]) -> Vec<crate::Phrase<'a>> {
+        let mut start = 0;
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let phrase = crate::Phrase {
+                    text,
+                    start,
+                    end: start + text.len(),
+                    score: scores.get(i).copied(),
+                };
+                start = phrase.end;
+                phrase
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_optimal() {
+        let wrap =
+            |phrases: &[crate::Phrase], width| super::wrap_optimal_with(phrases, width, |_| 1);
+
+        // Balance lines instead of short last line.
+        let p = phrases(&["aaaa", "bb", "cc"], &[2000, 2000]);
+        assert_eq!(
+            super::wrap_with(&["aaaa", "bb", "cc"], 6, |_| 1),
+            vec!["aaaabb", "cc"]
+        );
+        assert_eq!(wrap(&p, 6), vec!["aaaa", "bbcc"]);
+        assert_eq!(wrap(&p, 8), vec!["aaaabbcc"]);
+
+        // Prefer strong boundaries.
+        let p = phrases(&["aa", "bb", "cc"], &[1500, 4500]);
+        assert_eq!(wrap(&p, 4), vec!["aabb", "cc"]);
+        let p = phrases(&["aa", "bb", "cc"], &[4500, 1500]);
+        assert_eq!(wrap(&p, 4), vec!["aa", "bbcc"]);
+
+        // Phrases longer than width.
+        let p = phrases(&["abcdefg", "hi"], &[2000]);
+        assert_eq!(wrap(&p, 3), vec!["abc", "def", "ghi"]);
+        assert_eq!(wrap(&p, 5), vec!["abcde", "fghi"]);
+        assert_eq!(
+            super::wrap_optimal(&phrases(&["漢字"], &[]), 1),
+            vec!["漢", "字"]
+        );
+
+        // Breaks only between grapheme clusters.
+        assert_eq!(
+            super::wrap_optimal(&phrases(&["か\u{3099}"], &[]), 2),
+            vec!["か\u{3099}"]
+        );
+        assert_eq!(
+            super::wrap_optimal(&phrases(&["か\u{3099}き\u{3099}"], &[]), 2),
+            vec!["か\u{3099}", "き\u{3099}"]
+        );
+        assert_eq!(
+            wrap(&phrases(&["a\u{0301}b\u{0301}c"], &[]), 3),
+            vec!["a\u{0301}", "b\u{0301}c"]
+        );
+
+        assert_eq!(wrap(&[], 10), vec![""]);
+        assert_eq!(wrap(&phrases(&[""], &[]), 10), vec![""]);
+        assert_eq!(
+            wrap(&phrases(&["", "ab", ""], &[2000, 2000]), 10),
+            vec!["ab"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "model-ja")]
+    fn test_wrap_optimal_ja() {
+        let m = crate::models::default_japanese_model();
+        let input = "日本語の文章において語の区切りに空白を挟んで記述することはしません。";
+        let phrases = crate::parse_phrases(m, input);
+
+        for width in [10, 16, 20, 30] {
+            let lines = super::wrap_optimal(&phrases, width);
+            assert_eq!(lines.concat(), input);
+            for line in &lines {
+                assert!(super::width(line) <= width, "{:?}", lines);
+            }
+        }
+
+        let phrases = crate::parse_phrases(m, "か\u{3099}");
+        assert_eq!(super::wrap_optimal(&phrases, 2), vec!["か\u{3099}"]);
+    }
+}
@@ -5,1322 +5,1413 @@
 // BudouX | Apache License 2.0 | https://github.com/google/budoux/blob/main/LICENSE
 //
 
-use once_cell::sync::Lazy;
-
 /// MODEL reference to trained machine learning model.
-pub static MODEL: Lazy<crate::Model> = Lazy::new(|| {
-    let mut m = crate::Model::new();
-
-    m.insert(String::from("BB1:120120"), 169);
-    m.insert(String::from("BB1:162120"), 254);
-    m.insert(String::from("BB1:162162"), -189);
-    m.insert(String::from("BB2:001001"), -1251);
-    m.insert(String::from("BB2:107120"), -121);
-    m.insert(String::from("BB2:120120"), -50);
-    m.insert(String::from("BB2:162120"), 159);
-    m.insert(String::from("BB2:162162"), -642);
-    m.insert(String::from("BB3:120120"), 735);
-    m.insert(String::from("BB3:162162"), 601);
-    m.insert(String::from("BP1:BB"), -2);
-    m.insert(String::from("BP1:OO"), -63);
-    m.insert(String::from("BP1:UB"), -61);
-    m.insert(String::from("BP1:UO"), 26);
-    m.insert(String::from("BP2:BB"), -86);
-    m.insert(String::from("BP2:OB"), -30);
-    m.insert(String::from("BP2:OO"), 53);
-    m.insert(String::from("BQ1:B120162"), 24);
-    m.insert(String::from("BQ1:B162120"), 71);
-    m.insert(String::from("BQ2:B120120"), -17);
-    m.insert(String::from("BQ2:B162120"), 65);
-    m.insert(String::from("BQ2:U107120"), -44);
-    m.insert(String::from("BQ2:U120162"), 183);
-    m.insert(String::from("BQ3:B162162"), 59);
-    m.insert(String::from("BQ3:O072120"), -104);
-    m.insert(String::from("BQ3:O120120"), 61);
-    m.insert(String::from("BQ3:O120162"), 514);
-    m.insert(String::from("BQ3:O162162"), -130);
-    m.insert(String::from("BQ4:B120120"), -112);
-    m.insert(String::from("BQ4:B162120"), 148);
-    m.insert(String::from("BQ4:O107120"), 12);
-    m.insert(String::from("BQ4:O120107"), -76);
-    m.insert(String::from("BQ4:O120120"), -48);
-    m.insert(String::from("BQ4:O162120"), 169);
-    m.insert(String::from("BQ4:O162162"), -417);
-    m.insert(String::from("BQ4:U107120"), -373);
-    m.insert(String::from("BW1:一万"), -282);
-    m.insert(String::from("BW1:一九"), -775);
-    m.insert(String::from("BW1:一千"), -249);
-    m.insert(String::from("BW1:一百"), -72);
-    m.insert(String::from("BW1:不得"), -535);
-    m.insert(String::from("BW1:中国"), 665);
-    m.insert(String::from("BW1:了一"), 393);
-    m.insert(String::from("BW1:以色"), -200);
-    m.insert(String::from("BW1:全国"), 423);
-    m.insert(String::from("BW1:共和"), -2243);
-    m.insert(String::from("BW1:分之"), -2105);
-    m.insert(String::from("BW1:加拿"), -544);
-    m.insert(String::from("BW1:十一"), -337);
-    m.insert(String::from("BW1:反对"), -422);
-    m.insert(String::from("BW1:台湾"), 274);
-    m.insert(String::from("BW1:国之"), 460);
-    m.insert(String::from("BW1:国际"), 358);
-    m.insert(String::from("BW1:委员"), -221);
-    m.insert(String::from("BW1:已经"), 782);
-    m.insert(String::from("BW1:平方"), -220);
-    m.insert(String::from("BW1:总统"), 503);
-    m.insert(String::from("BW1:星期"), -476);
-    m.insert(String::from("BW1:是一"), 171);
-    m.insert(String::from("BW1:有人"), 124);
-    m.insert(String::from("BW1:的一"), 1030);
-    m.insert(String::from("BW1:的人"), 240);
-    m.insert(String::from("BW1:的新"), 271);
-    m.insert(String::from("BW1:经济"), 29);
-    m.insert(String::from("BW1:美国"), 202);
-    m.insert(String::from("BW1:越来"), -197);
-    m.insert(String::from("BW1:这样"), -517);
-    m.insert(String::from("BW1:进行"), 337);
-    m.insert(String::from("BW1:高行"), -36);
-    m.insert(String::from("BW1:，并"), 405);
-    m.insert(String::from("BW1:，而"), 113);
-    m.insert(String::from("BW2:一个"), 1119);
-    m.insert(String::from("BW2:一九"), -448);
-    m.insert(String::from("BW2:一百"), -29);
-    m.insert(String::from("BW2:一直"), -36);
-    m.insert(String::from("BW2:万人"), 9);
-    m.insert(String::from("BW2:三十"), -253);
-    m.insert(String::from("BW2:上海"), -165);
-    m.insert(String::from("BW2:不仅"), -97);
-    m.insert(String::from("BW2:不会"), 935);
-    m.insert(String::from("BW2:不断"), -26);
-    m.insert(String::from("BW2:不是"), 146);
-    m.insert(String::from("BW2:不过"), -842);
-    m.insert(String::from("BW2:个一"), -42);
-    m.insert(String::from("BW2:个人"), -121);
-    m.insert(String::from("BW2:中国"), -1912);
-    m.insert(String::from("BW2:为了"), -3239);
-    m.insert(String::from("BW2:为什"), -876);
-    m.insert(String::from("BW2:之一"), -545);
-    m.insert(String::from("BW2:之后"), -256);
-    m.insert(String::from("BW2:也许"), -198);
-    m.insert(String::from("BW2:了解"), -2123);
-    m.insert(String::from("BW2:二十"), -410);
-    m.insert(String::from("BW2:人民"), -796);
-    m.insert(String::from("BW2:亿元"), 104);
-    m.insert(String::from("BW2:令人"), 18);
-    m.insert(String::from("BW2:以上"), -345);
-    m.insert(String::from("BW2:以及"), -726);
-    m.insert(String::from("BW2:会议"), -252);
-    m.insert(String::from("BW2:但是"), -2375);
-    m.insert(String::from("BW2:全国"), 387);
-    m.insert(String::from("BW2:共和"), -830);
-    m.insert(String::from("BW2:其中"), -836);
-    m.insert(String::from("BW2:其他"), -1244);
-    m.insert(String::from("BW2:出口"), -54);
-    m.insert(String::from("BW2:分之"), -1773);
-    m.insert(String::from("BW2:十一"), -785);
-    m.insert(String::from("BW2:十五"), -26);
-    m.insert(String::from("BW2:十多"), -86);
-    m.insert(String::from("BW2:十年"), 422);
-    m.insert(String::from("BW2:去年"), -484);
-    m.insert(String::from("BW2:反对"), -69);
-    m.insert(String::from("BW2:可以"), -1035);
-    m.insert(String::from("BW2:可能"), -1368);
-    m.insert(String::from("BW2:合国"), -855);
-    m.insert(String::from("BW2:同时"), -395);
-    m.insert(String::from("BW2:和平"), -703);
-    m.insert(String::from("BW2:因为"), -690);
-    m.insert(String::from("BW2:国人"), -878);
-    m.insert(String::from("BW2:国家"), -1038);
-    m.insert(String::from("BW2:国际"), -461);
-    m.insert(String::from("BW2:在在"), -1042);
-    m.insert(String::from("BW2:如说"), -63);
-    m.insert(String::from("BW2:委会"), -23);
-    m.insert(String::from("BW2:对于"), -330);
-    m.insert(String::from("BW2:导人"), -266);
-    m.insert(String::from("BW2:就是"), -683);
-    m.insert(String::from("BW2:就说"), -45);
-    m.insert(String::from("BW2:已经"), -2119);
-    m.insert(String::from("BW2:年代"), -939);
-    m.insert(String::from("BW2:应该"), -141);
-    m.insert(String::from("BW2:很多"), -824);
-    m.insert(String::from("BW2:总统"), -502);
-    m.insert(String::from("BW2:成为"), -264);
-    m.insert(String::from("BW2:所以"), -593);
-    m.insert(String::from("BW2:所有"), -577);
-    m.insert(String::from("BW2:方面"), -305);
-    m.insert(String::from("BW2:时候"), -760);
-    m.insert(String::from("BW2:时间"), -82);
-    m.insert(String::from("BW2:是否"), -1124);
-    m.insert(String::from("BW2:最后"), -129);
-    m.insert(String::from("BW2:月份"), -261);
-    m.insert(String::from("BW2:有关"), -799);
-    m.insert(String::from("BW2:有的"), -2274);
-    m.insert(String::from("BW2:正在"), -54);
-    m.insert(String::from("BW2:民币"), -20);
-    m.insert(String::from("BW2:没有"), -1292);
-    m.insert(String::from("BW2:现在"), -1370);
-    m.insert(String::from("BW2:的海"), -822);
-    m.insert(String::from("BW2:的话"), -1643);
-    m.insert(String::from("BW2:目的"), -294);
-    m.insert(String::from("BW2:真的"), -1201);
-    m.insert(String::from("BW2:社会"), -230);
-    m.insert(String::from("BW2:等等"), -36);
-    m.insert(String::from("BW2:美元"), -80);
-    m.insert(String::from("BW2:美国"), -967);
-    m.insert(String::from("BW2:能够"), -989);
-    m.insert(String::from("BW2:要求"), -718);
-    m.insert(String::from("BW2:还是"), -1298);
-    m.insert(String::from("BW2:这个"), -2368);
-    m.insert(String::from("BW2:这些"), -442);
-    m.insert(String::from("BW2:这里"), -769);
-    m.insert(String::from("BW2:那个"), -999);
-    m.insert(String::from("BW2:部分"), -51);
-    m.insert(String::from("BW2:问题"), -226);
-    m.insert(String::from("BW2:际上"), -285);
-    m.insert(String::from("BW2:除了"), -1317);
-    m.insert(String::from("BW2:非常"), -177);
-    m.insert(String::from("BW2:香港"), -377);
-    m.insert(String::from("BW2:，一"), -448);
-    m.insert(String::from("BW2:０多"), -156);
-    m.insert(String::from("BW3:一个"), 62);
-    m.insert(String::from("BW3:上，"), 46);
-    m.insert(String::from("BW3:中国"), 200);
-    m.insert(String::from("BW3:主义"), -1817);
-    m.insert(String::from("BW3:二十"), 52);
-    m.insert(String::from("BW3:人民"), 600);
-    m.insert(String::from("BW3:以上"), 103);
-    m.insert(String::from("BW3:公司"), 287);
-    m.insert(String::from("BW3:共和"), 208);
-    m.insert(String::from("BW3:关系"), 578);
-    m.insert(String::from("BW3:其他"), 51);
-    m.insert(String::from("BW3:去年"), 206);
-    m.insert(String::from("BW3:可以"), 597);
-    m.insert(String::from("BW3:可能"), 129);
-    m.insert(String::from("BW3:台湾"), 490);
-    m.insert(String::from("BW3:后，"), 211);
-    m.insert(String::from("BW3:国家"), 1054);
-    m.insert(String::from("BW3:地区"), 220);
-    m.insert(String::from("BW3:地，"), -527);
-    m.insert(String::from("BW3:多的"), 575);
-    m.insert(String::from("BW3:多，"), 96);
-    m.insert(String::from("BW3:大的"), 473);
-    m.insert(String::from("BW3:好的"), 242);
-    m.insert(String::from("BW3:工作"), 111);
-    m.insert(String::from("BW3:年的"), 493);
-    m.insert(String::from("BW3:年，"), 432);
-    m.insert(String::from("BW3:成为"), 51);
-    m.insert(String::from("BW3:政府"), 634);
-    m.insert(String::from("BW3:教授"), 23);
-    m.insert(String::from("BW3:时，"), 641);
-    m.insert(String::from("BW3:是说"), -264);
-    m.insert(String::from("BW3:来说"), 246);
-    m.insert(String::from("BW3:现在"), 415);
-    m.insert(String::from("BW3:百分"), 371);
-    m.insert(String::from("BW3:社会"), 482);
-    m.insert(String::from("BW3:经济"), 313);
-    m.insert(String::from("BW3:美国"), 78);
-    m.insert(String::from("BW3:行政"), 12);
-    m.insert(String::from("BW3:说，"), 585);
-    m.insert(String::from("BW3:说："), 353);
-    m.insert(String::from("BW3:进行"), 97);
-    m.insert(String::from("BW3:里，"), 399);
-    m.insert(String::from("BW3:香港"), 445);
-    m.insert(String::from("TB1:107120120"), 15);
-    m.insert(String::from("TB1:120120120"), -131);
-    m.insert(String::from("TB1:162162120"), 9);
-    m.insert(String::from("TB2:107120120"), -197);
-    m.insert(String::from("TB3:120107120"), 149);
-    m.insert(String::from("TB3:120120120"), 56);
-    m.insert(String::from("TB3:120162120"), 847);
-    m.insert(String::from("TB3:120162162"), 1392);
-    m.insert(String::from("TB3:162162120"), -30);
-    m.insert(String::from("TB4:120107120"), 86);
-    m.insert(String::from("TB4:120120107"), 79);
-    m.insert(String::from("TB4:120120120"), -75);
-    m.insert(String::from("TB4:120120162"), -35);
-    m.insert(String::from("TB4:162120120"), -53);
-    m.insert(String::from("TB4:162162162"), 121);
-    m.insert(String::from("TQ1:B120120120"), 44);
-    m.insert(String::from("TQ1:B120162120"), 318);
-    m.insert(String::from("TQ1:B120162162"), -120);
-    m.insert(String::from("TQ1:O107120120"), 131);
-    m.insert(String::from("TQ1:O120120107"), 180);
-    m.insert(String::from("TQ1:O120120120"), -31);
-    m.insert(String::from("TQ1:O120162120"), -195);
-    m.insert(String::from("TQ1:O120162162"), 247);
-    m.insert(String::from("TQ1:O162162120"), 269);
-    m.insert(String::from("TQ2:B120120120"), 160);
-    m.insert(String::from("TQ2:B120162120"), 247);
-    m.insert(String::from("TQ2:O120120120"), -93);
-    m.insert(String::from("TQ2:O120162120"), -110);
-    m.insert(String::from("TQ2:U120120120"), -8);
-    m.insert(String::from("TQ3:B107120120"), 80);
-    m.insert(String::from("TQ3:B120107120"), 132);
-    m.insert(String::from("TQ3:B120120120"), -83);
-    m.insert(String::from("TQ3:B162120120"), -85);
-    m.insert(String::from("TQ3:O120120107"), 398);
-    m.insert(String::from("TQ3:O120120120"), 254);
-    m.insert(String::from("TQ3:O120120162"), 445);
-    m.insert(String::from("TQ3:O162120120"), 97);
-    m.insert(String::from("TQ3:O162162120"), 544);
-    m.insert(String::from("TQ4:B120120120"), -91);
-    m.insert(String::from("TQ4:B120120162"), -150);
-    m.insert(String::from("TQ4:O120120120"), 434);
-    m.insert(String::from("TQ4:O120120162"), 65);
-    m.insert(String::from("TQ4:O120162120"), 196);
-    m.insert(String::from("TQ4:O162162162"), -63);
-    m.insert(String::from("TW2:实际上"), -63);
-    m.insert(String::from("TW2:就是说"), -2927);
-    m.insert(String::from("TW2:特别是"), -602);
-    m.insert(String::from("TW2:电视台"), -656);
-    m.insert(String::from("TW2:这样的"), -180);
-    m.insert(String::from("TW2:进一步"), -1031);
-    m.insert(String::from("TW3:在一起"), -841);
-    m.insert(String::from("TW3:就是说"), -39);
-    m.insert(String::from("TW4:的话，"), -261);
-    m.insert(String::from("UB1:162"), 55);
-    m.insert(String::from("UB2:120"), 336);
-    m.insert(String::from("UB2:162"), 77);
-    m.insert(String::from("UB3:001"), -59);
-    m.insert(String::from("UB3:120"), -175);
-    m.insert(String::from("UB3:162"), 349);
-    m.insert(String::from("UB4:001"), -119);
-    m.insert(String::from("UB4:072"), -558);
-    m.insert(String::from("UB4:107"), -2046);
-    m.insert(String::from("UB4:120"), -1022);
-    m.insert(String::from("UB4:162"), -1536);
-    m.insert(String::from("UB5:107"), -260);
-    m.insert(String::from("UB5:120"), -47);
-    m.insert(String::from("UB5:162"), 67);
-    m.insert(String::from("UB6:120"), -191);
-    m.insert(String::from("UB6:162"), 32);
-    m.insert(String::from("UP1:B"), 16);
-    m.insert(String::from("UP1:O"), -49);
-    m.insert(String::from("UP2:O"), 9);
-    m.insert(String::from("UP3:B"), -74);
-    m.insert(String::from("UP3:O"), 771);
-    m.insert(String::from("UQ1:B120"), 3);
-    m.insert(String::from("UQ1:B162"), 33);
-    m.insert(String::from("UQ1:O120"), 15);
-    m.insert(String::from("UQ1:O162"), -62);
-    m.insert(String::from("UQ2:B120"), 68);
-    m.insert(String::from("UQ2:B162"), 43);
-    m.insert(String::from("UQ2:O107"), 221);
-    m.insert(String::from("UQ2:O120"), -36);
-    m.insert(String::from("UQ3:B072"), -210);
-    m.insert(String::from("UQ3:B107"), -1763);
-    m.insert(String::from("UQ3:B120"), 377);
-    m.insert(String::from("UQ3:B162"), 21);
-    m.insert(String::from("UQ3:O002"), -1155);
-    m.insert(String::from("UQ3:O072"), 758);
-    m.insert(String::from("UQ3:O107"), 2487);
-    m.insert(String::from("UQ3:O120"), -273);
-    m.insert(String::from("UW1:、"), 35);
-    m.insert(String::from("UW1:」"), -45);
-    m.insert(String::from("UW1:一"), -163);
-    m.insert(String::from("UW1:三"), -13);
-    m.insert(String::from("UW1:两"), -104);
-    m.insert(String::from("UW1:中"), -81);
-    m.insert(String::from("UW1:主"), -54);
-    m.insert(String::from("UW1:九"), -32);
-    m.insert(String::from("UW1:二"), -22);
-    m.insert(String::from("UW1:人"), -19);
-    m.insert(String::from("UW1:从"), -23);
-    m.insert(String::from("UW1:以"), 27);
-    m.insert(String::from("UW1:任"), -90);
-    m.insert(String::from("UW1:全"), -110);
-    m.insert(String::from("UW1:分"), -82);
-    m.insert(String::from("UW1:到"), -135);
-    m.insert(String::from("UW1:十"), 279);
-    m.insert(String::from("UW1:可"), 69);
-    m.insert(String::from("UW1:各"), -71);
-    m.insert(String::from("UW1:员"), -79);
-    m.insert(String::from("UW1:和"), -37);
-    m.insert(String::from("UW1:在"), -51);
-    m.insert(String::from("UW1:家"), -28);
-    m.insert(String::from("UW1:席"), -3);
-    m.insert(String::from("UW1:是"), -230);
-    m.insert(String::from("UW1:月"), -321);
-    m.insert(String::from("UW1:有"), 6);
-    m.insert(String::from("UW1:正"), -61);
-    m.insert(String::from("UW1:民"), -77);
-    m.insert(String::from("UW1:波"), -266);
-    m.insert(String::from("UW1:温"), 241);
-    m.insert(String::from("UW1:现"), 28);
-    m.insert(String::from("UW1:由"), -63);
-    m.insert(String::from("UW1:百"), -63);
-    m.insert(String::from("UW1:的"), 26);
-    m.insert(String::from("UW1:相"), -78);
-    m.insert(String::from("UW1:社"), -12);
-    m.insert(String::from("UW1:第"), 616);
-    m.insert(String::from("UW1:统"), -100);
-    m.insert(String::from("UW1:者"), -70);
-    m.insert(String::from("UW1:身"), 127);
-    m.insert(String::from("UW1:达"), -9);
-    m.insert(String::from("UW1:这"), -83);
-    m.insert(String::from("UW1:都"), -22);
-    m.insert(String::from("UW1:长"), -455);
-    m.insert(String::from("UW1:９"), -276);
-    m.insert(String::from("UW2:、"), -250);
-    m.insert(String::from("UW2:一"), 387);
-    m.insert(String::from("UW2:下"), 39);
-    m.insert(String::from("UW2:与"), -760);
-    m.insert(String::from("UW2:两"), 1319);
-    m.insert(String::from("UW2:个"), -210);
-    m.insert(String::from("UW2:中"), 32);
-    m.insert(String::from("UW2:为"), -185);
-    m.insert(String::from("UW2:主"), 238);
-    m.insert(String::from("UW2:么"), 197);
-    m.insert(String::from("UW2:九"), -886);
-    m.insert(String::from("UW2:了"), -203);
-    m.insert(String::from("UW2:二"), -214);
-    m.insert(String::from("UW2:于"), -83);
-    m.insert(String::from("UW2:人"), 15);
-    m.insert(String::from("UW2:从"), -456);
-    m.insert(String::from("UW2:他"), -49);
-    m.insert(String::from("UW2:以"), -261);
-    m.insert(String::from("UW2:伊"), -121);
-    m.insert(String::from("UW2:伦"), 167);
-    m.insert(String::from("UW2:你"), -81);
-    m.insert(String::from("UW2:克"), -169);
-    m.insert(String::from("UW2:全"), 191);
-    m.insert(String::from("UW2:公"), 345);
-    m.insert(String::from("UW2:共"), -272);
-    m.insert(String::from("UW2:几"), 789);
-    m.insert(String::from("UW2:出"), 7);
-    m.insert(String::from("UW2:分"), -18);
-    m.insert(String::from("UW2:到"), -54);
-    m.insert(String::from("UW2:副"), -881);
-    m.insert(String::from("UW2:加"), -70);
-    m.insert(String::from("UW2:务"), 9);
-    m.insert(String::from("UW2:势"), -20);
-    m.insert(String::from("UW2:反"), -194);
-    m.insert(String::from("UW2:发"), 41);
-    m.insert(String::from("UW2:可"), 80);
-    m.insert(String::from("UW2:台"), 27);
-    m.insert(String::from("UW2:各"), 310);
-    m.insert(String::from("UW2:名"), -152);
-    m.insert(String::from("UW2:和"), -706);
-    m.insert(String::from("UW2:国"), -57);
-    m.insert(String::from("UW2:在"), -522);
-    m.insert(String::from("UW2:基"), -18);
-    m.insert(String::from("UW2:多"), 276);
-    m.insert(String::from("UW2:大"), -286);
-    m.insert(String::from("UW2:天"), -148);
-    m.insert(String::from("UW2:奥"), -369);
-    m.insert(String::from("UW2:委"), -154);
-    m.insert(String::from("UW2:学"), 126);
-    m.insert(String::from("UW2:实"), -65);
-    m.insert(String::from("UW2:对"), -42);
-    m.insert(String::from("UW2:将"), -116);
-    m.insert(String::from("UW2:尔"), -139);
-    m.insert(String::from("UW2:已"), 80);
-    m.insert(String::from("UW2:巴"), -273);
-    m.insert(String::from("UW2:常"), -6);
-    m.insert(String::from("UW2:年"), 88);
-    m.insert(String::from("UW2:建"), 23);
-    m.insert(String::from("UW2:开"), 288);
-    m.insert(String::from("UW2:张"), -704);
-    m.insert(String::from("UW2:或"), -178);
-    m.insert(String::from("UW2:所"), -195);
-    m.insert(String::from("UW2:把"), -42);
-    m.insert(String::from("UW2:据"), -55);
-    m.insert(String::from("UW2:提"), 162);
-    m.insert(String::from("UW2:政"), 357);
-    m.insert(String::from("UW2:斯"), -63);
-    m.insert(String::from("UW2:新"), -506);
-    m.insert(String::from("UW2:无"), -392);
-    m.insert(String::from("UW2:日"), 131);
-    m.insert(String::from("UW2:时"), 99);
-    m.insert(String::from("UW2:星"), -249);
-    m.insert(String::from("UW2:是"), -265);
-    m.insert(String::from("UW2:最"), 336);
-    m.insert(String::from("UW2:有"), -197);
-    m.insert(String::from("UW2:朱"), -129);
-    m.insert(String::from("UW2:机"), 38);
-    m.insert(String::from("UW2:李"), -416);
-    m.insert(String::from("UW2:次"), -189);
-    m.insert(String::from("UW2:每"), 404);
-    m.insert(String::from("UW2:民"), 94);
-    m.insert(String::from("UW2:波"), -160);
-    m.insert(String::from("UW2:洛"), -242);
-    m.insert(String::from("UW2:点"), -135);
-    m.insert(String::from("UW2:然"), 12);
-    m.insert(String::from("UW2:现"), 38);
-    m.insert(String::from("UW2:理"), -33);
-    m.insert(String::from("UW2:百"), -649);
-    m.insert(String::from("UW2:的"), -811);
-    m.insert(String::from("UW2:看"), -96);
-    m.insert(String::from("UW2:着"), -73);
-    m.insert(String::from("UW2:研"), -92);
-    m.insert(String::from("UW2:种"), -102);
-    m.insert(String::from("UW2:米"), -22);
-    m.insert(String::from("UW2:经"), 146);
-    m.insert(String::from("UW2:统"), -22);
-    m.insert(String::from("UW2:美"), 159);
-    m.insert(String::from("UW2:老"), -106);
-    m.insert(String::from("UW2:而"), -199);
-    m.insert(String::from("UW2:菲"), -69);
-    m.insert(String::from("UW2:被"), -239);
-    m.insert(String::from("UW2:让"), -27);
-    m.insert(String::from("UW2:这"), 827);
-    m.insert(String::from("UW2:那"), 176);
-    m.insert(String::from("UW2:阿"), -631);
-    m.insert(String::from("UW2:际"), -9);
-    m.insert(String::from("UW2:陈"), -640);
-    m.insert(String::from("UW2:首"), 184);
-    m.insert(String::from("UW2:高"), -65);
-    m.insert(String::from("UW2:（"), -485);
-    m.insert(String::from("UW2:６"), 76);
-    m.insert(String::from("UW2:９"), -134);
-    m.insert(String::from("UW3:“"), -1957);
-    m.insert(String::from("UW3:”"), 564);
-    m.insert(String::from("UW3:「"), -532);
-    m.insert(String::from("UW3:一"), 781);
-    m.insert(String::from("UW3:万"), 67);
-    m.insert(String::from("UW3:三"), 397);
-    m.insert(String::from("UW3:上"), 672);
-    m.insert(String::from("UW3:下"), 90);
-    m.insert(String::from("UW3:不"), 784);
-    m.insert(String::from("UW3:与"), 1751);
-    m.insert(String::from("UW3:且"), 504);
-    m.insert(String::from("UW3:世"), -395);
-    m.insert(String::from("UW3:业"), 329);
-    m.insert(String::from("UW3:两"), 1790);
-    m.insert(String::from("UW3:个"), 1504);
-    m.insert(String::from("UW3:中"), 319);
-    m.insert(String::from("UW3:为"), 1255);
-    m.insert(String::from("UW3:主"), -636);
-    m.insert(String::from("UW3:么"), 368);
-    m.insert(String::from("UW3:之"), 520);
-    m.insert(String::from("UW3:九"), -824);
-    m.insert(String::from("UW3:也"), 1947);
-    m.insert(String::from("UW3:买"), 254);
-    m.insert(String::from("UW3:了"), 2522);
-    m.insert(String::from("UW3:于"), 1101);
-    m.insert(String::from("UW3:些"), 151);
-    m.insert(String::from("UW3:交"), -444);
-    m.insert(String::from("UW3:人"), 705);
-    m.insert(String::from("UW3:亿"), 93);
-    m.insert(String::from("UW3:仅"), 325);
-    m.insert(String::from("UW3:今"), -520);
-    m.insert(String::from("UW3:仍"), 295);
-    m.insert(String::from("UW3:从"), 1114);
-    m.insert(String::from("UW3:他"), 2511);
-    m.insert(String::from("UW3:令"), 333);
-    m.insert(String::from("UW3:以"), 581);
-    m.insert(String::from("UW3:们"), 1745);
-    m.insert(String::from("UW3:件"), 626);
-    m.insert(String::from("UW3:会"), 1037);
-    m.insert(String::from("UW3:但"), 1328);
-    m.insert(String::from("UW3:位"), 1040);
-    m.insert(String::from("UW3:你"), 2260);
-    m.insert(String::from("UW3:使"), 369);
-    m.insert(String::from("UW3:保"), -346);
-    m.insert(String::from("UW3:做"), 538);
-    m.insert(String::from("UW3:像"), 624);
-    m.insert(String::from("UW3:元"), 100);
-    m.insert(String::from("UW3:党"), 314);
-    m.insert(String::from("UW3:入"), 12);
-    m.insert(String::from("UW3:全"), 666);
-    m.insert(String::from("UW3:八"), -125);
-    m.insert(String::from("UW3:公"), -1390);
-    m.insert(String::from("UW3:六"), 70);
-    m.insert(String::from("UW3:共"), 23);
-    m.insert(String::from("UW3:再"), 835);
-    m.insert(String::from("UW3:几"), 746);
-    m.insert(String::from("UW3:分"), -216);
-    m.insert(String::from("UW3:则"), 1261);
-    m.insert(String::from("UW3:利"), -293);
-    m.insert(String::from("UW3:到"), 1673);
-    m.insert(String::from("UW3:前"), 613);
-    m.insert(String::from("UW3:副"), -132);
-    m.insert(String::from("UW3:加"), -357);
-    m.insert(String::from("UW3:务"), -120);
-    m.insert(String::from("UW3:包"), -3);
-    m.insert(String::from("UW3:北"), -245);
-    m.insert(String::from("UW3:区"), 456);
-    m.insert(String::from("UW3:十"), -759);
-    m.insert(String::from("UW3:千"), -676);
-    m.insert(String::from("UW3:华"), -157);
-    m.insert(String::from("UW3:却"), 1493);
-    m.insert(String::from("UW3:去"), 540);
-    m.insert(String::from("UW3:参"), -803);
-    m.insert(String::from("UW3:又"), 1562);
-    m.insert(String::from("UW3:及"), 1172);
-    m.insert(String::from("UW3:反"), -104);
-    m.insert(String::from("UW3:发"), -563);
-    m.insert(String::from("UW3:口"), 38);
-    m.insert(String::from("UW3:只"), 493);
-    m.insert(String::from("UW3:叫"), 18);
-    m.insert(String::from("UW3:可"), -166);
-    m.insert(String::from("UW3:号"), 400);
-    m.insert(String::from("UW3:各"), 1462);
-    m.insert(String::from("UW3:合"), -352);
-    m.insert(String::from("UW3:同"), 181);
-    m.insert(String::from("UW3:名"), 694);
-    m.insert(String::from("UW3:后"), 598);
-    m.insert(String::from("UW3:向"), 1208);
-    m.insert(String::from("UW3:吨"), 250);
-    m.insert(String::from("UW3:呃"), 440);
-    m.insert(String::from("UW3:呢"), 1038);
-    m.insert(String::from("UW3:和"), 1875);
-    m.insert(String::from("UW3:啊"), 206);
-    m.insert(String::from("UW3:四"), 223);
-    m.insert(String::from("UW3:国"), 194);
-    m.insert(String::from("UW3:在"), 2146);
-    m.insert(String::from("UW3:地"), 414);
-    m.insert(String::from("UW3:场"), 378);
-    m.insert(String::from("UW3:基"), -510);
-    m.insert(String::from("UW3:增"), -277);
-    m.insert(String::from("UW3:多"), 750);
-    m.insert(String::from("UW3:大"), 61);
-    m.insert(String::from("UW3:天"), 116);
-    m.insert(String::from("UW3:太"), 666);
-    m.insert(String::from("UW3:头"), 113);
-    m.insert(String::from("UW3:她"), 1425);
-    m.insert(String::from("UW3:好"), 387);
-    m.insert(String::from("UW3:如"), -226);
-    m.insert(String::from("UW3:学"), -6);
-    m.insert(String::from("UW3:它"), 1531);
-    m.insert(String::from("UW3:安"), -354);
-    m.insert(String::from("UW3:定"), 142);
-    m.insert(String::from("UW3:实"), -328);
-    m.insert(String::from("UW3:家"), 399);
-    m.insert(String::from("UW3:对"), 1235);
-    m.insert(String::from("UW3:导"), -439);
-    m.insert(String::from("UW3:将"), 2002);
-    m.insert(String::from("UW3:小"), 320);
-    m.insert(String::from("UW3:尔"), -436);
-    m.insert(String::from("UW3:就"), 1619);
-    m.insert(String::from("UW3:尼"), -216);
-    m.insert(String::from("UW3:届"), 293);
-    m.insert(String::from("UW3:岁"), 100);
-    m.insert(String::from("UW3:工"), -331);
-    m.insert(String::from("UW3:已"), 1430);
-    m.insert(String::from("UW3:市"), 121);
-    m.insert(String::from("UW3:常"), 229);
-    m.insert(String::from("UW3:年"), 1164);
-    m.insert(String::from("UW3:并"), 607);
-    m.insert(String::from("UW3:广"), -137);
-    m.insert(String::from("UW3:府"), 163);
-    m.insert(String::from("UW3:度"), 565);
-    m.insert(String::from("UW3:建"), -152);
-    m.insert(String::from("UW3:开"), -370);
-    m.insert(String::from("UW3:很"), 1528);
-    m.insert(String::from("UW3:得"), 398);
-    m.insert(String::from("UW3:性"), 90);
-    m.insert(String::from("UW3:总"), -125);
-    m.insert(String::from("UW3:您"), 244);
-    m.insert(String::from("UW3:想"), 391);
-    m.insert(String::from("UW3:我"), 2180);
-    m.insert(String::from("UW3:或"), 752);
-    m.insert(String::from("UW3:所"), 449);
-    m.insert(String::from("UW3:才"), 1356);
-    m.insert(String::from("UW3:把"), 1647);
-    m.insert(String::from("UW3:投"), -608);
-    m.insert(String::from("UW3:拉"), -1146);
-    m.insert(String::from("UW3:指"), -54);
-    m.insert(String::from("UW3:据"), 1014);
-    m.insert(String::from("UW3:提"), -758);
-    m.insert(String::from("UW3:支"), -9);
-    m.insert(String::from("UW3:改"), -57);
-    m.insert(String::from("UW3:政"), -550);
-    m.insert(String::from("UW3:敦"), 529);
-    m.insert(String::from("UW3:文"), -294);
-    m.insert(String::from("UW3:斯"), -757);
-    m.insert(String::from("UW3:新"), 497);
-    m.insert(String::from("UW3:无"), -137);
-    m.insert(String::from("UW3:时"), 348);
-    m.insert(String::from("UW3:明"), -55);
-    m.insert(String::from("UW3:是"), 2434);
-    m.insert(String::from("UW3:更"), 1322);
-    m.insert(String::from("UW3:曾"), 324);
-    m.insert(String::from("UW3:最"), 865);
-    m.insert(String::from("UW3:月"), 827);
-    m.insert(String::from("UW3:有"), 1126);
-    m.insert(String::from("UW3:未"), 93);
-    m.insert(String::from("UW3:本"), 294);
-    m.insert(String::from("UW3:机"), -52);
-    m.insert(String::from("UW3:条"), 124);
-    m.insert(String::from("UW3:来"), 674);
-    m.insert(String::from("UW3:果"), 154);
-    m.insert(String::from("UW3:次"), 917);
-    m.insert(String::from("UW3:此"), 581);
-    m.insert(String::from("UW3:段"), 9);
-    m.insert(String::from("UW3:每"), 1372);
-    m.insert(String::from("UW3:民"), -18);
-    m.insert(String::from("UW3:泽"), -48);
-    m.insert(String::from("UW3:洛"), -222);
-    m.insert(String::from("UW3:港"), 291);
-    m.insert(String::from("UW3:湾"), 54);
-    m.insert(String::from("UW3:点"), -265);
-    m.insert(String::from("UW3:特"), -636);
-    m.insert(String::from("UW3:用"), 677);
-    m.insert(String::from("UW3:由"), 986);
-    m.insert(String::from("UW3:电"), -479);
-    m.insert(String::from("UW3:界"), 41);
-    m.insert(String::from("UW3:百"), -850);
-    m.insert(String::from("UW3:的"), 3511);
-    m.insert(String::from("UW3:目"), -521);
-    m.insert(String::from("UW3:相"), -566);
-    m.insert(String::from("UW3:省"), 726);
-    m.insert(String::from("UW3:看"), 36);
-    m.insert(String::from("UW3:着"), 1472);
-    m.insert(String::from("UW3:研"), -254);
-    m.insert(String::from("UW3:种"), 708);
-    m.insert(String::from("UW3:科"), -663);
-    m.insert(String::from("UW3:站"), 95);
-    m.insert(String::from("UW3:第"), -1365);
-    m.insert(String::from("UW3:等"), 1313);
-    m.insert(String::from("UW3:约"), 330);
-    m.insert(String::from("UW3:经"), -410);
-    m.insert(String::from("UW3:给"), 1258);
-    m.insert(String::from("UW3:维"), -589);
-    m.insert(String::from("UW3:罗"), -1172);
-    m.insert(String::from("UW3:美"), -137);
-    m.insert(String::from("UW3:者"), 699);
-    m.insert(String::from("UW3:而"), 1176);
-    m.insert(String::from("UW3:联"), -599);
-    m.insert(String::from("UW3:能"), 1071);
-    m.insert(String::from("UW3:至"), 207);
-    m.insert(String::from("UW3:表"), -242);
-    m.insert(String::from("UW3:被"), 1550);
-    m.insert(String::from("UW3:西"), -291);
-    m.insert(String::from("UW3:要"), 1614);
-    m.insert(String::from("UW3:规"), -238);
-    m.insert(String::from("UW3:认"), -357);
-    m.insert(String::from("UW3:让"), 1468);
-    m.insert(String::from("UW3:话"), 466);
-    m.insert(String::from("UW3:该"), 1232);
-    m.insert(String::from("UW3:说"), 950);
-    m.insert(String::from("UW3:请"), 327);
-    m.insert(String::from("UW3:谁"), 396);
-    m.insert(String::from("UW3:起"), 128);
-    m.insert(String::from("UW3:越"), 66);
-    m.insert(String::from("UW3:跟"), 694);
-    m.insert(String::from("UW3:车"), 20);
-    m.insert(String::from("UW3:较"), 1105);
-    m.insert(String::from("UW3:达"), 71);
-    m.insert(String::from("UW3:过"), 523);
-    m.insert(String::from("UW3:运"), -370);
-    m.insert(String::from("UW3:近"), 281);
-    m.insert(String::from("UW3:还"), 1263);
-    m.insert(String::from("UW3:这"), 1664);
-    m.insert(String::from("UW3:进"), -642);
-    m.insert(String::from("UW3:通"), -270);
-    m.insert(String::from("UW3:那"), 680);
-    m.insert(String::from("UW3:都"), 1952);
-    m.insert(String::from("UW3:里"), 209);
-    m.insert(String::from("UW3:重"), -151);
-    m.insert(String::from("UW3:量"), 120);
-    m.insert(String::from("UW3:钱"), 51);
-    m.insert(String::from("UW3:长"), 369);
-    m.insert(String::from("UW3:门"), 92);
-    m.insert(String::from("UW3:间"), 377);
-    m.insert(String::from("UW3:阿"), -90);
-    m.insert(String::from("UW3:院"), 351);
-    m.insert(String::from("UW3:零"), -297);
-    m.insert(String::from("UW3:面"), 6);
-    m.insert(String::from("UW3:项"), 12);
-    m.insert(String::from("UW3:领"), -272);
-    m.insert(String::from("UW3:题"), 121);
-    m.insert(String::from("UW3:首"), 18);
-    m.insert(String::from("UW3:香"), -49);
-    m.insert(String::from("UW3:驻"), 275);
-    m.insert(String::from("UW3:％"), 573);
-    m.insert(String::from("UW3:（"), -1161);
-    m.insert(String::from("UW3:＊"), -652);
-    m.insert(String::from("UW3:，"), 2115);
-    m.insert(String::from("UW3:／"), 346);
-    m.insert(String::from("UW3:０"), -317);
-    m.insert(String::from("UW3:１"), -304);
-    m.insert(String::from("UW3:："), 82);
-    m.insert(String::from("UW3:＞"), 722);
-    m.insert(String::from("UW4:·"), -1328);
-    m.insert(String::from("UW4:“"), 1596);
-    m.insert(String::from("UW4:”"), -4374);
-    m.insert(String::from("UW4:…"), -1586);
-    m.insert(String::from("UW4:、"), -2639);
-    m.insert(String::from("UW4:。"), -4099);
-    m.insert(String::from("UW4:《"), 2533);
-    m.insert(String::from("UW4:「"), 2806);
-    m.insert(String::from("UW4:」"), -3274);
-    m.insert(String::from("UW4:『"), 579);
-    m.insert(String::from("UW4:一"), 641);
-    m.insert(String::from("UW4:七"), -16);
-    m.insert(String::from("UW4:万"), -1359);
-    m.insert(String::from("UW4:三"), 80);
-    m.insert(String::from("UW4:上"), 538);
-    m.insert(String::from("UW4:下"), 76);
-    m.insert(String::from("UW4:不"), 520);
-    m.insert(String::from("UW4:与"), 1150);
-    m.insert(String::from("UW4:且"), -1100);
-    m.insert(String::from("UW4:世"), 318);
-    m.insert(String::from("UW4:业"), -1096);
-    m.insert(String::from("UW4:两"), 897);
-    m.insert(String::from("UW4:个"), 786);
-    m.insert(String::from("UW4:中"), 978);
-    m.insert(String::from("UW4:久"), -69);
-    m.insert(String::from("UW4:么"), -1985);
-    m.insert(String::from("UW4:义"), -478);
-    m.insert(String::from("UW4:之"), 411);
-    m.insert(String::from("UW4:乎"), -239);
-    m.insert(String::from("UW4:九"), -852);
-    m.insert(String::from("UW4:也"), 1984);
-    m.insert(String::from("UW4:了"), 2088);
-    m.insert(String::from("UW4:于"), -780);
-    m.insert(String::from("UW4:五"), -59);
-    m.insert(String::from("UW4:亚"), -375);
-    m.insert(String::from("UW4:些"), -1435);
-    m.insert(String::from("UW4:人"), -69);
-    m.insert(String::from("UW4:亿"), -1649);
-    m.insert(String::from("UW4:今"), 212);
-    m.insert(String::from("UW4:仍"), 220);
-    m.insert(String::from("UW4:从"), 830);
-    m.insert(String::from("UW4:他"), 1680);
-    m.insert(String::from("UW4:以"), 384);
-    m.insert(String::from("UW4:们"), -4203);
-    m.insert(String::from("UW4:份"), -24);
-    m.insert(String::from("UW4:企"), 430);
-    m.insert(String::from("UW4:会"), -294);
-    m.insert(String::from("UW4:传"), 62);
-    m.insert(String::from("UW4:位"), 178);
-    m.insert(String::from("UW4:体"), -83);
-    m.insert(String::from("UW4:何"), -144);
-    m.insert(String::from("UW4:你"), 1405);
-    m.insert(String::from("UW4:使"), 87);
-    m.insert(String::from("UW4:保"), 145);
-    m.insert(String::from("UW4:倍"), 293);
-    m.insert(String::from("UW4:做"), 1020);
-    m.insert(String::from("UW4:儿"), -288);
-    m.insert(String::from("UW4:元"), 385);
-    m.insert(String::from("UW4:先"), 103);
-    m.insert(String::from("UW4:克"), -564);
-    m.insert(String::from("UW4:党"), -470);
-    m.insert(String::from("UW4:入"), -173);
-    m.insert(String::from("UW4:全"), 318);
-    m.insert(String::from("UW4:八"), -134);
-    m.insert(String::from("UW4:公"), 660);
-    m.insert(String::from("UW4:兰"), -425);
-    m.insert(String::from("UW4:共"), 44);
-    m.insert(String::from("UW4:内"), 30);
-    m.insert(String::from("UW4:再"), 172);
-    m.insert(String::from("UW4:军"), -20);
-    m.insert(String::from("UW4:几"), 187);
-    m.insert(String::from("UW4:出"), 28);
-    m.insert(String::from("UW4:切"), -20);
-    m.insert(String::from("UW4:列"), -406);
-    m.insert(String::from("UW4:创"), 42);
-    m.insert(String::from("UW4:利"), -413);
-    m.insert(String::from("UW4:到"), 65);
-    m.insert(String::from("UW4:前"), 19);
-    m.insert(String::from("UW4:副"), 275);
-    m.insert(String::from("UW4:力"), -940);
-    m.insert(String::from("UW4:务"), -803);
-    m.insert(String::from("UW4:动"), -338);
-    m.insert(String::from("UW4:助"), -20);
-    m.insert(String::from("UW4:化"), -845);
-    m.insert(String::from("UW4:区"), -1041);
-    m.insert(String::from("UW4:十"), -327);
-    m.insert(String::from("UW4:千"), -890);
-    m.insert(String::from("UW4:华"), -138);
-    m.insert(String::from("UW4:却"), 182);
-    m.insert(String::from("UW4:参"), 331);
-    m.insert(String::from("UW4:又"), 540);
-    m.insert(String::from("UW4:反"), 111);
-    m.insert(String::from("UW4:发"), 583);
-    m.insert(String::from("UW4:口"), -674);
-    m.insert(String::from("UW4:只"), 652);
-    m.insert(String::from("UW4:可"), 303);
-    m.insert(String::from("UW4:台"), 248);
-    m.insert(String::from("UW4:史"), -249);
-    m.insert(String::from("UW4:号"), -312);
-    m.insert(String::from("UW4:各"), 240);
-    m.insert(String::from("UW4:名"), 377);
-    m.insert(String::from("UW4:向"), 126);
-    m.insert(String::from("UW4:否"), -614);
-    m.insert(String::from("UW4:吧"), 237);
-    m.insert(String::from("UW4:员"), -1272);
-    m.insert(String::from("UW4:呢"), 1922);
-    m.insert(String::from("UW4:周"), 296);
-    m.insert(String::from("UW4:和"), 1407);
-    m.insert(String::from("UW4:品"), -427);
-    m.insert(String::from("UW4:啊"), 729);
-    m.insert(String::from("UW4:四"), -240);
-    m.insert(String::from("UW4:回"), 127);
-    m.insert(String::from("UW4:因"), 23);
-    m.insert(String::from("UW4:团"), -290);
-    m.insert(String::from("UW4:国"), -65);
-    m.insert(String::from("UW4:在"), 1071);
-    m.insert(String::from("UW4:地"), 174);
-    m.insert(String::from("UW4:场"), -392);
-    m.insert(String::from("UW4:坦"), -204);
-    m.insert(String::from("UW4:型"), -325);
-    m.insert(String::from("UW4:城"), 6);
-    m.insert(String::from("UW4:增"), 600);
-    m.insert(String::from("UW4:士"), -503);
-    m.insert(String::from("UW4:多"), -79);
-    m.insert(String::from("UW4:大"), 298);
-    m.insert(String::from("UW4:天"), 42);
-    m.insert(String::from("UW4:太"), 49);
-    m.insert(String::from("UW4:夫"), -236);
-    m.insert(String::from("UW4:她"), 233);
-    m.insert(String::from("UW4:好"), 227);
-    m.insert(String::from("UW4:子"), -995);
-    m.insert(String::from("UW4:学"), -40);
-    m.insert(String::from("UW4:它"), 487);
-    m.insert(String::from("UW4:完"), 444);
-    m.insert(String::from("UW4:定"), -596);
-    m.insert(String::from("UW4:宾"), -358);
-    m.insert(String::from("UW4:察"), -26);
-    m.insert(String::from("UW4:对"), 730);
-    m.insert(String::from("UW4:将"), 1089);
-    m.insert(String::from("UW4:小"), 274);
-    m.insert(String::from("UW4:尔"), -1519);
-    m.insert(String::from("UW4:就"), 1275);
-    m.insert(String::from("UW4:尼"), -853);
-    m.insert(String::from("UW4:局"), -598);
-    m.insert(String::from("UW4:届"), 335);
-    m.insert(String::from("UW4:展"), -116);
-    m.insert(String::from("UW4:岁"), 1085);
-    m.insert(String::from("UW4:州"), -309);
-    m.insert(String::from("UW4:工"), 262);
-    m.insert(String::from("UW4:己"), -418);
-    m.insert(String::from("UW4:已"), 997);
-    m.insert(String::from("UW4:市"), -218);
-    m.insert(String::from("UW4:师"), -164);
-    m.insert(String::from("UW4:常"), -36);
-    m.insert(String::from("UW4:平"), -227);
-    m.insert(String::from("UW4:年"), -33);
-    m.insert(String::from("UW4:并"), 445);
-    m.insert(String::from("UW4:广"), 6);
-    m.insert(String::from("UW4:应"), 62);
-    m.insert(String::from("UW4:底"), -29);
-    m.insert(String::from("UW4:府"), -779);
-    m.insert(String::from("UW4:建"), 118);
-    m.insert(String::from("UW4:开"), 520);
-    m.insert(String::from("UW4:式"), -764);
-    m.insert(String::from("UW4:当"), 410);
-    m.insert(String::from("UW4:待"), -66);
-    m.insert(String::from("UW4:很"), 843);
-    m.insert(String::from("UW4:德"), -270);
-    m.insert(String::from("UW4:心"), -315);
-    m.insert(String::from("UW4:性"), -1548);
-    m.insert(String::from("UW4:总"), 1151);
-    m.insert(String::from("UW4:想"), 326);
-    m.insert(String::from("UW4:意"), -229);
-    m.insert(String::from("UW4:我"), 1529);
-    m.insert(String::from("UW4:或"), 360);
-    m.insert(String::from("UW4:打"), 58);
-    m.insert(String::from("UW4:批"), 226);
-    m.insert(String::from("UW4:把"), 546);
-    m.insert(String::from("UW4:投"), 865);
-    m.insert(String::from("UW4:拉"), -841);
-    m.insert(String::from("UW4:指"), 404);
-    m.insert(String::from("UW4:提"), 688);
-    m.insert(String::from("UW4:支"), 199);
-    m.insert(String::from("UW4:改"), 18);
-    m.insert(String::from("UW4:政"), 138);
-    m.insert(String::from("UW4:教"), 22);
-    m.insert(String::from("UW4:斯"), -1623);
-    m.insert(String::from("UW4:新"), 552);
-    m.insert(String::from("UW4:方"), -206);
-    m.insert(String::from("UW4:族"), -348);
-    m.insert(String::from("UW4:日"), -218);
-    m.insert(String::from("UW4:明"), -203);
-    m.insert(String::from("UW4:易"), -511);
-    m.insert(String::from("UW4:是"), 702);
-    m.insert(String::from("UW4:更"), 584);
-    m.insert(String::from("UW4:曾"), 105);
-    m.insert(String::from("UW4:最"), 1344);
-    m.insert(String::from("UW4:月"), -427);
-    m.insert(String::from("UW4:有"), 597);
-    m.insert(String::from("UW4:望"), -204);
-    m.insert(String::from("UW4:期"), -232);
-    m.insert(String::from("UW4:术"), -80);
-    m.insert(String::from("UW4:权"), -464);
-    m.insert(String::from("UW4:条"), 168);
-    m.insert(String::from("UW4:来"), -35);
-    m.insert(String::from("UW4:林"), -135);
-    m.insert(String::from("UW4:果"), -545);
-    m.insert(String::from("UW4:样"), -1579);
-    m.insert(String::from("UW4:格"), -444);
-    m.insert(String::from("UW4:案"), -64);
-    m.insert(String::from("UW4:次"), 632);
-    m.insert(String::from("UW4:止"), -239);
-    m.insert(String::from("UW4:正"), 40);
-    m.insert(String::from("UW4:步"), -313);
-    m.insert(String::from("UW4:比"), 50);
-    m.insert(String::from("UW4:民"), -239);
-    m.insert(String::from("UW4:气"), -15);
-    m.insert(String::from("UW4:水"), 37);
-    m.insert(String::from("UW4:求"), -543);
-    m.insert(String::from("UW4:没"), 573);
-    m.insert(String::from("UW4:法"), -159);
-    m.insert(String::from("UW4:洲"), -395);
-    m.insert(String::from("UW4:派"), -263);
-    m.insert(String::from("UW4:济"), -432);
-    m.insert(String::from("UW4:海"), -171);
-    m.insert(String::from("UW4:湾"), -1145);
-    m.insert(String::from("UW4:源"), -274);
-    m.insert(String::from("UW4:点"), -783);
-    m.insert(String::from("UW4:然"), -991);
-    m.insert(String::from("UW4:物"), -143);
-    m.insert(String::from("UW4:特"), -12);
-    m.insert(String::from("UW4:率"), -764);
-    m.insert(String::from("UW4:球"), -159);
-    m.insert(String::from("UW4:理"), -394);
-    m.insert(String::from("UW4:生"), -156);
-    m.insert(String::from("UW4:用"), -131);
-    m.insert(String::from("UW4:电"), 893);
-    m.insert(String::from("UW4:界"), -1097);
-    m.insert(String::from("UW4:百"), -551);
-    m.insert(String::from("UW4:的"), 2695);
-    m.insert(String::from("UW4:相"), 75);
-    m.insert(String::from("UW4:省"), -248);
-    m.insert(String::from("UW4:看"), 564);
-    m.insert(String::from("UW4:真"), 20);
-    m.insert(String::from("UW4:着"), 626);
-    m.insert(String::from("UW4:研"), 100);
-    m.insert(String::from("UW4:确"), -390);
-    m.insert(String::from("UW4:示"), -240);
-    m.insert(String::from("UW4:社"), -138);
-    m.insert(String::from("UW4:种"), 870);
-    m.insert(String::from("UW4:程"), -127);
-    m.insert(String::from("UW4:站"), -139);
-    m.insert(String::from("UW4:第"), 834);
-    m.insert(String::from("UW4:等"), 867);
-    m.insert(String::from("UW4:系"), -129);
-    m.insert(String::from("UW4:经"), 40);
-    m.insert(String::from("UW4:结"), 63);
-    m.insert(String::from("UW4:给"), 117);
-    m.insert(String::from("UW4:统"), -91);
-    m.insert(String::from("UW4:续"), -444);
-    m.insert(String::from("UW4:维"), -108);
-    m.insert(String::from("UW4:罗"), -58);
-    m.insert(String::from("UW4:美"), 913);
-    m.insert(String::from("UW4:老"), 559);
-    m.insert(String::from("UW4:者"), -1289);
-    m.insert(String::from("UW4:联"), 203);
-    m.insert(String::from("UW4:育"), -115);
-    m.insert(String::from("UW4:能"), 655);
-    m.insert(String::from("UW4:自"), 101);
-    m.insert(String::from("UW4:舍"), -3);
-    m.insert(String::from("UW4:色"), -682);
-    m.insert(String::from("UW4:英"), 402);
-    m.insert(String::from("UW4:行"), -185);
-    m.insert(String::from("UW4:被"), 1211);
-    m.insert(String::from("UW4:要"), 241);
-    m.insert(String::from("UW4:规"), 110);
-    m.insert(String::from("UW4:认"), 313);
-    m.insert(String::from("UW4:让"), 550);
-    m.insert(String::from("UW4:议"), -327);
-    m.insert(String::from("UW4:记"), 261);
-    m.insert(String::from("UW4:讲"), 187);
-    m.insert(String::from("UW4:论"), -293);
-    m.insert(String::from("UW4:话"), -478);
-    m.insert(String::from("UW4:说"), 505);
-    m.insert(String::from("UW4:费"), -436);
-    m.insert(String::from("UW4:赛"), -26);
-    m.insert(String::from("UW4:跟"), 396);
-    m.insert(String::from("UW4:车"), -292);
-    m.insert(String::from("UW4:过"), 128);
-    m.insert(String::from("UW4:还"), 1094);
-    m.insert(String::from("UW4:这"), 2205);
-    m.insert(String::from("UW4:进"), 276);
-    m.insert(String::from("UW4:通"), 90);
-    m.insert(String::from("UW4:道"), -604);
-    m.insert(String::from("UW4:那"), 312);
-    m.insert(String::from("UW4:部"), -290);
-    m.insert(String::from("UW4:都"), 1033);
-    m.insert(String::from("UW4:重"), 194);
-    m.insert(String::from("UW4:野"), -262);
-    m.insert(String::from("UW4:量"), -646);
-    m.insert(String::from("UW4:钱"), 584);
-    m.insert(String::from("UW4:长"), -431);
-    m.insert(String::from("UW4:门"), -485);
-    m.insert(String::from("UW4:间"), -373);
-    m.insert(String::from("UW4:闻"), -373);
-    m.insert(String::from("UW4:队"), -324);
-    m.insert(String::from("UW4:际"), -436);
-    m.insert(String::from("UW4:院"), -989);
-    m.insert(String::from("UW4:需"), 291);
-    m.insert(String::from("UW4:非"), 122);
-    m.insert(String::from("UW4:面"), -412);
-    m.insert(String::from("UW4:项"), 973);
-    m.insert(String::from("UW4:顿"), -786);
-    m.insert(String::from("UW4:领"), 209);
-    m.insert(String::from("UW4:题"), -210);
-    m.insert(String::from("UW4:额"), -452);
-    m.insert(String::from("UW4:馆"), -316);
-    m.insert(String::from("UW4:首"), 455);
-    m.insert(String::from("UW4:高"), 500);
-    m.insert(String::from("UW4:！"), -1143);
-    m.insert(String::from("UW4:＂"), -1864);
-    m.insert(String::from("UW4:（"), 3081);
-    m.insert(String::from("UW4:）"), -2234);
-    m.insert(String::from("UW4:，"), -4925);
-    m.insert(String::from("UW4:．"), -2017);
-    m.insert(String::from("UW4:０"), -788);
-    m.insert(String::from("UW4:１"), 478);
-    m.insert(String::from("UW4:２"), 72);
-    m.insert(String::from("UW4:："), -2002);
-    m.insert(String::from("UW4:；"), -1643);
-    m.insert(String::from("UW4:＜"), 588);
-    m.insert(String::from("UW4:？"), -1862);
-    m.insert(String::from("UW4:［"), 135);
-    m.insert(String::from("UW5:”"), -3);
-    m.insert(String::from("UW5:、"), -50);
-    m.insert(String::from("UW5:。"), -109);
-    m.insert(String::from("UW5:《"), 74);
-    m.insert(String::from("UW5:「"), 166);
-    m.insert(String::from("UW5:一"), -28);
-    m.insert(String::from("UW5:万"), -54);
-    m.insert(String::from("UW5:三"), -269);
-    m.insert(String::from("UW5:上"), -263);
-    m.insert(String::from("UW5:不"), -234);
-    m.insert(String::from("UW5:与"), -588);
-    m.insert(String::from("UW5:业"), 481);
-    m.insert(String::from("UW5:个"), 304);
-    m.insert(String::from("UW5:中"), -336);
-    m.insert(String::from("UW5:主"), -85);
-    m.insert(String::from("UW5:么"), 264);
-    m.insert(String::from("UW5:之"), -494);
-    m.insert(String::from("UW5:九"), -69);
-    m.insert(String::from("UW5:也"), -766);
-    m.insert(String::from("UW5:了"), -459);
-    m.insert(String::from("UW5:争"), 42);
-    m.insert(String::from("UW5:二"), -208);
-    m.insert(String::from("UW5:五"), -134);
-    m.insert(String::from("UW5:亚"), -467);
-    m.insert(String::from("UW5:些"), 343);
-    m.insert(String::from("UW5:产"), 68);
-    m.insert(String::from("UW5:人"), -271);
-    m.insert(String::from("UW5:亿"), -214);
-    m.insert(String::from("UW5:什"), 320);
-    m.insert(String::from("UW5:从"), -202);
-    m.insert(String::from("UW5:他"), -104);
-    m.insert(String::from("UW5:以"), -303);
-    m.insert(String::from("UW5:件"), 410);
-    m.insert(String::from("UW5:会"), -3);
-    m.insert(String::from("UW5:体"), 44);
-    m.insert(String::from("UW5:作"), 102);
-    m.insert(String::from("UW5:你"), -84);
-    m.insert(String::from("UW5:克"), -361);
-    m.insert(String::from("UW5:党"), -282);
-    m.insert(String::from("UW5:入"), 165);
-    m.insert(String::from("UW5:八"), -68);
-    m.insert(String::from("UW5:公"), -360);
-    m.insert(String::from("UW5:六"), -19);
-    m.insert(String::from("UW5:共"), -23);
-    m.insert(String::from("UW5:关"), 115);
-    m.insert(String::from("UW5:出"), 79);
-    m.insert(String::from("UW5:别"), 356);
-    m.insert(String::from("UW5:到"), 96);
-    m.insert(String::from("UW5:前"), 136);
-    m.insert(String::from("UW5:副"), -81);
-    m.insert(String::from("UW5:力"), 139);
-    m.insert(String::from("UW5:务"), 1063);
-    m.insert(String::from("UW5:动"), 474);
-    m.insert(String::from("UW5:发"), -364);
-    m.insert(String::from("UW5:口"), 205);
-    m.insert(String::from("UW5:只"), -316);
-    m.insert(String::from("UW5:可"), -87);
-    m.insert(String::from("UW5:员"), 507);
-    m.insert(String::from("UW5:呢"), -848);
-    m.insert(String::from("UW5:和"), -965);
-    m.insert(String::from("UW5:品"), 193);
-    m.insert(String::from("UW5:啊"), -191);
-    m.insert(String::from("UW5:国"), 83);
-    m.insert(String::from("UW5:在"), -494);
-    m.insert(String::from("UW5:地"), -349);
-    m.insert(String::from("UW5:场"), 697);
-    m.insert(String::from("UW5:士"), 159);
-    m.insert(String::from("UW5:备"), 157);
-    m.insert(String::from("UW5:天"), 137);
-    m.insert(String::from("UW5:夫"), -329);
-    m.insert(String::from("UW5:头"), 147);
-    m.insert(String::from("UW5:奇"), -331);
-    m.insert(String::from("UW5:女"), 127);
-    m.insert(String::from("UW5:子"), 121);
-    m.insert(String::from("UW5:学"), 159);
-    m.insert(String::from("UW5:安"), -160);
-    m.insert(String::from("UW5:定"), 519);
-    m.insert(String::from("UW5:对"), -247);
-    m.insert(String::from("UW5:将"), -390);
-    m.insert(String::from("UW5:尔"), -51);
-    m.insert(String::from("UW5:就"), -453);
-    m.insert(String::from("UW5:尼"), -46);
-    m.insert(String::from("UW5:展"), 314);
-    m.insert(String::from("UW5:己"), 540);
-    m.insert(String::from("UW5:已"), -651);
-    m.insert(String::from("UW5:市"), -114);
-    m.insert(String::from("UW5:并"), -52);
-    m.insert(String::from("UW5:度"), 432);
-    m.insert(String::from("UW5:开"), -13);
-    m.insert(String::from("UW5:式"), 29);
-    m.insert(String::from("UW5:当"), -47);
-    m.insert(String::from("UW5:得"), 408);
-    m.insert(String::from("UW5:性"), -171);
-    m.insert(String::from("UW5:总"), -454);
-    m.insert(String::from("UW5:意"), 58);
-    m.insert(String::from("UW5:我"), -274);
-    m.insert(String::from("UW5:或"), -515);
-    m.insert(String::from("UW5:所"), -250);
-    m.insert(String::from("UW5:投"), -157);
-    m.insert(String::from("UW5:持"), 123);
-    m.insert(String::from("UW5:指"), -340);
-    m.insert(String::from("UW5:提"), -140);
-    m.insert(String::from("UW5:教"), -93);
-    m.insert(String::from("UW5:斯"), -365);
-    m.insert(String::from("UW5:新"), -130);
-    m.insert(String::from("UW5:方"), 148);
-    m.insert(String::from("UW5:日"), -94);
-    m.insert(String::from("UW5:时"), -124);
-    m.insert(String::from("UW5:易"), 256);
-    m.insert(String::from("UW5:是"), -510);
-    m.insert(String::from("UW5:更"), -201);
-    m.insert(String::from("UW5:最"), -164);
-    m.insert(String::from("UW5:月"), 437);
-    m.insert(String::from("UW5:有"), -101);
-    m.insert(String::from("UW5:期"), 467);
-    m.insert(String::from("UW5:本"), 241);
-    m.insert(String::from("UW5:术"), 279);
-    m.insert(String::from("UW5:来"), 109);
-    m.insert(String::from("UW5:果"), 242);
-    m.insert(String::from("UW5:次"), 6);
-    m.insert(String::from("UW5:比"), -44);
-    m.insert(String::from("UW5:民"), 135);
-    m.insert(String::from("UW5:没"), -123);
-    m.insert(String::from("UW5:治"), 9);
-    m.insert(String::from("UW5:济"), 451);
-    m.insert(String::from("UW5:源"), 9);
-    m.insert(String::from("UW5:特"), -409);
-    m.insert(String::from("UW5:理"), 119);
-    m.insert(String::from("UW5:生"), 18);
-    m.insert(String::from("UW5:用"), 219);
-    m.insert(String::from("UW5:电"), -134);
-    m.insert(String::from("UW5:界"), 9);
-    m.insert(String::from("UW5:的"), -909);
-    m.insert(String::from("UW5:研"), -82);
-    m.insert(String::from("UW5:示"), 804);
-    m.insert(String::from("UW5:社"), -355);
-    m.insert(String::from("UW5:程"), 59);
-    m.insert(String::from("UW5:第"), -20);
-    m.insert(String::from("UW5:等"), -704);
-    m.insert(String::from("UW5:续"), 277);
-    m.insert(String::from("UW5:维"), -384);
-    m.insert(String::from("UW5:而"), -316);
-    m.insert(String::from("UW5:行"), 434);
-    m.insert(String::from("UW5:被"), -94);
-    m.insert(String::from("UW5:议"), 407);
-    m.insert(String::from("UW5:说"), -853);
-    m.insert(String::from("UW5:资"), 152);
-    m.insert(String::from("UW5:车"), 20);
-    m.insert(String::from("UW5:还"), -655);
-    m.insert(String::from("UW5:这"), -467);
-    m.insert(String::from("UW5:道"), 296);
-    m.insert(String::from("UW5:那"), -252);
-    m.insert(String::from("UW5:部"), -146);
-    m.insert(String::from("UW5:都"), -595);
-    m.insert(String::from("UW5:里"), -189);
-    m.insert(String::from("UW5:量"), 98);
-    m.insert(String::from("UW5:长"), 149);
-    m.insert(String::from("UW5:门"), 493);
-    m.insert(String::from("UW5:间"), 175);
-    m.insert(String::from("UW5:阵"), 416);
-    m.insert(String::from("UW5:际"), 746);
-    m.insert(String::from("UW5:非"), -153);
-    m.insert(String::from("UW5:面"), 286);
-    m.insert(String::from("UW5:领"), -47);
-    m.insert(String::from("UW5:题"), 602);
-    m.insert(String::from("UW5:）"), -586);
-    m.insert(String::from("UW5:，"), -189);
-    m.insert(String::from("UW5:／"), 6);
-    m.insert(String::from("UW5:３"), 12);
-    m.insert(String::from("UW5:；"), -60);
-    m.insert(String::from("UW5:ｕ"), 91);
-    m.insert(String::from("UW5:ｗ"), 377);
-    m.insert(String::from("UW6:·"), -6);
-    m.insert(String::from("UW6:。"), 8);
-    m.insert(String::from("UW6:」"), -64);
-    m.insert(String::from("UW6:一"), 99);
-    m.insert(String::from("UW6:三"), 3);
-    m.insert(String::from("UW6:上"), 146);
-    m.insert(String::from("UW6:不"), 119);
-    m.insert(String::from("UW6:与"), 103);
-    m.insert(String::from("UW6:业"), -265);
-    m.insert(String::from("UW6:两"), 171);
-    m.insert(String::from("UW6:中"), 153);
-    m.insert(String::from("UW6:之"), 708);
-    m.insert(String::from("UW6:也"), 37);
-    m.insert(String::from("UW6:了"), 482);
-    m.insert(String::from("UW6:人"), 30);
-    m.insert(String::from("UW6:亿"), -167);
-    m.insert(String::from("UW6:他"), 89);
-    m.insert(String::from("UW6:们"), -52);
-    m.insert(String::from("UW6:企"), 12);
-    m.insert(String::from("UW6:会"), 120);
-    m.insert(String::from("UW6:作"), -85);
-    m.insert(String::from("UW6:你"), 114);
-    m.insert(String::from("UW6:元"), -347);
-    m.insert(String::from("UW6:党"), 149);
-    m.insert(String::from("UW6:入"), -78);
-    m.insert(String::from("UW6:公"), 219);
-    m.insert(String::from("UW6:六"), 18);
-    m.insert(String::from("UW6:其"), 31);
-    m.insert(String::from("UW6:出"), -69);
-    m.insert(String::from("UW6:力"), -138);
-    m.insert(String::from("UW6:加"), -51);
-    m.insert(String::from("UW6:务"), -62);
-    m.insert(String::from("UW6:动"), -238);
-    m.insert(String::from("UW6:十"), -54);
-    m.insert(String::from("UW6:司"), -216);
-    m.insert(String::from("UW6:员"), -286);
-    m.insert(String::from("UW6:和"), 231);
-    m.insert(String::from("UW6:国"), 59);
-    m.insert(String::from("UW6:地"), -52);
-    m.insert(String::from("UW6:场"), -256);
-    m.insert(String::from("UW6:士"), -87);
-    m.insert(String::from("UW6:大"), 112);
-    m.insert(String::from("UW6:天"), -167);
-    m.insert(String::from("UW6:定"), -46);
-    m.insert(String::from("UW6:家"), -210);
-    m.insert(String::from("UW6:察"), -196);
-    m.insert(String::from("UW6:就"), 62);
-    m.insert(String::from("UW6:展"), -16);
-    m.insert(String::from("UW6:年"), -110);
-    m.insert(String::from("UW6:府"), -51);
-    m.insert(String::from("UW6:度"), -64);
-    m.insert(String::from("UW6:式"), -175);
-    m.insert(String::from("UW6:性"), 87);
-    m.insert(String::from("UW6:投"), 57);
-    m.insert(String::from("UW6:时"), 12);
-    m.insert(String::from("UW6:易"), -30);
-    m.insert(String::from("UW6:期"), -63);
-    m.insert(String::from("UW6:济"), -284);
-    m.insert(String::from("UW6:然"), -57);
-    m.insert(String::from("UW6:理"), -165);
-    m.insert(String::from("UW6:生"), -162);
-    m.insert(String::from("UW6:的"), 384);
-    m.insert(String::from("UW6:示"), -562);
-    m.insert(String::from("UW6:空"), 3);
-    m.insert(String::from("UW6:等"), 43);
-    m.insert(String::from("UW6:自"), 79);
-    m.insert(String::from("UW6:行"), -285);
-    m.insert(String::from("UW6:议"), -245);
-    m.insert(String::from("UW6:说"), -12);
-    m.insert(String::from("UW6:这"), 190);
-    m.insert(String::from("UW6:里"), 6);
-    m.insert(String::from("UW6:长"), -46);
-    m.insert(String::from("UW6:际"), -99);
-    m.insert(String::from("UW6:雨"), 325);
-    m.insert(String::from("UW6:面"), -78);
-    m.insert(String::from("UW6:（"), -60);
-    m.insert(String::from("UW6:，"), 54);
-    m.insert(String::from("UW6:．"), 118);
-    m.insert(String::from("UW6:："), 12);
-
-    m
-});
+pub static MODEL: crate::models::StaticModel = crate::models::StaticModel::new(&[
+    ("BB1:120120", 169),
+    ("BB1:162120", 254),
+    ("BB1:162162", -189),
+    ("BB2:001001", -1251),
+    ("BB2:107120", -121),
+    ("BB2:120120", -50),
+    ("BB2:162120", 159),
+    ("BB2:162162", -642),
+    ("BB3:120120", 735),
+    ("BB3:162162", 601),
+    ("BP1:BB", -2),
+    ("BP1:OO", -63),
+    ("BP1:UB", -61),
+    ("BP1:UO", 26),
+    ("BP2:BB", -86),
+    ("BP2:OB", -30),
+    ("BP2:OO", 53),
+    ("BQ1:B120162", 24),
+    ("BQ1:B162120", 71),
+    ("BQ2:B120120", -17),
+    ("BQ2:B162120", 65),
+    ("BQ2:U107120", -44),
+    ("BQ2:U120162", 183),
+    ("BQ3:B162162", 59),
+    ("BQ3:O072120", -104),
+    ("BQ3:O120120", 61),
+    ("BQ3:O120162", 514),
+    ("BQ3:O162162", -130),
+    ("BQ4:B120120", -112),
+    ("BQ4:B162120", 148),
+    ("BQ4:O107120", 12),
+    ("BQ4:O120107", -76),
+    ("BQ4:O120120", -48),
+    ("BQ4:O162120", 169),
+    ("BQ4:O162162", -417),
+    ("BQ4:U107120", -373),
+    ("BW1:一万", -282),
+    ("BW1:一九", -775),
+    ("BW1:一千", -249),
+    ("BW1:一百", -72),
+    ("BW1:不得", -535),
+    ("BW1:中国", 665),
+    ("BW1:了一", 393),
+    ("BW1:以色", -200),
+    ("BW1:全国", 423),
+    ("BW1:共和", -2243),
+    ("BW1:分之", -2105),
+    ("BW1:加拿", -544),
+    ("BW1:十一", -337),
+    ("BW1:反对", -422),
+    ("BW1:台湾", 274),
+    ("BW1:国之", 460),
+    ("BW1:国际", 358),
+    ("BW1:委员", -221),
+    ("BW1:已经", 782),
+    ("BW1:平方", -220),
+    ("BW1:总统", 503),
+    ("BW1:星期", -476),
+    ("BW1:是一", 171),
+    ("BW1:有人", 124),
+    ("BW1:的一", 1030),
+    ("BW1:的人", 240),
+    ("BW1:的新", 271),
+    ("BW1:经济", 29),
+    ("BW1:美国", 202),
+    ("BW1:越来", -197),
+    ("BW1:这样", -517),
+    ("BW1:进行", 337),
+    ("BW1:高行", -36),
+    ("BW1:，并", 405),
+    ("BW1:，而", 113),
+    ("BW2:一个", 1119),
+    ("BW2:一九", -448),
+    ("BW2:一百", -29),
+    ("BW2:一直", -36),
+    ("BW2:万人", 9),
+    ("BW2:三十", -253),
+    ("BW2:上海", -165),
+    ("BW2:不仅", -97),
+    ("BW2:不会", 935),
+    ("BW2:不断", -26),
+    ("BW2:不是", 146),
+    ("BW2:不过", -842),
+    ("BW2:个一", -42),
+    ("BW2:个人", -121),
+    ("BW2:中国", -1912),
+    ("BW2:为了", -3239),
+    ("BW2:为什", -876),
+    ("BW2:之一", -545),
+    ("BW2:之后", -256),
+    ("BW2:也许", -198),
+    ("BW2:了解", -2123),
+    ("BW2:二十", -410),
+    ("BW2:人民", -796),
+    ("BW2:亿元", 104),
+    ("BW2:令人", 18),
+    ("BW2:以上", -345),
+    ("BW2:以及", -726),
+    ("BW2:会议", -252),
+    ("BW2:但是", -2375),
+    ("BW2:全国", 387),
+    ("BW2:共和", -830),
+    ("BW2:其中", -836),
+    ("BW2:其他", -1244),
+    ("BW2:出口", -54),
+    ("BW2:分之", -1773),
+    ("BW2:十一", -785),
+    ("BW2:十五", -26),
+    ("BW2:十多", -86),
+    ("BW2:十年", 422),
+    ("BW2:去年", -484),
+    ("BW2:反对", -69),
+    ("BW2:可以", -1035),
+    ("BW2:可能", -1368),
+    ("BW2:合国", -855),
+    ("BW2:同时", -395),
+    ("BW2:和平", -703),
+    ("BW2:因为", -690),
+    ("BW2:国人", -878),
+    ("BW2:国家", -1038),
+    ("BW2:国际", -461),
+    ("BW2:在在", -1042),
+    ("BW2:如说", -63),
+    ("BW2:委会", -23),
+    ("BW2:对于", -330),
+    ("BW2:导人", -266),
+    ("BW2:就是", -683),
+    ("BW2:就说", -45),
+    ("BW2:已经", -2119),
+    ("BW2:年代", -939),
+    ("BW2:应该", -141),
+    ("BW2:很多", -824),
+    ("BW2:总统", -502),
+    ("BW2:成为", -264),
+    ("BW2:所以", -593),
+    ("BW2:所有", -577),
+    ("BW2:方面", -305),
+    ("BW2:时候", -760),
+    ("BW2:时间", -82),
+    ("BW2:是否", -1124),
+    ("BW2:最后", -129),
+    ("BW2:月份", -261),
+    ("BW2:有关", -799),
+    ("BW2:有的", -2274),
+    ("BW2:正在", -54),
+    ("BW2:民币", -20),
+    ("BW2:没有", -1292),
+    ("BW2:现在", -1370),
+    ("BW2:的海", -822),
+    ("BW2:的话", -1643),
+    ("BW2:目的", -294),
+    ("BW2:真的", -1201),
+    ("BW2:社会", -230),
+    ("BW2:等等", -36),
+    ("BW2:美元", -80),
+    ("BW2:美国", -967),
+    ("BW2:能够", -989),
+    ("BW2:要求", -718),
+    ("BW2:还是", -1298),
+    ("BW2:这个", -2368),
+    ("BW2:这些", -442),
+    ("BW2:这里", -769),
+    ("BW2:那个", -999),
+    ("BW2:部分", -51),
+    ("BW2:问题", -226),
+    ("BW2:际上", -285),
+    ("BW2:除了", -1317),
+    ("BW2:非常", -177),
+    ("BW2:香港", -377),
+    ("BW2:，一", -448),
+    ("BW2:０多", -156),
+    ("BW3:一个", 62),
+    ("BW3:上，", 46),
+    ("BW3:中国", 200),
+    ("BW3:主义", -1817),
+    ("BW3:二十", 52),
+    ("BW3:人民", 600),
+    ("BW3:以上", 103),
+    ("BW3:公司", 287),
+    ("BW3:共和", 208),
+    ("BW3:关系", 578),
+    ("BW3:其他", 51),
+    ("BW3:去年", 206),
+    ("BW3:可以", 597),
+    ("BW3:可能", 129),
+    ("BW3:台湾", 490),
+    ("BW3:后，", 211),
+    ("BW3:国家", 1054),
+    ("BW3:地区", 220),
+    ("BW3:地，", -527),
+    ("BW3:多的", 575),
+    ("BW3:多，", 96),
+    ("BW3:大的", 473),
+    ("BW3:好的", 242),
+    ("BW3:工作", 111),
+    ("BW3:年的", 493),
+    ("BW3:年，", 432),
+    ("BW3:成为", 51),
+    ("BW3:政府", 634),
+    ("BW3:教授", 23),
+    ("BW3:时，", 641),
+    ("BW3:是说", -264),
+    ("BW3:来说", 246),
+    ("BW3:现在", 415),
+    ("BW3:百分", 371),
+    ("BW3:社会", 482),
+    ("BW3:经济", 313),
+    ("BW3:美国", 78),
+    ("BW3:行政", 12),
+    ("BW3:说，", 585),
+    ("BW3:说：", 353),
+    ("BW3:进行", 97),
+    ("BW3:里，", 399),
+    ("BW3:香港", 445),
+    ("TB1:107120120", 15),
+    ("TB1:120120120", -131),
+    ("TB1:162162120", 9),
+    ("TB2:107120120", -197),
+    ("TB3:120107120", 149),
+    ("TB3:120120120", 56),
+    ("TB3:120162120", 847),
+    ("TB3:120162162", 1392),
+    ("TB3:162162120", -30),
+    ("TB4:120107120", 86),
+    ("TB4:120120107", 79),
+    ("TB4:120120120", -75),
+    ("TB4:120120162", -35),
+    ("TB4:162120120", -53),
+    ("TB4:162162162", 121),
+    ("TQ1:B120120120", 44),
+    ("TQ1:B120162120", 318),
+    ("TQ1:B120162162", -120),
+    ("TQ1:O107120120", 131),
+    ("TQ1:O120120107", 180),
+    ("TQ1:O120120120", -31),
+    ("TQ1:O120162120", -195),
+    ("TQ1:O120162162", 247),
+    ("TQ1:O162162120", 269),
+    ("TQ2:B120120120", 160),
+    ("TQ2:B120162120", 247),
+    ("TQ2:O120120120", -93),
+    ("TQ2:O120162120", -110),
+    ("TQ2:U120120120", -8),
+    ("TQ3:B107120120", 80),
+    ("TQ3:B120107120", 132),
+    ("TQ3:B120120120", -83),
+    ("TQ3:B162120120", -85),
+    ("TQ3:O120120107", 398),
+    ("TQ3:O120120120", 254),
+    ("TQ3:O120120162", 445),
+    ("TQ3:O162120120", 97),
+    ("TQ3:O162162120", 544),
+    ("TQ4:B120120120", -91),
+    ("TQ4:B120120162", -150),
+    ("TQ4:O120120120", 434),
+    ("TQ4:O120120162", 65),
+    ("TQ4:O120162120", 196),
+    ("TQ4:O162162162", -63),
+    ("TW2:实际上", -63),
+    ("TW2:就是说", -2927),
+    ("TW2:特别是", -602),
+    ("TW2:电视台", -656),
+    ("TW2:这样的", -180),
+    ("TW2:进一步", -1031),
+    ("TW3:在一起", -841),
+    ("TW3:就是说", -39),
+    ("TW4:的话，", -261),
+    ("UB1:162", 55),
+    ("UB2:120", 336),
+    ("UB2:162", 77),
+    ("UB3:001", -59),
+    ("UB3:120", -175),
+    ("UB3:162", 349),
+    ("UB4:001", -119),
+    ("UB4:072", -558),
+    ("UB4:107", -2046),
+    ("UB4:120", -1022),
+    ("UB4:162", -1536),
+    ("UB5:107", -260),
+    ("UB5:120", -47),
+    ("UB5:162", 67),
+    ("UB6:120", -191),
+    ("UB6:162", 32),
+    ("UP1:B", 16),
+    ("UP1:O", -49),
+    ("UP2:O", 9),
+    ("UP3:B", -74),
+    ("UP3:O", 771),
+    ("UQ1:B120", 3),
+    ("UQ1:B162", 33),
+    ("UQ1:O120", 15),
+    ("UQ1:O162", -62),
+    ("UQ2:B120", 68),
+    ("UQ2:B162", 43),
+    ("UQ2:O107", 221),
+    ("UQ2:O120", -36),
+    ("UQ3:B072", -210),
+    ("UQ3:B107", -1763),
+    ("UQ3:B120", 377),
+    ("UQ3:B162", 21),
+    ("UQ3:O002", -1155),
+    ("UQ3:O072", 758),
+    ("UQ3:O107", 2487),
+    ("UQ3:O120", -273),
+    ("UW1:、", 35),
+    ("UW1:」", -45),
+    ("UW1:一", -163),
+    ("UW1:三", -13),
+    ("UW1:两", -104),
+    ("UW1:中", -81),
+    ("UW1:主", -54),
+    ("UW1:九", -32),
+    ("UW1:二", -22),
+    ("UW1:人", -19),
+    ("UW1:从", -23),
+    ("UW1:以", 27),
+    ("UW1:任", -90),
+    ("UW1:全", -110),
+    ("UW1:分", -82),
+    ("UW1:到", -135),
+    ("UW1:十", 279),
+    ("UW1:可", 69),
+    ("UW1:各", -71),
+    ("UW1:员", -79),
+    ("UW1:和", -37),
+    ("UW1:在", -51),
+    ("UW1:家", -28),
+    ("UW1:席", -3),
+    ("UW1:是", -230),
+    ("UW1:月", -321),
+    ("UW1:有", 6),
+    ("UW1:正", -61),
+    ("UW1:民", -77),
+    ("UW1:波", -266),
+    ("UW1:温", 241),
+    ("UW1:现", 28),
+    ("UW1:由", -63),
+    ("UW1:百", -63),
+    ("UW1:的", 26),
+    ("UW1:相", -78),
+    ("UW1:社", -12),
+    ("UW1:第", 616),
+    ("UW1:统", -100),
+    ("UW1:者", -70),
+    ("UW1:身", 127),
+    ("UW1:达", -9),
+    ("UW1:这", -83),
+    ("UW1:都", -22),
+    ("UW1:长", -455),
+    ("UW1:９", -276),
+    ("UW2:、", -250),
+    ("UW2:一", 387),
+    ("UW2:下", 39),
+    ("UW2:与", -760),
+    ("UW2:两", 1319),
+    ("UW2:个", -210),
+    ("UW2:中", 32),
+    ("UW2:为", -185),
+    ("UW2:主", 238),
+    ("UW2:么", 197),
+    ("UW2:九", -886),
+    ("UW2:了", -203),
+    ("UW2:二", -214),
+    ("UW2:于", -83),
+    ("UW2:人", 15),
+    ("UW2:从", -456),
+    ("UW2:他", -49),
+    ("UW2:以", -261),
+    ("UW2:伊", -121),
+    ("UW2:伦", 167),
+    ("UW2:你", -81),
+    ("UW2:克", -169),
+    ("UW2:全", 191),
+    ("UW2:公", 345),
+    ("UW2:共", -272),
+    ("UW2:几", 789),
+    ("UW2:出", 7),
+    ("UW2:分", -18),
+    ("UW2:到", -54),
+    ("UW2:副", -881),
+    ("UW2:加", -70),
+    ("UW2:务", 9),
+    ("UW2:势", -20),
+    ("UW2:反", -194),
+    ("UW2:发", 41),
+    ("UW2:可", 80),
+    ("UW2:台", 27),
+    ("UW2:各", 310),
+    ("UW2:名", -152),
+    ("UW2:和", -706),
+    ("UW2:国", -57),
+    ("UW2:在", -522),
+    ("UW2:基", -18),
+    ("UW2:多", 276),
+    ("UW2:大", -286),
+    ("UW2:天", -148),
+    ("UW2:奥", -369),
+    ("UW2:委", -154),
+    ("UW2:学", 126),
+    ("UW2:实", -65),
+    ("UW2:对", -42),
+    ("UW2:将", -116),
+    ("UW2:尔", -139),
+    ("UW2:已", 80),
+    ("UW2:巴", -273),
+    ("UW2:常", -6),
+    ("UW2:年", 88),
+    ("UW2:建", 23),
+    ("UW2:开", 288),
+    ("UW2:张", -704),
+    ("UW2:或", -178),
+    ("UW2:所", -195),
+    ("UW2:把", -42),
+    ("UW2:据", -55),
+    ("UW2:提", 162),
+    ("UW2:政", 357),
+    ("UW2:斯", -63),
+    ("UW2:新", -506),
+    ("UW2:无", -392),
+    ("UW2:日", 131),
+    ("UW2:时", 99),
+    ("UW2:星", -249),
+    ("UW2:是", -265),
+    ("UW2:最", 336),
+    ("UW2:有", -197),
+    ("UW2:朱", -129),
+    ("UW2:机", 38),
+    ("UW2:李", -416),
+    ("UW2:次", -189),
+    ("UW2:每", 404),
+    ("UW2:民", 94),
+    ("UW2:波", -160),
+    ("UW2:洛", -242),
+    ("UW2:点", -135),
+    ("UW2:然", 12),
+    ("UW2:现", 38),
+    ("UW2:理", -33),
+    ("UW2:百", -649),
+    ("UW2:的", -811),
+    ("UW2:看", -96),
+    ("UW2:着", -73),
+    ("UW2:研", -92),
+    ("UW2:种", -102),
+    ("UW2:米", -22),
+    ("UW2:经", 146),
+    ("UW2:统", -22),
+    ("UW2:美", 159),
+    ("UW2:老", -106),
+    ("UW2:而", -199),
+    ("UW2:菲", -69),
+    ("UW2:被", -239),
+    ("UW2:让", -27),
+    ("UW2:这", 827),
+    ("UW2:那", 176),
+    ("UW2:阿", -631),
+    ("UW2:际", -9),
+    ("UW2:陈", -640),
+    ("UW2:首", 184),
+    ("UW2:高", -65),
+    ("UW2:（", -485),
+    ("UW2:６", 76),
+    ("UW2:９", -134),
+    ("UW3:“", -1957),
+    ("UW3:”", 564),
+    ("UW3:「", -532),
+    ("UW3:一", 781),
+    ("UW3:万", 67),
+    ("UW3:三", 397),
+    ("UW3:上", 672),
+    ("UW3:下", 90),
+    ("UW3:不", 784),
+    ("UW3:与", 1751),
+    ("UW3:且", 504),
+    ("UW3:世", -395),
+    ("UW3:业", 329),
+    ("UW3:两", 1790),
+    ("UW3:个", 1504),
+    ("UW3:中", 319),
+    ("UW3:为", 1255),
+    ("UW3:主", -636),
+    ("UW3:么", 368),
+    ("UW3:之", 520),
+    ("UW3:九", -824),
+    ("UW3:也", 1947),
+    ("UW3:买", 254),
+    ("UW3:了", 2522),
+    ("UW3:于", 1101),
+    ("UW3:些", 151),
+    ("UW3:交", -444),
+    ("UW3:人", 705),
+    ("UW3:亿", 93),
+    ("UW3:仅", 325),
+    ("UW3:今", -520),
+    ("UW3:仍", 295),
+    ("UW3:从", 1114),
+    ("UW3:他", 2511),
+    ("UW3:令", 333),
+    ("UW3:以", 581),
+    ("UW3:们", 1745),
+    ("UW3:件", 626),
+    ("UW3:会", 1037),
+    ("UW3:但", 1328),
+    ("UW3:位", 1040),
+    ("UW3:你", 2260),
+    ("UW3:使", 369),
+    ("UW3:保", -346),
+    ("UW3:做", 538),
+    ("UW3:像", 624),
+    ("UW3:元", 100),
+    ("UW3:党", 314),
+    ("UW3:入", 12),
+    ("UW3:全", 666),
+    ("UW3:八", -125),
+    ("UW3:公", -1390),
+    ("UW3:六", 70),
+    ("UW3:共", 23),
+    ("UW3:再", 835),
+    ("UW3:几", 746),
+    ("UW3:分", -216),
+    ("UW3:则", 1261),
+    ("UW3:利", -293),
+    ("UW3:到", 1673),
+    ("UW3:前", 613),
+    ("UW3:副", -132),
+    ("UW3:加", -357),
+    ("UW3:务", -120),
+    ("UW3:包", -3),
+    ("UW3:北", -245),
+    ("UW3:区", 456),
+    ("UW3:十", -759),
+    ("UW3:千", -676),
+    ("UW3:华", -157),
+    ("UW3:却", 1493),
+    ("UW3:去", 540),
+    ("UW3:参", -803),
+    ("UW3:又", 1562),
+    ("UW3:及", 1172),
+    ("UW3:反", -104),
+    ("UW3:发", -563),
+    ("UW3:口", 38),
+    ("UW3:只", 493),
+    ("UW3:叫", 18),
+    ("UW3:可", -166),
+    ("UW3:号", 400),
+    ("UW3:各", 1462),
+    ("UW3:合", -352),
+    ("UW3:同", 181),
+    ("UW3:名", 694),
+    ("UW3:后", 598),
+    ("UW3:向", 1208),
+    ("UW3:吨", 250),
+    ("UW3:呃", 440),
+    ("UW3:呢", 1038),
+    ("UW3:和", 1875),
+    ("UW3:啊", 206),
+    ("UW3:四", 223),
+    ("UW3:国", 194),
+    ("UW3:在", 2146),
+    ("UW3:地", 414),
+    ("UW3:场", 378),
+    ("UW3:基", -510),
+    ("UW3:增", -277),
+    ("UW3:多", 750),
+    ("UW3:大", 61),
+    ("UW3:天", 116),
+    ("UW3:太", 666),
+    ("UW3:头", 113),
+    ("UW3:她", 1425),
+    ("UW3:好", 387),
+    ("UW3:如", -226),
+    ("UW3:学", -6),
+    ("UW3:它", 1531),
+    ("UW3:安", -354),
+    ("UW3:定", 142),
+    ("UW3:实", -328),
+    ("UW3:家", 399),
+    ("UW3:对", 1235),
+    ("UW3:导", -439),
+    ("UW3:将", 2002),
+    ("UW3:小", 320),
+    ("UW3:尔", -436),
+    ("UW3:就", 1619),
+    ("UW3:尼", -216),
+    ("UW3:届", 293),
+    ("UW3:岁", 100),
+    ("UW3:工", -331),
+    ("UW3:已", 1430),
+    ("UW3:市", 121),
+    ("UW3:常", 229),
+    ("UW3:年", 1164),
+    ("UW3:并", 607),
+    ("UW3:广", -137),
+    ("UW3:府", 163),
+    ("UW3:度", 565),
+    ("UW3:建", -152),
+    ("UW3:开", -370),
+    ("UW3:很", 1528),
+    ("UW3:得", 398),
+    ("UW3:性", 90),
+    ("UW3:总", -125),
+    ("UW3:您", 244),
+    ("UW3:想", 391),
+    ("UW3:我", 2180),
+    ("UW3:或", 752),
+    ("UW3:所", 449),
+    ("UW3:才", 1356),
+    ("UW3:把", 1647),
+    ("UW3:投", -608),
+    ("UW3:拉", -1146),
+    ("UW3:指", -54),
+    ("UW3:据", 1014),
+    ("UW3:提", -758),
+    ("UW3:支", -9),
+    ("UW3:改", -57),
+    ("UW3:政", -550),
+    ("UW3:敦", 529),
+    ("UW3:文", -294),
+    ("UW3:斯", -757),
+    ("UW3:新", 497),
+    ("UW3:无", -137),
+    ("UW3:时", 348),
+    ("UW3:明", -55),
+    ("UW3:是", 2434),
+    ("UW3:更", 1322),
+    ("UW3:曾", 324),
+    ("UW3:最", 865),
+    ("UW3:月", 827),
+    ("UW3:有", 1126),
+    ("UW3:未", 93),
+    ("UW3:本", 294),
+    ("UW3:机", -52),
+    ("UW3:条", 124),
+    ("UW3:来", 674),
+    ("UW3:果", 154),
+    ("UW3:次", 917),
+    ("UW3:此", 581),
+    ("UW3:段", 9),
+    ("UW3:每", 1372),
+    ("UW3:民", -18),
+    ("UW3:泽", -48),
+    ("UW3:洛", -222),
+    ("UW3:港", 291),
+    ("UW3:湾", 54),
+    ("UW3:点", -265),
+    ("UW3:特", -636),
+    ("UW3:用", 677),
+    ("UW3:由", 986),
+    ("UW3:电", -479),
+    ("UW3:界", 41),
+    ("UW3:百", -850),
+    ("UW3:的", 3511),
+    ("UW3:目", -521),
+    ("UW3:相", -566),
+    ("UW3:省", 726),
+    ("UW3:看", 36),
+    ("UW3:着", 1472),
+    ("UW3:研", -254),
+    ("UW3:种", 708),
+    ("UW3:科", -663),
+    ("UW3:站", 95),
+    ("UW3:第", -1365),
+    ("UW3:等", 1313),
+    ("UW3:约", 330),
+    ("UW3:经", -410),
+    ("UW3:给", 1258),
+    ("UW3:维", -589),
+    ("UW3:罗", -1172),
+    ("UW3:美", -137),
+    ("UW3:者", 699),
+    ("UW3:而", 1176),
+    ("UW3:联", -599),
+    ("UW3:能", 1071),
+    ("UW3:至", 207),
+    ("UW3:表", -242),
+    ("UW3:被", 1550),
+    ("UW3:西", -291),
+    ("UW3:要", 1614),
+    ("UW3:规", -238),
+    ("UW3:认", -357),
+    ("UW3:让", 1468),
+    ("UW3:话", 466),
+    ("UW3:该", 1232),
+    ("UW3:说", 950),
+    ("UW3:请", 327),
+    ("UW3:谁", 396),
+    ("UW3:起", 128),
+    ("UW3:越", 66),
+    ("UW3:跟", 694),
+    ("UW3:车", 20),
+    ("UW3:较", 1105),
+    ("UW3:达", 71),
+    ("UW3:过", 523),
+    ("UW3:运", -370),
+    ("UW3:近", 281),
+    ("UW3:还", 1263),
+    ("UW3:这", 1664),
+    ("UW3:进", -642),
+    ("UW3:通", -270),
+    ("UW3:那", 680),
+    ("UW3:都", 1952),
+    ("UW3:里", 209),
+    ("UW3:重", -151),
+    ("UW3:量", 120),
+    ("UW3:钱", 51),
+    ("UW3:长", 369),
+    ("UW3:门", 92),
+    ("UW3:间", 377),
+    ("UW3:阿", -90),
+    ("UW3:院", 351),
+    ("UW3:零", -297),
+    ("UW3:面", 6),
+    ("UW3:项", 12),
+    ("UW3:领", -272),
+    ("UW3:题", 121),
+    ("UW3:首", 18),
+    ("UW3:香", -49),
+    ("UW3:驻", 275),
+    ("UW3:％", 573),
+    ("UW3:（", -1161),
+    ("UW3:＊", -652),
+    ("UW3:，", 2115),
+    ("UW3:／", 346),
+    ("UW3:０", -317),
+    ("UW3:１", -304),
+    ("UW3:：", 82),
+    ("UW3:＞", 722),
+    ("UW4:·", -1328),
+    ("UW4:“", 1596),
+    ("UW4:”", -4374),
+    ("UW4:…", -1586),
+    ("UW4:、", -2639),
+    ("UW4:。", -4099),
+    ("UW4:《", 2533),
+    ("UW4:「", 2806),
+    ("UW4:」", -3274),
+    ("UW4:『", 579),
+    ("UW4:一", 641),
+    ("UW4:七", -16),
+    ("UW4:万", -1359),
+    ("UW4:三", 80),
+    ("UW4:上", 538),
+    ("UW4:下", 76),
+    ("UW4:不", 520),
+    ("UW4:与", 1150),
+    ("UW4:且", -1100),
+    ("UW4:世", 318),
+    ("UW4:业", -1096),
+    ("UW4:两", 897),
+    ("UW4:个", 786),
+    ("UW4:中", 978),
+    ("UW4:久", -69),
+    ("UW4:么", -1985),
+    ("UW4:义", -478),
+    ("UW4:之", 411),
+    ("UW4:乎", -239),
+    ("UW4:九", -852),
+    ("UW4:也", 1984),
+    ("UW4:了", 2088),
+    ("UW4:于", -780),
+    ("UW4:五", -59),
+    ("UW4:亚", -375),
+    ("UW4:些", -1435),
+    ("UW4:人", -69),
+    ("UW4:亿", -1649),
+    ("UW4:今", 212),
+    ("UW4:仍", 220),
+    ("UW4:从", 830),
+    ("UW4:他", 1680),
+    ("UW4:以", 384),
+    ("UW4:们", -4203),
+    ("UW4:份", -24),
+    ("UW4:企", 430),
+    ("UW4:会", -294),
+    ("UW4:传", 62),
+    ("UW4:位", 178),
+    ("UW4:体", -83),
+    ("UW4:何", -144),
+    ("UW4:你", 1405),
+    ("UW4:使", 87),
+    ("UW4:保", 145),
+    ("UW4:倍", 293),
+    ("UW4:做", 1020),
+    ("UW4:儿", -288),
+    ("UW4:元", 385),
+    ("UW4:先", 103),
+    ("UW4:克", -564),
+    ("UW4:党", -470),
+    ("UW4:入", -173),
+    ("UW4:全", 318),
+    ("UW4:八", -134),
+    ("UW4:公", 660),
+    ("UW4:兰", -425),
+    ("UW4:共", 44),
+    ("UW4:内", 30),
+    ("UW4:再", 172),
+    ("UW4:军", -20),
+    ("UW4:几", 187),
+    ("UW4:出", 28),
+    ("UW4:切", -20),
+    ("UW4:列", -406),
+    ("UW4:创", 42),
+    ("UW4:利", -413),
+    ("UW4:到", 65),
+    ("UW4:前", 19),
+    ("UW4:副", 275),
+    ("UW4:力", -940),
+    ("UW4:务", -803),
+    ("UW4:动", -338),
+    ("UW4:助", -20),
+    ("UW4:化", -845),
+    ("UW4:区", -1041),
+    ("UW4:十", -327),
+    ("UW4:千", -890),
+    ("UW4:华", -138),
+    ("UW4:却", 182),
+    ("UW4:参", 331),
+    ("UW4:又", 540),
+    ("UW4:反", 111),
+    ("UW4:发", 583),
+    ("UW4:口", -674),
+    ("UW4:只", 652),
+    ("UW4:可", 303),
+    ("UW4:台", 248),
+    ("UW4:史", -249),
+    ("UW4:号", -312),
+    ("UW4:各", 240),
+    ("UW4:名", 377),
+    ("UW4:向", 126),
+    ("UW4:否", -614),
+    ("UW4:吧", 237),
+    ("UW4:员", -1272),
+    ("UW4:呢", 1922),
+    ("UW4:周", 296),
+    ("UW4:和", 1407),
+    ("UW4:品", -427),
+    ("UW4:啊", 729),
+    ("UW4:四", -240),
+    ("UW4:回", 127),
+    ("UW4:因", 23),
+    ("UW4:团", -290),
+    ("UW4:国", -65),
+    ("UW4:在", 1071),
+    ("UW4:地", 174),
+    ("UW4:场", -392),
+    ("UW4:坦", -204),
+    ("UW4:型", -325),
+    ("UW4:城", 6),
+    ("UW4:增", 600),
+    ("UW4:士", -503),
+    ("UW4:多", -79),
+    ("UW4:大", 298),
+    ("UW4:天", 42),
+    ("UW4:太", 49),
+    ("UW4:夫", -236),
+    ("UW4:她", 233),
+    ("UW4:好", 227),
+    ("UW4:子", -995),
+    ("UW4:学", -40),
+    ("UW4:它", 487),
+    ("UW4:完", 444),
+    ("UW4:定", -596),
+    ("UW4:宾", -358),
+    ("UW4:察", -26),
+    ("UW4:对", 730),
+    ("UW4:将", 1089),
+    ("UW4:小", 274),
+    ("UW4:尔", -1519),
+    ("UW4:就", 1275),
+    ("UW4:尼", -853),
+    ("UW4:局", -598),
+    ("UW4:届", 335),
+    ("UW4:展", -116),
+    ("UW4:岁", 1085),
+    ("UW4:州", -309),
+    ("UW4:工", 262),
+    ("UW4:己", -418),
+    ("UW4:已", 997),
+    ("UW4:市", -218),
+    ("UW4:师", -164),
+    ("UW4:常", -36),
+    ("UW4:平", -227),
+    ("UW4:年", -33),
+    ("UW4:并", 445),
+    ("UW4:广", 6),
+    ("UW4:应", 62),
+    ("UW4:底", -29),
+    ("UW4:府", -779),
+    ("UW4:建", 118),
+    ("UW4:开", 520),
+    ("UW4:式", -764),
+    ("UW4:当", 410),
+    ("UW4:待", -66),
+    ("UW4:很", 843),
+    ("UW4:德", -270),
+    ("UW4:心", -315),
+    ("UW4:性", -1548),
+    ("UW4:总", 1151),
+    ("UW4:想", 326),
+    ("UW4:意", -229),
+    ("UW4:我", 1529),
+    ("UW4:或", 360),
+    ("UW4:打", 58),
+    ("UW4:批", 226),
+    ("UW4:把", 546),
+    ("UW4:投", 865),
+    ("UW4:拉", -841),
+    ("UW4:指", 404),
+    ("UW4:提", 688),
+    ("UW4:支", 199),
+    ("UW4:改", 18),
+    ("UW4:政", 138),
+    ("UW4:教", 22),
+    ("UW4:斯", -1623),
+    ("UW4:新", 552),
+    ("UW4:方", -206),
+    ("UW4:族", -348),
+    ("UW4:日", -218),
+    ("UW4:明", -203),
+    ("UW4:易", -511),
+    ("UW4:是", 702),
+    ("UW4:更", 584),
+    ("UW4:曾", 105),
+    ("UW4:最", 1344),
+    ("UW4:月", -427),
+    ("UW4:有", 597),
+    ("UW4:望", -204),
+    ("UW4:期", -232),
+    ("UW4:术", -80),
+    ("UW4:权", -464),
+    ("UW4:条", 168),
+    ("UW4:来", -35),
+    ("UW4:林", -135),
+    ("UW4:果", -545),
+    ("UW4:样", -1579),
+    ("UW4:格", -444),
+    ("UW4:案", -64),
+    ("UW4:次", 632),
+    ("UW4:止", -239),
+    ("UW4:正", 40),
+    ("UW4:步", -313),
+    ("UW4:比", 50),
+    ("UW4:民", -239),
+    ("UW4:气", -15),
+    ("UW4:水", 37),
+    ("UW4:求", -543),
+    ("UW4:没", 573),
+    ("UW4:法", -159),
+    ("UW4:洲", -395),
+    ("UW4:派", -263),
+    ("UW4:济", -432),
+    ("UW4:海", -171),
+    ("UW4:湾", -1145),
+    ("UW4:源", -274),
+    ("UW4:点", -783),
+    ("UW4:然", -991),
+    ("UW4:物", -143),
+    ("UW4:特", -12),
+    ("UW4:率", -764),
+    ("UW4:球", -159),
+    ("UW4:理", -394),
+    ("UW4:生", -156),
+    ("UW4:用", -131),
+    ("UW4:电", 893),
+    ("UW4:界", -1097),
+    ("UW4:百", -551),
+    ("UW4:的", 2695),
+    ("UW4:相", 75),
+    ("UW4:省", -248),
+    ("UW4:看", 564),
+    ("UW4:真", 20),
+    ("UW4:着", 626),
+    ("UW4:研", 100),
+    ("UW4:确", -390),
+    ("UW4:示", -240),
+    ("UW4:社", -138),
+    ("UW4:种", 870),
+    ("UW4:程", -127),
+    ("UW4:站", -139),
+    ("UW4:第", 834),
+    ("UW4:等", 867),
+    ("UW4:系", -129),
+    ("UW4:经", 40),
+    ("UW4:结", 63),
+    ("UW4:给", 117),
+    ("UW4:统", -91),
+    ("UW4:续", -444),
+    ("UW4:维", -108),
+    ("UW4:罗", -58),
+    ("UW4:美", 913),
+    ("UW4:老", 559),
+    ("UW4:者", -1289),
+    ("UW4:联", 203),
+    ("UW4:育", -115),
+    ("UW4:能", 655),
+    ("UW4:自", 101),
+    ("UW4:舍", -3),
+    ("UW4:色", -682),
+    ("UW4:英", 402),
+    ("UW4:行", -185),
+    ("UW4:被", 1211),
+    ("UW4:要", 241),
+    ("UW4:规", 110),
+    ("UW4:认", 313),
+    ("UW4:让", 550),
+    ("UW4:议", -327),
+    ("UW4:记", 261),
+    ("UW4:讲", 187),
+    ("UW4:论", -293),
+    ("UW4:话", -478),
+    ("UW4:说", 505),
+    ("UW4:费", -436),
+    ("UW4:赛", -26),
+    ("UW4:跟", 396),
+    ("UW4:车", -292),
+    ("UW4:过", 128),
+    ("UW4:还", 1094),
+    ("UW4:这", 2205),
+    ("UW4:进", 276),
+    ("UW4:通", 90),
+    ("UW4:道", -604),
+    ("UW4:那", 312),
+    ("UW4:部", -290),
+    ("UW4:都", 1033),
+    ("UW4:重", 194),
+    ("UW4:野", -262),
+    ("UW4:量", -646),
+    ("UW4:钱", 584),
+    ("UW4:长", -431),
+    ("UW4:门", -485),
+    ("UW4:间", -373),
+    ("UW4:闻", -373),
+    ("UW4:队", -324),
+    ("UW4:际", -436),
+    ("UW4:院", -989),
+    ("UW4:需", 291),
+    ("UW4:非", 122),
+    ("UW4:面", -412),
+    ("UW4:项", 973),
+    ("UW4:顿", -786),
+    ("UW4:领", 209),
+    ("UW4:题", -210),
+    ("UW4:额", -452),
+    ("UW4:馆", -316),
+    ("UW4:首", 455),
+    ("UW4:高", 500),
+    ("UW4:！", -1143),
+    ("UW4:＂", -1864),
+    ("UW4:（", 3081),
+    ("UW4:）", -2234),
+    ("UW4:，", -4925),
+    ("UW4:．", -2017),
+    ("UW4:０", -788),
+    ("UW4:１", 478),
+    ("UW4:２", 72),
+    ("UW4:：", -2002),
+    ("UW4:；", -1643),
+    ("UW4:＜", 588),
+    ("UW4:？", -1862),
+    ("UW4:［", 135),
+    ("UW5:”", -3),
+    ("UW5:、", -50),
+    ("UW5:。", -109),
+    ("UW5:《", 74),
+    ("UW5:「", 166),
+    ("UW5:一", -28),
+    ("UW5:万", -54),
+    ("UW5:三", -269),
+    ("UW5:上", -263),
+    ("UW5:不", -234),
+    ("UW5:与", -588),
+    ("UW5:业", 481),
+    ("UW5:个", 304),
+    ("UW5:中", -336),
+    ("UW5:主", -85),
+    ("UW5:么", 264),
+    ("UW5:之", -494),
+    ("UW5:九", -69),
+    ("UW5:也", -766),
+    ("UW5:了", -459),
+    ("UW5:争", 42),
+    ("UW5:二", -208),
+    ("UW5:五", -134),
+    ("UW5:亚", -467),
+    ("UW5:些", 343),
+    ("UW5:产", 68),
+    ("UW5:人", -271),
+    ("UW5:亿", -214),
+    ("UW5:什", 320),
+    ("UW5:从", -202),
+    ("UW5:他", -104),
+    ("UW5:以", -303),
+    ("UW5:件", 410),
+    ("UW5:会", -3),
+    ("UW5:体", 44),
+    ("UW5:作", 102),
+    ("UW5:你", -84),
+    ("UW5:克", -361),
+    ("UW5:党", -282),
+    ("UW5:入", 165),
+    ("UW5:八", -68),
+    ("UW5:公", -360),
+    ("UW5:六", -19),
+    ("UW5:共", -23),
+    ("UW5:关", 115),
+    ("UW5:出", 79),
+    ("UW5:别", 356),
+    ("UW5:到", 96),
+    ("UW5:前", 136),
+    ("UW5:副", -81),
+    ("UW5:力", 139),
+    ("UW5:务", 1063),
+    ("UW5:动", 474),
+    ("UW5:发", -364),
+    ("UW5:口", 205),
+    ("UW5:只", -316),
+    ("UW5:可", -87),
+    ("UW5:员", 507),
+    ("UW5:呢", -848),
+    ("UW5:和", -965),
+    ("UW5:品", 193),
+    ("UW5:啊", -191),
+    ("UW5:国", 83),
+    ("UW5:在", -494),
+    ("UW5:地", -349),
+    ("UW5:场", 697),
+    ("UW5:士", 159),
+    ("UW5:备", 157),
+    ("UW5:天", 137),
+    ("UW5:夫", -329),
+    ("UW5:头", 147),
+    ("UW5:奇", -331),
+    ("UW5:女", 127),
+    ("UW5:子", 121),
+    ("UW5:学", 159),
+    ("UW5:安", -160),
+    ("UW5:定", 519),
+    ("UW5:对", -247),
+    ("UW5:将", -390),
+    ("UW5:尔", -51),
+    ("UW5:就", -453),
+    ("UW5:尼", -46),
+    ("UW5:展", 314),
+    ("UW5:己", 540),
+    ("UW5:已", -651),
+    ("UW5:市", -114),
+    ("UW5:并", -52),
+    ("UW5:度", 432),
+    ("UW5:开", -13),
+    ("UW5:式", 29),
+    ("UW5:当", -47),
+    ("UW5:得", 408),
+    ("UW5:性", -171),
+    ("UW5:总", -454),
+    ("UW5:意", 58),
+    ("UW5:我", -274),
+    ("UW5:或", -515),
+    ("UW5:所", -250),
+    ("UW5:投", -157),
+    ("UW5:持", 123),
+    ("UW5:指", -340),
+    ("UW5:提", -140),
+    ("UW5:教", -93),
+    ("UW5:斯", -365),
+    ("UW5:新", -130),
+    ("UW5:方", 148),
+    ("UW5:日", -94),
+    ("UW5:时", -124),
+    ("UW5:易", 256),
+    ("UW5:是", -510),
+    ("UW5:更", -201),
+    ("UW5:最", -164),
+    ("UW5:月", 437),
+    ("UW5:有", -101),
+    ("UW5:期", 467),
+    ("UW5:本", 241),
+    ("UW5:术", 279),
+    ("UW5:来", 109),
+    ("UW5:果", 242),
+    ("UW5:次", 6),
+    ("UW5:比", -44),
+    ("UW5:民", 135),
+    ("UW5:没", -123),
+    ("UW5:治", 9),
+    ("UW5:济", 451),
+    ("UW5:源", 9),
+    ("UW5:特", -409),
+    ("UW5:理", 119),
+    ("UW5:生", 18),
+    ("UW5:用", 219),
+    ("UW5:电", -134),
+    ("UW5:界", 9),
+    ("UW5:的", -909),
+    ("UW5:研", -82),
+    ("UW5:示", 804),
+    ("UW5:社", -355),
+    ("UW5:程", 59),
+    ("UW5:第", -20),
+    ("UW5:等", -704),
+    ("UW5:续", 277),
+    ("UW5:维", -384),
+    ("UW5:而", -316),
+    ("UW5:行", 434),
+    ("UW5:被", -94),
+    ("UW5:议", 407),
+    ("UW5:说", -853),
+    ("UW5:资", 152),
+    ("UW5:车", 20),
+    ("UW5:还", -655),
+    ("UW5:这", -467),
+    ("UW5:道", 296),
+    ("UW5:那", -252),
+    ("UW5:部", -146),
+    ("UW5:都", -595),
+    ("UW5:里", -189),
+    ("UW5:量", 98),
+    ("UW5:长", 149),
+    ("UW5:门", 493),
+    ("UW5:间", 175),
+    ("UW5:阵", 416),
+    ("UW5:际", 746),
+    ("UW5:非", -153),
+    ("UW5:面", 286),
+    ("UW5:领", -47),
+    ("UW5:题", 602),
+    ("UW5:）", -586),
+    ("UW5:，", -189),
+    ("UW5:／", 6),
+    ("UW5:３", 12),
+    ("UW5:；", -60),
+    ("UW5:ｕ", 91),
+    ("UW5:ｗ", 377),
+    ("UW6:·", -6),
+    ("UW6:。", 8),
+    ("UW6:」", -64),
+    ("UW6:一", 99),
+    ("UW6:三", 3),
+    ("UW6:上", 146),
+    ("UW6:不", 119),
+    ("UW6:与", 103),
+    ("UW6:业", -265),
+    ("UW6:两", 171),
+    ("UW6:中", 153),
+    ("UW6:之", 708),
+    ("UW6:也", 37),
+    ("UW6:了", 482),
+    ("UW6:人", 30),
+    ("UW6:亿", -167),
+    ("UW6:他", 89),
+    ("UW6:们", -52),
+    ("UW6:企", 12),
+    ("UW6:会", 120),
+    ("UW6:作", -85),
+    ("UW6:你", 114),
+    ("UW6:元", -347),
+    ("UW6:党", 149),
+    ("UW6:入", -78),
+    ("UW6:公", 219),
+    ("UW6:六", 18),
+    ("UW6:其", 31),
+    ("UW6:出", -69),
+    ("UW6:力", -138),
+    ("UW6:加", -51),
+    ("UW6:务", -62),
+    ("UW6:动", -238),
+    ("UW6:十", -54),
+    ("UW6:司", -216),
+    ("UW6:员", -286),
+    ("UW6:和", 231),
+    ("UW6:国", 59),
+    ("UW6:地", -52),
+    ("UW6:场", -256),
+    ("UW6:士", -87),
+    ("UW6:大", 112),
+    ("UW6:天", -167),
+    ("UW6:定", -46),
+    ("UW6:家", -210),
+    ("UW6:察", -196),
+    ("UW6:就", 62),
+    ("UW6:展", -16),
+    ("UW6:年", -110),
+    ("UW6:府", -51),
+    ("UW6:度", -64),
+    ("UW6:式", -175),
+    ("UW6:性", 87),
+    ("UW6:投", 57),
+    ("UW6:时", 12),
+    ("UW6:易", -30),
+    ("UW6:期", -63),
+    ("UW6:济", -284),
+    ("UW6:然", -57),
+    ("UW6:理", -165),
+    ("UW6:生", -162),
+    ("UW6:的", 384),
+    ("UW6:示", -562),
+    ("UW6:空", 3),
+    ("UW6:等", 43),
+    ("UW6:自", 79),
+    ("UW6:行", -285),
+    ("UW6:议", -245),
+    ("UW6:说", -12),
+    ("UW6:这", 190),
+    ("UW6:里", 6),
+    ("UW6:长", -46),
+    ("UW6:际", -99),
+    ("UW6:雨", 325),
+    ("UW6:面", -78),
+    ("UW6:（", -60),
+    ("UW6:，", 54),
+    ("UW6:．", 118),
+    ("UW6:：", 12),
+])
+.with_index(
+    &[
+        5, 2, 1, 15, 64, 206, 85, 40, 1, 10, 18, 35, 7, 18, 1, 10, 451, 179, 27, 221, 1, 141, 186,
+        81, 64, 189, 11, 13, 60, 3, 39, 2, 1, 2, 245, 19, 59, 541, 2, 6, 44, 21, 9, 25, 270, 13, 4,
+        9, 5, 11, 352, 11, 2, 1, 2, 134, 1, 1, 2, 2, 2, 5, 15, 2, 5, 91, 8, 4, 1, 5, 333, 6, 78,
+        76, 2, 70, 105, 2, 15, 9, 32, 0, 9, 3, 3, 8, 314, 17, 34, 208, 192, 60, 9, 1, 522, 26, 37,
+        13, 47, 51, 19, 87, 28, 3, 14, 63, 21, 69, 27, 31, 57, 2, 236, 7, 192, 32, 38, 1, 11, 5,
+        178, 726, 1, 238, 99, 11, 179, 222, 29, 13, 20, 0, 142, 58, 4, 2, 129, 47, 77, 9, 13, 16,
+        118, 3, 86, 123, 133, 409, 1, 35, 17, 7, 3, 27, 341, 718, 26, 13, 34, 9, 122, 26, 1, 2, 30,
+        3, 12, 416, 101, 21, 80, 62, 37, 52, 9, 92, 23, 169, 187, 178, 413, 15, 189, 232, 106, 40,
+        3, 177, 131, 38, 1, 5, 138, 38, 9, 370, 278, 18, 1, 17, 451, 28, 52, 4, 5, 241, 4, 2, 49,
+        5, 98, 20, 289, 4, 26, 946, 492, 52, 437, 11, 213, 626, 16, 16, 79, 119, 86, 3, 55, 2, 42,
+        9, 11, 27, 188, 2, 310, 10, 7, 206, 0, 8, 64, 34, 140, 114, 8, 18, 367, 14, 5, 383, 33,
+        883, 34, 14, 581, 4, 37, 47, 233, 35, 228, 281, 1, 233, 36, 302, 6, 206, 470, 484, 2, 9,
+        30, 1, 78, 3, 485, 3, 290, 17, 437, 51, 1261, 8, 5, 465, 10, 12, 3798, 14, 33, 3, 8, 39,
+        286, 383, 0, 1, 2067, 806, 338, 273, 1, 33, 372, 1507, 264, 253, 286, 19, 158, 4, 803, 145,
+        163, 386, 2336, 4143, 1000, 6, 3264, 253, 227, 884, 46, 42,
+    ],
+    &[
+        999, 544, 69, 890, 107, 97, 753, 1307, 983, 272, 1049, 1170, 354, 64, 284, 492, 865, 259,
+        130, 928, 712, 636, 1092, 334, 1138, 1028, 1309, 1102, 469, 787, 93, 217, 997, 557, 20,
+        1059, 389, 489, 571, 468, 328, 189, 888, 794, 289, 568, 844, 460, 670, 270, 1166, 817, 815,
+        1126, 1255, 1074, 533, 285, 666, 508, 234, 1029, 333, 1164, 181, 295, 828, 456, 1135, 1051,
+        647, 1069, 273, 1251, 559, 388, 851, 303, 99, 1198, 361, 889, 1002, 994, 418, 1184, 868,
+        474, 768, 946, 966, 822, 174, 980, 600, 863, 356, 436, 144, 95, 1041, 1096, 332, 821, 1175,
+        341, 618, 831, 242, 111, 1229, 515, 1261, 276, 154, 21, 287, 346, 1214, 1083, 1003, 55,
+        461, 84, 936, 1047, 797, 229, 61, 1149, 878, 603, 668, 1267, 734, 360, 984, 137, 33, 498,
+        238, 178, 18, 1271, 390, 488, 816, 131, 240, 1209, 649, 419, 203, 359, 343, 1273, 19, 51,
+        463, 922, 1124, 79, 426, 939, 1282, 777, 267, 1116, 962, 897, 528, 631, 1275, 316, 694,
+        176, 949, 342, 1215, 610, 313, 671, 1183, 642, 692, 838, 632, 374, 793, 305, 129, 1021,
+        859, 945, 947, 135, 913, 108, 704, 971, 930, 1143, 1233, 1257, 1285, 1178, 182, 834, 41,
+        141, 974, 552, 298, 11, 304, 85, 842, 314, 1276, 1210, 449, 770, 1103, 380, 1287, 1190,
+        640, 307, 1160, 679, 742, 297, 209, 1153, 931, 109, 317, 319, 1112, 363, 10, 1179, 637, 70,
+        487, 716, 136, 26, 219, 406, 43, 547, 187, 762, 739, 183, 105, 12, 1268, 993, 744, 124,
+        633, 708, 271, 1288, 801, 1151, 405, 905, 113, 964, 256, 685, 425, 535, 676, 1026, 110,
+        717, 549, 595, 1061, 809, 813, 682, 142, 252, 17, 814, 551, 1241, 893, 429, 1148, 991, 441,
+        1075, 963, 310, 867, 564, 1105, 75, 696, 1234, 731, 465, 752, 775, 1147, 879, 205, 1270,
+        1152, 895, 721, 280, 824, 411, 71, 1289, 87, 1111, 776, 1305, 1231, 701, 1024, 524, 68,
+        852, 574, 1063, 1298, 45, 1258, 291, 477, 1161, 16, 1193, 338, 553, 98, 246, 830, 687, 899,
+        567, 953, 530, 576, 1247, 1104, 444, 279, 521, 933, 308, 482, 59, 924, 1078, 707, 513, 973,
+        301, 1114, 1109, 819, 180, 413, 532, 424, 63, 810, 127, 686, 684, 225, 629, 923, 894, 667,
+        483, 542, 384, 231, 358, 479, 402, 848, 91, 83, 88, 886, 1196, 582, 846, 1012, 347, 1299,
+        1025, 385, 832, 1119, 56, 961, 1296, 518, 330, 1046, 555, 293, 958, 995, 663, 30, 90, 1020,
+        1280, 94, 1118, 601, 526, 623, 1250, 395, 162, 1201, 103, 1060, 892, 372, 159, 226, 920,
+        74, 245, 1162, 387, 1146, 420, 1262, 243, 353, 932, 550, 36, 169, 940, 747, 173, 155, 450,
+        573, 1206, 442, 120, 451, 948, 577, 856, 184, 590, 1019, 364, 811, 357, 407, 998, 4, 475,
+        161, 292, 391, 677, 829, 872, 1141, 1, 294, 1235, 713, 884, 1053, 1208, 608, 675, 798,
+        1084, 457, 976, 439, 1195, 849, 853, 1038, 765, 326, 199, 1050, 168, 166, 725, 678, 194,
+        1122, 746, 613, 612, 325, 720, 67, 1189, 282, 1039, 1182, 241, 727, 432, 365, 990, 955,
+        639, 956, 954, 543, 196, 669, 311, 1015, 1238, 1132, 76, 750, 153, 376, 462, 606, 507, 730,
+        1163, 77, 1171, 799, 825, 503, 117, 502, 396, 1139, 1306, 1131, 480, 306, 299, 1009, 1129,
+        378, 664, 883, 165, 464, 807, 1071, 761, 766, 204, 901, 466, 1284, 1120, 1045, 368, 1219,
+        662, 81, 1108, 1027, 812, 1089, 421, 1297, 1217, 100, 873, 827, 561, 1220, 486, 723, 602,
+        925, 840, 586, 132, 1308, 802, 1054, 283, 737, 1057, 369, 1040, 1304, 850, 115, 345, 1010,
+        1156, 880, 534, 1242, 1017, 417, 1213, 1191, 615, 216, 112, 320, 200, 1048, 177, 1106, 367,
+        690, 651, 926, 454, 572, 1125, 598, 337, 255, 599, 756, 741, 803, 223, 1269, 213, 691, 164,
+        7, 399, 900, 705, 9, 443, 312, 1052, 152, 1274, 1150, 645, 841, 558, 581, 652, 1283, 959,
+        1154, 519, 48, 1181, 700, 362, 1263, 251, 861, 1243, 400, 525, 260, 1157, 175, 262, 683,
+        1194, 1031, 1088, 938, 431, 1142, 235, 1253, 1291, 783, 470, 1216, 274, 650, 394, 1121,
+        422, 236, 655, 381, 860, 523, 89, 969, 324, 1068, 778, 882, 634, 780, 163, 106, 72, 408,
+        1091, 1128, 414, 1177, 724, 688, 52, 710, 409, 1245, 1133, 657, 49, 539, 224, 1032, 1212,
+        53, 263, 228, 496, 654, 722, 1016, 658, 870, 545, 1167, 14, 495, 170, 1221, 759, 1077,
+        1087, 1207, 485, 578, 73, 697, 826, 735, 847, 1080, 288, 1225, 268, 1223, 1013, 505, 560,
+        221, 790, 541, 748, 760, 788, 31, 423, 960, 215, 1265, 0, 584, 1165, 478, 150, 302, 1022,
+        197, 622, 718, 82, 921, 1172, 891, 459, 1082, 992, 318, 546, 392, 433, 605, 37, 455, 511,
+        397, 340, 1034, 536, 145, 1093, 427, 186, 1199, 1272, 653, 729, 269, 249, 493, 587, 35,
+        570, 2, 247, 784, 490, 1279, 1117, 192, 122, 156, 625, 659, 148, 767, 350, 27, 607, 446,
+        28, 833, 65, 8, 1018, 751, 50, 935, 386, 383, 179, 646, 428, 445, 537, 699, 1086, 715,
+        1023, 517, 481, 520, 227, 706, 158, 214, 119, 1070, 635, 351, 740, 253, 473, 39, 904, 151,
+        1137, 733, 604, 709, 982, 275, 820, 915, 1130, 563, 25, 210, 680, 1115, 781, 1218, 1244,
+        509, 172, 592, 1227, 211, 1173, 656, 1007, 887, 743, 726, 774, 382, 370, 393, 1168, 476,
+        907, 910, 208, 818, 410, 908, 1081, 198, 1006, 702, 562, 1113, 1211, 719, 855, 638, 458,
+        749, 1295, 978, 711, 1033, 627, 29, 1066, 412, 643, 585, 1290, 371, 42, 1192, 869, 871,
+        614, 290, 779, 874, 143, 348, 1249, 147, 786, 491, 996, 516, 201, 611, 661, 118, 988, 510,
+        977, 583, 15, 1004, 265, 594, 985, 202, 589, 839, 986, 512, 597, 499, 648, 261, 133, 80,
+        1302, 950, 1264, 808, 674, 232, 335, 1159, 138, 379, 1062, 327, 22, 1260, 435, 621, 1256,
+        732, 981, 206, 1095, 1222, 6, 121, 1176, 139, 62, 593, 745, 104, 527, 1205, 681, 845, 278,
+        472, 771, 944, 628, 1202, 1140, 309, 1301, 1090, 877, 47, 5, 134, 494, 195, 556, 672, 934,
+        1005, 795, 471, 1056, 34, 1197, 404, 864, 352, 501, 1185, 24, 1097, 401, 323, 190, 522,
+        1174, 1254, 1230, 620, 805, 1145, 248, 1246, 957, 467, 866, 366, 114, 540, 912, 1186, 1226,
+        1030, 876, 1011, 975, 296, 548, 1252, 791, 3, 754, 951, 13, 1014, 438, 321, 785, 1188, 843,
+        660, 1094, 416, 1073, 738, 942, 1098, 453, 1123, 1085, 237, 989, 430, 38, 92, 315, 580,
+        250, 919, 529, 881, 1224, 437, 1064, 857, 1067, 1187, 1158, 506, 78, 624, 630, 885, 514,
+        909, 86, 1044, 689, 789, 728, 979, 207, 736, 281, 1076, 398, 1100, 906, 837, 644, 1237,
+        929, 764, 403, 32, 1200, 1036, 1136, 46, 23, 239, 375, 157, 806, 193, 579, 149, 1292, 188,
+        191, 1266, 757, 588, 40, 1248, 57, 1303, 911, 758, 1101, 44, 1134, 918, 943, 58, 641, 266,
+        230, 917, 1000, 160, 264, 617, 125, 258, 1300, 336, 447, 244, 952, 1239, 101, 1035, 941,
+        693, 140, 1204, 616, 1042, 497, 504, 126, 773, 1107, 128, 1037, 665, 1294, 835, 254, 714,
+        331, 1277, 914, 902, 538, 1169, 373, 452, 800, 116, 1055, 1065, 257, 96, 916, 146, 1072,
+        565, 772, 792, 1079, 972, 1278, 102, 823, 575, 440, 54, 1008, 1110, 66, 591, 967, 854, 698,
+        1259, 212, 500, 927, 171, 60, 434, 836, 987, 804, 695, 484, 220, 965, 796, 896, 755, 703,
+        377, 1203, 903, 531, 1127, 448, 566, 300, 875, 355, 349, 415, 222, 185, 609, 1043, 554,
+        1099, 329, 123, 286, 619, 673, 1236, 858, 1180, 862, 1155, 322, 898, 218, 233, 167, 1232,
+        596, 1240, 1144, 569, 277, 1228, 970, 937, 1281, 968, 1001, 769, 1286, 1293, 1058, 626,
+        782, 339, 763, 344,
+    ],
+);
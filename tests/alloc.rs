@@ -0,0 +1,102 @@
+//! Allocations of budoux::Parser, counted by global allocator of this test binary.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use budoux::models::StaticModel;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counter is allocator counting allocations of each thread.
+struct Counter;
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|v| v.set(v.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|v| v.set(v.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+/// allocations returns number of allocations by f on current thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+static MODEL: StaticModel = StaticModel::new(&[("UW3:は", 2000), ("UW4:テ", 1000)]);
+
+const INPUTS: [&str; 4] = [
+    "これはテストです。",
+    "詳細はhttps://example.com/v1.2をご覧ください",
+    "v1.2.3は2024/01/01に`cargo test`で確認",
+    "",
+];
+
+#[test]
+fn test_parser_reuse() {
+    let mut parser = budoux::Parser::new(&MODEL);
+    let mut offsets = Vec::new();
+    let mut phrases = Vec::new();
+    let mut slice = [0; 16];
+
+    // Buffers grow on the first calls.
+    for input in INPUTS {
+        parser.boundaries_into(input, &mut offsets);
+        parser.phrases_into(input, &mut phrases);
+    }
+
+    let n = allocations(|| {
+        for _ in 0..100 {
+            for input in INPUTS {
+                parser.boundaries_into(input, &mut offsets);
+                parser.boundaries_into_slice(input, &mut slice).unwrap();
+                parser.phrases_into(input, &mut phrases);
+            }
+        }
+    });
+    assert_eq!(n, 0);
+
+    parser.phrases_into(INPUTS[1], &mut phrases);
+    assert_eq!(
+        phrases,
+        vec!["詳細は", "https://example.com/v1.2をご覧ください"]
+    );
+}
+
+#[test]
+#[cfg(feature = "model-ja")]
+fn test_parser_reuse_ja() {
+    let mut parser = budoux::Parser::new(budoux::models::default_japanese_model());
+    let mut offsets = Vec::new();
+    let mut phrases = Vec::new();
+
+    for input in INPUTS {
+        parser.boundaries_into(input, &mut offsets);
+        parser.phrases_into(input, &mut phrases);
+    }
+
+    let n = allocations(|| {
+        for _ in 0..100 {
+            for input in INPUTS {
+                parser.boundaries_into(input, &mut offsets);
+                parser.phrases_into(input, &mut phrases);
+            }
+        }
+    });
+    assert_eq!(n, 0);
+}